@@ -1,30 +1,78 @@
+mod activity_log;
+mod asset_data_address_edit;
+mod asset_properties_edit;
+mod asset_source_test;
 mod atomic_constraint_edit;
+mod auto_refresh_toggle;
 mod create_asset;
 mod create_contract_definition;
 mod create_contract_negotiation;
 mod create_policy;
 mod create_transfer_process;
 mod criterion_edit;
+mod data_destination_edit;
+mod edr_panel;
+mod export_connector;
+mod global_search;
+mod import_assets;
+mod key_value_edit;
 mod list_assets;
+mod list_connector_diff;
 mod list_contract_agreements;
 mod list_contract_definitions;
 mod list_contract_negotiations;
+mod list_data_planes;
+mod list_edrs;
 mod list_of_rules;
 mod list_policies;
+mod list_query_toolbar;
+mod list_secrets;
 mod list_transfer_processes;
+mod policy_rules;
 mod policy_selector;
+mod response_preview;
+mod restore_connector;
 mod rule;
+mod secret_form;
+mod secret_selector;
 mod simple_or_id_field;
+mod transfer_process_action;
+mod transfer_type_field;
+mod watch_button;
 
+pub use activity_log::{ActivityLogPanel, ActivityLogToggle};
+pub use asset_data_address_edit::AssetDataAddressEdit;
+pub use asset_properties_edit::AssetPropertiesEdit;
+pub use asset_source_test::AssetSourceTest;
+pub use auto_refresh_toggle::AutoRefreshToggle;
 pub use create_asset::*;
 pub use create_contract_definition::*;
 pub use create_contract_negotiation::*;
 pub use create_policy::*;
 pub use create_transfer_process::*;
+pub use data_destination_edit::DataDestinationEdit;
+pub use edr_panel::EdrPanel;
+pub use export_connector::*;
+pub use global_search::GlobalSearch;
+pub use import_assets::ImportAssets;
+pub use key_value_edit::KeyValueEdit;
 pub use list_assets::ListAssets;
+pub use list_connector_diff::ListConnectorDiff;
 pub use list_contract_agreements::ListContractAgreements;
 pub use list_contract_definitions::ListContractDefinitions;
 pub use list_contract_negotiations::ListContractNegotiations;
+pub use list_data_planes::ListDataPlanes;
+pub use list_edrs::ListEdrs;
 pub use list_policies::ListPolicies;
+pub use list_query_toolbar::*;
+pub use list_secrets::ListSecrets;
 pub use list_transfer_processes::ListTransferProcesses;
+pub use policy_rules::PolicyRules;
 pub use policy_selector::*;
+pub use response_preview::{RequestFailed, ResponsePreview};
+pub use restore_connector::*;
+pub use secret_form::SecretForm;
+pub use secret_selector::SecretSelector;
+pub use transfer_process_action::*;
+pub use transfer_type_field::TransferTypeField;
+pub use watch_button::WatchButton;
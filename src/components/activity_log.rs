@@ -0,0 +1,112 @@
+use crate::contexts::{ActivityEntry, ActivityLogAction, use_activity_log_context};
+use crate::routes::AppRoute;
+use patternfly_yew::prelude::*;
+use yew::prelude::*;
+use yew_nested_router::components::Link;
+
+#[derive(Clone, Debug, PartialEq, Properties)]
+pub struct ActivityLogToggleProps {
+  pub ontoggle: Callback<()>,
+}
+
+/// Toolbar button opening the activity log, showing how many entries arrived since it was last opened.
+#[component]
+pub fn ActivityLogToggle(props: &ActivityLogToggleProps) -> Html {
+  let activity_log_context = use_activity_log_context();
+  let unread = activity_log_context.unread;
+
+  let onclick = use_callback(
+    (activity_log_context, props.ontoggle.clone()),
+    |_, (activity_log_context, ontoggle)| {
+      activity_log_context.dispatch(ActivityLogAction::MarkRead);
+      ontoggle.emit(());
+    },
+  );
+
+  html!(
+    <Button icon={Icon::Bell} variant={ButtonVariant::Plain} aria_label="Activity log" {onclick}>
+      if unread > 0 {
+        <Badge>{ unread }</Badge>
+      }
+    </Button>
+  )
+}
+
+#[derive(Clone, Debug, PartialEq, Properties)]
+pub struct ActivityLogPanelProps {
+  pub onclose: Callback<()>,
+}
+
+#[component]
+pub fn ActivityLogPanel(props: &ActivityLogPanelProps) -> Html {
+  let activity_log_context = use_activity_log_context();
+
+  let onclear = use_callback(activity_log_context.clone(), |_, activity_log_context| {
+    activity_log_context.dispatch(ActivityLogAction::Clear);
+  });
+
+  html!(
+    <>
+      <DrawerHead>
+        <Title level={Level::H3}>{ "Activity" }</Title>
+        <DrawerActions>
+          <Button
+            variant={ButtonVariant::Link}
+            disabled={activity_log_context.entries.is_empty()}
+            onclick={onclear}
+          >
+            { "Clear" }
+          </Button>
+          <DrawerCloseButton onclick={props.onclose.reform(|_| ())} />
+        </DrawerActions>
+      </DrawerHead>
+      <DrawerPanelContent>
+        if activity_log_context.entries.is_empty() {
+          { "Nothing has happened in this session yet" }
+        } else {
+          <Stack gutter=true>
+            { for activity_log_context.entries.iter().map(|entry| html_nested!(
+              <StackItem>{ activity_entry(entry) }</StackItem>
+            )) }
+          </Stack>
+        }
+      </DrawerPanelContent>
+    </>
+  )
+}
+
+fn activity_entry(entry: &ActivityEntry) -> Html {
+  let outcome = match entry.error {
+    None => html!(<Label color={Color::Green} label="Succeeded" />),
+    Some(_) => html!(<Label color={Color::Red} label="Failed" />),
+  };
+
+  let target = match entry.id.clone() {
+    Some(id) if entry.links_to_entity() => html!(
+      <Link<AppRoute> to={entry.kind.route(id.clone())}>{ id }</Link<AppRoute>>
+    ),
+    Some(id) => html!(id),
+    None => html!(),
+  };
+
+  html!(
+    <Stack>
+      <StackItem>
+        { outcome }
+        { format!(" {} {} ", entry.operation, entry.kind) }
+        { target }
+        if let Some(connector) = entry.connector.clone() {
+          { format!(" on {connector}") }
+        }
+      </StackItem>
+      <StackItem>
+        <small>{ entry.timestamp.clone() }</small>
+      </StackItem>
+      if let Some(error) = entry.error.clone() {
+        <StackItem>
+          <Alert inline=true plain=true r#type={AlertType::Danger} title={error} />
+        </StackItem>
+      }
+    </Stack>
+  )
+}
@@ -0,0 +1,316 @@
+use crate::components::{KeyValueEdit, SecretSelector};
+use crate::models::{AssetAuthKind, AssetDataAddressKind, AssetDraft};
+use patternfly_yew::prelude::*;
+use yew::prelude::*;
+
+#[derive(Clone, Debug, PartialEq, Properties)]
+pub struct AssetDataAddressEditProps {
+  pub value: AssetDraft,
+  pub onchange: Callback<AssetDraft>,
+}
+
+/// Edits where an asset's data comes from, with a dedicated form per data address type.
+#[component]
+pub fn AssetDataAddressEdit(props: &AssetDataAddressEditProps) -> Html {
+  // Picking an auth type shows its fields before any of them is filled in.
+  let auth_kind = use_state(|| props.value.auth_kind());
+
+  let update = |apply: fn(&mut AssetDraft, String)| {
+    let value = props.value.clone();
+
+    props.onchange.reform(move |input: String| {
+      let mut value = value.clone();
+      apply(&mut value, input);
+      value
+    })
+  };
+
+  let update_flag = |apply: fn(&mut AssetDraft, bool)| {
+    let value = props.value.clone();
+
+    props.onchange.reform(move |input: bool| {
+      let mut value = value.clone();
+      apply(&mut value, input);
+      value
+    })
+  };
+
+  let update_entries = |apply: fn(&mut AssetDraft, Vec<(String, String)>)| {
+    let value = props.value.clone();
+
+    props.onchange.reform(move |entries| {
+      let mut value = value.clone();
+      apply(&mut value, entries);
+      value
+    })
+  };
+
+  let onselect_kind = {
+    let value = props.value.clone();
+
+    props.onchange.reform(move |kind| AssetDraft {
+      kind,
+      ..value.clone()
+    })
+  };
+
+  let onselect_auth_kind = {
+    let value = props.value.clone();
+    let onchange = props.onchange.clone();
+    let auth_kind = auth_kind.clone();
+
+    Callback::from(move |kind| {
+      auth_kind.set(kind);
+      onchange.emit(value.with_auth_kind(kind));
+    })
+  };
+
+  let value = &props.value;
+
+  let auth_key_field = html!(
+    <FormGroup label="Header Name">
+      <TextInput
+        placeholder="Authorization"
+        value={value.auth_key.clone()}
+        onchange={update(|value, auth_key| value.auth_key = auth_key)}
+      />
+    </FormGroup>
+  );
+
+  let auth_fields = match *auth_kind {
+    AssetAuthKind::None => html!(),
+    AssetAuthKind::AuthCode => html!(
+      <>
+        { auth_key_field }
+        <FormGroup label="Header Value" required=true>
+          <TextInput
+            required=true
+            r#type={TextInputType::Password}
+            value={value.auth_code.clone()}
+            onchange={update(|value, auth_code| value.auth_code = auth_code)}
+          />
+        </FormGroup>
+      </>
+    ),
+    AssetAuthKind::Secret => html!(
+      <>
+        { auth_key_field }
+        <SecretSelector
+          value={value.secret_name.clone()}
+          onchange={update(|value, secret_name| value.secret_name = secret_name)}
+        />
+      </>
+    ),
+    AssetAuthKind::OAuth2 => html!(
+      <>
+        <FormGroup label="Token URL" required=true>
+          <TextInput
+            required=true
+            r#type={TextInputType::Url}
+            value={value.oauth2_token_url.clone()}
+            onchange={update(|value, token_url| value.oauth2_token_url = token_url)}
+          />
+        </FormGroup>
+        <FormGroup label="Client ID" required=true>
+          <TextInput
+            required=true
+            value={value.oauth2_client_id.clone()}
+            onchange={update(|value, client_id| value.oauth2_client_id = client_id)}
+          />
+        </FormGroup>
+        <SecretSelector
+          label="Client Secret"
+          value={value.oauth2_client_secret_key.clone()}
+          onchange={update(|value, client_secret_key| {
+            value.oauth2_client_secret_key = client_secret_key
+          })}
+        />
+        <FormGroup label="Scope">
+          <TextInput
+            value={value.oauth2_scope.clone()}
+            onchange={update(|value, scope| value.oauth2_scope = scope)}
+          />
+        </FormGroup>
+      </>
+    ),
+  };
+
+  let fields = match value.kind {
+    AssetDataAddressKind::HttpData => html!(
+      <>
+        <FormGroup label="Base URL" required=true>
+          <TextInput
+            required=true
+            value={value.base_url.clone()}
+            onchange={update(|value, base_url| value.base_url = base_url)}
+            r#type={TextInputType::Url}
+          />
+        </FormGroup>
+        <FormGroup label="Proxy Path">
+          <Switch
+            checked={value.proxy_path}
+            onchange={update_flag(|value, proxy_path| value.proxy_path = proxy_path)}
+          />
+        </FormGroup>
+        <FormGroup label="Proxy Query Parameters">
+          <Switch
+            checked={value.proxy_query_params}
+            onchange={update_flag(|value, proxy_query_params| {
+              value.proxy_query_params = proxy_query_params
+            })}
+          />
+        </FormGroup>
+        <FormGroup label="Proxy Method">
+          <Switch
+            checked={value.proxy_method}
+            onchange={update_flag(|value, proxy_method| value.proxy_method = proxy_method)}
+          />
+        </FormGroup>
+        <FormGroup label="Proxy Body">
+          <Switch
+            checked={value.proxy_body}
+            onchange={update_flag(|value, proxy_body| value.proxy_body = proxy_body)}
+          />
+        </FormGroup>
+        <FormSection title="Request">
+          <FormGroup label="Method">
+            <TextInput
+              placeholder="GET"
+              value={value.method.clone()}
+              onchange={update(|value, method| value.method = method)}
+            />
+          </FormGroup>
+          <FormGroup label="Path">
+            <TextInput
+              value={value.path.clone()}
+              onchange={update(|value, path| value.path = path)}
+            />
+          </FormGroup>
+          <FormGroup label="Query Parameters">
+            <TextInput
+              placeholder="limit=10&offset=0"
+              value={value.query_params.clone()}
+              onchange={update(|value, query_params| value.query_params = query_params)}
+            />
+          </FormGroup>
+          <FormGroup label="Content Type">
+            <TextInput
+              placeholder="application/json"
+              value={value.request_content_type.clone()}
+              onchange={update(|value, request_content_type| {
+                value.request_content_type = request_content_type
+              })}
+            />
+          </FormGroup>
+          <FormGroup label="Headers">
+            <KeyValueEdit
+              entries={value.headers.clone()}
+              onchange={update_entries(|value, headers| value.headers = headers)}
+              add_label="Add header"
+            />
+          </FormGroup>
+        </FormSection>
+        <FormSection title="Authentication">
+          <FormGroup label="Type">
+            <SimpleSelect<AssetAuthKind>
+              entries={AssetAuthKind::ALL.to_vec()}
+              selected={*auth_kind}
+              onselect={onselect_auth_kind}
+            />
+          </FormGroup>
+          { auth_fields }
+        </FormSection>
+      </>
+    ),
+    AssetDataAddressKind::AmazonS3 => html!(
+      <>
+        <FormGroup label="Bucket Name" required=true>
+          <TextInput
+            required=true
+            value={value.bucket_name.clone()}
+            onchange={update(|value, bucket_name| value.bucket_name = bucket_name)}
+          />
+        </FormGroup>
+        <FormGroup label="Region" required=true>
+          <TextInput
+            required=true
+            placeholder="eu-central-1"
+            value={value.region.clone()}
+            onchange={update(|value, region| value.region = region)}
+          />
+        </FormGroup>
+        <FormGroup label="Object Key">
+          <TextInput
+            value={value.object_name.clone()}
+            onchange={update(|value, object_name| value.object_name = object_name)}
+          />
+        </FormGroup>
+        <SecretSelector
+          label="Credentials"
+          value={value.key_name.clone()}
+          onchange={update(|value, key_name| value.key_name = key_name)}
+        />
+      </>
+    ),
+    AssetDataAddressKind::AzureStorage => html!(
+      <>
+        <FormGroup label="Account Name" required=true>
+          <TextInput
+            required=true
+            value={value.account_name.clone()}
+            onchange={update(|value, account_name| value.account_name = account_name)}
+          />
+        </FormGroup>
+        <FormGroup label="Container" required=true>
+          <TextInput
+            required=true
+            value={value.container_name.clone()}
+            onchange={update(|value, container_name| value.container_name = container_name)}
+          />
+        </FormGroup>
+        <FormGroup label="Blob Name">
+          <TextInput
+            value={value.blob_name.clone()}
+            onchange={update(|value, blob_name| value.blob_name = blob_name)}
+          />
+        </FormGroup>
+        <SecretSelector
+          label="Account Key"
+          value={value.key_name.clone()}
+          onchange={update(|value, key_name| value.key_name = key_name)}
+        />
+      </>
+    ),
+    AssetDataAddressKind::Custom => html!(
+      <>
+        <FormGroup label="Type" required=true>
+          <TextInput
+            required=true
+            value={value.custom_type.clone()}
+            onchange={update(|value, custom_type| value.custom_type = custom_type)}
+          />
+        </FormGroup>
+        <FormGroup label="Properties">
+          <KeyValueEdit
+            entries={value.custom_properties.clone()}
+            onchange={update_entries(|value, properties| value.custom_properties = properties)}
+            add_label="Add property"
+          />
+        </FormGroup>
+      </>
+    ),
+  };
+
+  html!(
+    <>
+      <FormGroup label="Data Address Type">
+        <SimpleSelect<AssetDataAddressKind>
+          entries={AssetDataAddressKind::ALL.to_vec()}
+          selected={value.kind}
+          onselect={onselect_kind}
+        />
+      </FormGroup>
+      { fields }
+    </>
+  )
+}
@@ -0,0 +1,175 @@
+use crate::components::KeyValueEdit;
+use crate::models::{AssetDraft, AssetPropertyDraft, AssetPropertyValueKind, expand_property_name};
+use patternfly_yew::prelude::*;
+use yew::prelude::*;
+
+#[derive(Clone, Debug, PartialEq, Properties)]
+pub struct AssetPropertiesEditProps {
+  pub value: AssetDraft,
+  pub onchange: Callback<AssetDraft>,
+}
+
+/// Edits an asset's additional properties and the namespace prefixes their names may use.
+#[component]
+pub fn AssetPropertiesEdit(props: &AssetPropertiesEditProps) -> Html {
+  let onchange_namespaces = {
+    let value = props.value.clone();
+
+    props.onchange.reform(move |namespaces| AssetDraft {
+      namespaces,
+      ..value.clone()
+    })
+  };
+
+  let onchange_property = {
+    let value = props.value.clone();
+
+    props
+      .onchange
+      .reform(move |(index, property): (usize, AssetPropertyDraft)| {
+        let mut value = value.clone();
+        value.properties[index] = property;
+        value
+      })
+  };
+
+  let onremove = {
+    let value = props.value.clone();
+
+    props.onchange.reform(move |index: usize| {
+      let mut value = value.clone();
+      value.properties.remove(index);
+      value
+    })
+  };
+
+  let onadd = {
+    let value = props.value.clone();
+
+    props.onchange.reform(move |_| {
+      let mut value = value.clone();
+      value.properties.push(AssetPropertyDraft::default());
+      value
+    })
+  };
+
+  let value = &props.value;
+
+  html!(
+    <>
+      <FormGroup
+        label="Namespaces"
+        helper_text={FormHelperText::from("dct, dcat, odrl, foaf and dspace are known already")}
+      >
+        <KeyValueEdit
+          entries={value.namespaces.clone()}
+          onchange={onchange_namespaces}
+          key_placeholder="Prefix"
+          value_placeholder="Namespace IRI"
+          add_label="Add namespace"
+        />
+      </FormGroup>
+      <FormGroup label="Properties">
+        <Stack gutter=true>
+          { for value.properties.iter().enumerate().map(|(index, property)| {
+            let update = |apply: fn(&mut AssetPropertyDraft, String)| {
+              let property = property.clone();
+
+              onchange_property.reform(move |input: String| {
+                let mut property = property.clone();
+                apply(&mut property, input);
+                (index, property)
+              })
+            };
+
+            let onselect_kind = {
+              let property = property.clone();
+
+              onchange_property.reform(move |kind| (index, AssetPropertyDraft {
+                kind,
+                ..property.clone()
+              }))
+            };
+
+            let onchange_private = {
+              let property = property.clone();
+
+              onchange_property.reform(move |private| (index, AssetPropertyDraft {
+                private,
+                ..property.clone()
+              }))
+            };
+
+            // Show the full IRI a prefixed name expands to, and why a value cannot be parsed.
+            let mut hints = Vec::new();
+            let expanded_name = expand_property_name(&property.name, &value.namespaces);
+            if expanded_name != property.name.trim() {
+              hints.push(html_nested!(<HelperTextItem>{ expanded_name }</HelperTextItem>));
+            }
+            let value_error = match property.is_blank() {
+              true => None,
+              false => property.to_value().err(),
+            };
+            if let Some(value_error) = value_error.clone() {
+              hints.push(html_nested!(
+                <HelperTextItem variant={HelperTextItemVariant::Error}>{ value_error }</HelperTextItem>
+              ));
+            }
+
+            html_nested!(
+              <StackItem>
+                <Flex>
+                  <FlexItem modifiers={[FlexModifier::Flex1]}>
+                    <TextInput
+                      placeholder="dct:description"
+                      value={property.name.clone()}
+                      onchange={update(|property, name| property.name = name)}
+                    />
+                  </FlexItem>
+                  <FlexItem>
+                    <SimpleSelect<AssetPropertyValueKind>
+                      entries={AssetPropertyValueKind::ALL.to_vec()}
+                      selected={property.kind}
+                      onselect={onselect_kind}
+                    />
+                  </FlexItem>
+                  <FlexItem modifiers={[FlexModifier::Flex2]}>
+                    <TextInput
+                      placeholder="Value"
+                      state={if value_error.is_some() { InputState::Error } else { InputState::Default }}
+                      value={property.value.clone()}
+                      onchange={update(|property, value| property.value = value)}
+                    />
+                  </FlexItem>
+                  <FlexItem>
+                    <Switch
+                      label="Private"
+                      checked={property.private}
+                      onchange={onchange_private}
+                    />
+                  </FlexItem>
+                  <FlexItem>
+                    <Button
+                      icon={Icon::Trash}
+                      variant={ButtonVariant::Plain}
+                      aria_label="Remove"
+                      onclick={onremove.reform(move |_| index)}
+                    />
+                  </FlexItem>
+                </Flex>
+                if !hints.is_empty() {
+                  <HelperText>{ for hints }</HelperText>
+                }
+              </StackItem>
+            )
+          }) }
+          <StackItem>
+            <Button icon={Icon::Plus} variant={ButtonVariant::Secondary} onclick={onadd}>
+              { "Add property" }
+            </Button>
+          </StackItem>
+        </Stack>
+      </FormGroup>
+    </>
+  )
+}
@@ -0,0 +1,120 @@
+use crate::components::{RequestFailed, ResponsePreview};
+use crate::services::{SourceTestRequest, SourceTestResult, test_source};
+use patternfly_yew::prelude::*;
+use yew::platform::spawn_local;
+use yew::prelude::*;
+
+#[derive(Clone, Debug, PartialEq, Properties)]
+pub struct AssetSourceTestProps {
+  pub request: SourceTestRequest,
+}
+
+/// Sends the request an asset's HTTP data address describes straight from the browser, with the
+/// path and query open to override.
+#[component]
+pub fn AssetSourceTest(props: &AssetSourceTestProps) -> Html {
+  let path = use_state(|| props.request.path.clone());
+  let query_params = use_state(|| props.request.query_params.clone());
+  let result = use_state(|| Option::<Result<SourceTestResult, String>>::None);
+  let sending = use_state(|| false);
+
+  let onchange_path = use_callback(path.setter(), |path, path_setter| {
+    path_setter.set(path);
+  });
+
+  let onchange_query_params = use_callback(
+    query_params.setter(),
+    |query_params, query_params_setter| {
+      query_params_setter.set(query_params);
+    },
+  );
+
+  let request = SourceTestRequest {
+    path: (*path).clone(),
+    query_params: (*query_params).clone(),
+    ..props.request.clone()
+  };
+
+  let onsend = use_callback(
+    (request.clone(), result.clone(), sending.clone()),
+    |_, (request, result, sending)| {
+      let request = request.clone();
+      let result = result.clone();
+      let sending = sending.clone();
+
+      sending.set(true);
+
+      spawn_local(async move {
+        result.set(Some(test_source(&request).await));
+        sending.set(false);
+      });
+    },
+  );
+
+  html!(
+    <Stack gutter=true>
+      <StackItem>
+        <Alert
+          inline=true
+          plain=true
+          r#type={AlertType::Info}
+          title="Tested from this browser, not from the connector"
+        >
+          { "The connector may reach the source differently, e.g. through another network or without CORS restrictions." }
+        </Alert>
+      </StackItem>
+      if let Some(authentication) = &props.request.unsent_authentication {
+        <StackItem>
+          <Alert inline=true plain=true r#type={AlertType::Warning} title="Authentication not sent">
+            { html!(format!(
+              "The data plane authenticates with {authentication}, which the browser cannot use."
+            )) }
+          </Alert>
+        </StackItem>
+      }
+      <StackItem>
+        <Split gutter=true>
+          <SplitItem fill=true>
+            <TextInput placeholder="Path" value={(*path).clone()} onchange={onchange_path} />
+          </SplitItem>
+          <SplitItem fill=true>
+            <TextInput
+              placeholder="Query, e.g. limit=10"
+              value={(*query_params).clone()}
+              onchange={onchange_query_params}
+            />
+          </SplitItem>
+          <SplitItem>
+            <Button variant={ButtonVariant::Secondary} disabled={*sending} onclick={onsend}>
+              { format!("Send {}", request.method) }
+            </Button>
+          </SplitItem>
+        </Split>
+      </StackItem>
+      <StackItem><code style="word-break: break-all">{ request.url() }</code></StackItem>
+      if let Some(result) = (*result).clone() {
+        <StackItem>{ result_view(result) }</StackItem>
+      }
+    </Stack>
+  )
+}
+
+fn result_view(result: Result<SourceTestResult, String>) -> Html {
+  match result {
+    Ok(result) => html!(
+      <Stack gutter=true>
+        <StackItem>
+          <DescriptionList>
+            <DescriptionGroup term="Status">{ result.response.status.clone() }</DescriptionGroup>
+            <DescriptionGroup term="Latency">{ format!("{} ms", result.latency_ms) }</DescriptionGroup>
+            <DescriptionGroup term="Content Type">
+              { result.response.content_type.clone().unwrap_or_default() }
+            </DescriptionGroup>
+          </DescriptionList>
+        </StackItem>
+        <StackItem><ResponsePreview response={result.response} /></StackItem>
+      </Stack>
+    ),
+    Err(error) => html!(<RequestFailed {error} />),
+  }
+}
@@ -0,0 +1,45 @@
+use crate::models::{AutoRefresh, RefreshInterval};
+use patternfly_yew::prelude::*;
+use yew::prelude::*;
+
+#[derive(Clone, Debug, PartialEq, Properties)]
+pub struct AutoRefreshToggleProps {
+  pub value: AutoRefresh,
+  pub onchange: Callback<AutoRefresh>,
+}
+
+#[component]
+pub fn AutoRefreshToggle(props: &AutoRefreshToggleProps) -> Html {
+  let onchange_enabled = use_callback(
+    (props.value, props.onchange.clone()),
+    |enabled, (value, onchange)| {
+      onchange.emit(AutoRefresh { enabled, ..*value });
+    },
+  );
+
+  let onselect_interval = use_callback(
+    (props.value, props.onchange.clone()),
+    |interval, (value, onchange)| {
+      onchange.emit(AutoRefresh { interval, ..*value });
+    },
+  );
+
+  html!(
+    <Split gutter=true>
+      <SplitItem>
+        <Switch
+          label="Auto-refresh"
+          checked={props.value.enabled}
+          onchange={onchange_enabled}
+        />
+      </SplitItem>
+      <SplitItem>
+        <SimpleSelect<RefreshInterval>
+          entries={RefreshInterval::ALL.to_vec()}
+          selected={props.value.interval}
+          onselect={onselect_interval}
+        />
+      </SplitItem>
+    </Split>
+  )
+}
@@ -1,7 +1,7 @@
 use crate::components::PolicySelector;
 use crate::components::criterion_edit::CriterionEdit;
-use crate::contexts::use_edc_connector_context;
-use crate::models::PolicyDefinitionItem;
+use crate::contexts::{record_creation, use_activity_log_context, use_edc_connector_context};
+use crate::models::{ContractDefinitionDraft, EntityKind};
 use edc_connector_client::types::contract_definition::NewContractDefinition;
 use edc_connector_client::types::query::Criterion;
 use patternfly_yew::prelude::*;
@@ -12,20 +12,27 @@ use yew::prelude::*;
 pub struct CreateContractDefinitionProps {
   #[prop_or_default]
   pub on_create: Callback<()>,
+  /// Values to start from instead of an empty form, e.g. when duplicating a contract definition.
+  #[prop_or_default]
+  pub initial: Option<ContractDefinitionDraft>,
 }
 
 #[component]
 pub fn CreateContractDefinition(props: &CreateContractDefinitionProps) -> Html {
   let edc_connector_context = use_edc_connector_context();
+  let activity_log_context = use_activity_log_context();
 
   let identifier = use_state(|| "".to_string());
-  let access_policy_definition_item = use_state(|| Option::<PolicyDefinitionItem>::None);
-  let contract_policy_definition_item = use_state(|| Option::<PolicyDefinitionItem>::None);
-  let asset_selector = use_state(|| (String::new(), String::new(), String::new()));
+  let initial = props.initial.clone().unwrap_or_default();
+  let access_policy_definition_item = use_state(|| initial.access_policy_definition_item.clone());
+  let contract_policy_definition_item =
+    use_state(|| initial.contract_policy_definition_item.clone());
+  let asset_selector = use_state(|| initial.asset_selector.clone());
 
   let onsubmit = use_callback(
     (
       edc_connector_context.clone(),
+      activity_log_context,
       identifier.clone(),
       access_policy_definition_item.clone(),
       contract_policy_definition_item.clone(),
@@ -35,6 +42,7 @@ pub fn CreateContractDefinition(props: &CreateContractDefinitionProps) -> Html {
     |event: SubmitEvent,
      (
       edc_connector_context,
+      activity_log_context,
       identifier,
       access_policy_definition_item,
       contract_policy_definition_item,
@@ -44,6 +52,7 @@ pub fn CreateContractDefinition(props: &CreateContractDefinitionProps) -> Html {
       event.prevent_default();
 
       let edc_connector_context = edc_connector_context.clone();
+      let activity_log_context = activity_log_context.clone();
       let identifier = (**identifier).clone();
       let access_policy_definition_item = (**access_policy_definition_item).clone();
       let contract_policy_definition_item = (**contract_policy_definition_item).clone();
@@ -75,10 +84,16 @@ pub fn CreateContractDefinition(props: &CreateContractDefinitionProps) -> Html {
         let new_contract_definition = new_contract_definition.build();
 
         if let Some(client) = edc_connector_context.get_client() {
-          let _ = client
+          let result = client
             .contract_definitions()
             .create(&new_contract_definition)
             .await;
+          record_creation(
+            &activity_log_context,
+            EntityKind::ContractDefinition,
+            Some(identifier),
+            &result,
+          );
 
           on_create.emit(());
         }
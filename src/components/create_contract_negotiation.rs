@@ -1,6 +1,6 @@
 use crate::components::PolicySelector;
-use crate::contexts::use_edc_connector_context;
-use crate::models::PolicyDefinitionItem;
+use crate::contexts::{record_creation, use_activity_log_context, use_edc_connector_context};
+use crate::models::{EntityKind, PolicyDefinitionItem};
 use edc_connector_client::types::contract_negotiation::ContractRequest;
 use patternfly_yew::prelude::*;
 use yew::platform::spawn_local;
@@ -15,6 +15,7 @@ pub struct CreateContractNegotiationProps {
 #[component]
 pub fn CreateContractNegotiation(props: &CreateContractNegotiationProps) -> Html {
   let edc_connector_context = use_edc_connector_context();
+  let activity_log_context = use_activity_log_context();
 
   let counter_party_address = use_state(String::new);
   let counter_party_id = use_state(String::new);
@@ -23,6 +24,7 @@ pub fn CreateContractNegotiation(props: &CreateContractNegotiationProps) -> Html
   let onsubmit = use_callback(
     (
       edc_connector_context.clone(),
+      activity_log_context,
       counter_party_address.clone(),
       counter_party_id.clone(),
       policy_definition_item.clone(),
@@ -31,6 +33,7 @@ pub fn CreateContractNegotiation(props: &CreateContractNegotiationProps) -> Html
     |event: SubmitEvent,
      (
       edc_connector_context,
+      activity_log_context,
       counter_party_address,
       counter_party_id,
       policy_definition_item,
@@ -39,6 +42,7 @@ pub fn CreateContractNegotiation(props: &CreateContractNegotiationProps) -> Html
       event.prevent_default();
 
       let edc_connector_context = edc_connector_context.clone();
+      let activity_log_context = activity_log_context.clone();
       let counter_party_address = (**counter_party_address).clone();
       let counter_party_id = (**counter_party_id).clone();
       let policy_definition_item = (**policy_definition_item).clone();
@@ -55,10 +59,16 @@ pub fn CreateContractNegotiation(props: &CreateContractNegotiationProps) -> Html
             .policy(policy_definition.policy().clone())
             .build();
 
-          let _ = client
+          let result = client
             .contract_negotiations()
             .initiate(&contract_request)
             .await;
+          record_creation(
+            &activity_log_context,
+            EntityKind::ContractNegotiation,
+            None,
+            &result,
+          );
 
           oncreate.emit(());
         }
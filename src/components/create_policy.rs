@@ -1,6 +1,7 @@
 use crate::components::list_of_rules::ListOfRules;
 use crate::components::simple_or_id_field::SimpleOrIdField;
-use crate::contexts::use_edc_connector_context;
+use crate::contexts::{record_creation, use_activity_log_context, use_edc_connector_context};
+use crate::models::EntityKind;
 use edc_connector_client::types::policy::{
   Action, Constraint, NewPolicyDefinition, Obligation, Permission, Policy, PolicyKind, Prohibition,
   Target,
@@ -20,24 +21,87 @@ enum Options {
 pub struct CreatePolicyProps {
   #[prop_or_default]
   pub on_create: Callback<()>,
+  /// Policy to start from instead of an empty form, e.g. when duplicating a policy definition.
+  #[prop_or_default]
+  pub initial: Option<Policy>,
 }
 
 #[component]
 pub fn CreatePolicy(props: &CreatePolicyProps) -> Html {
   let edc_connector_context = use_edc_connector_context();
+  let activity_log_context = use_activity_log_context();
 
   let identifier = use_state(String::new);
-  let kind = use_state(|| Options::Set);
-  let assignee = use_state(String::default);
-  let assigner = use_state(String::default);
-  let target = use_state(|| (true, String::default()));
-  let permissions = use_state(Vec::new);
-  let prohibitions = use_state(Vec::new);
-  let obligations = use_state(Vec::new);
+  let kind = use_state(|| match props.initial.as_ref().map(Policy::kind) {
+    Some(PolicyKind::Agreement) => Options::Agreement,
+    Some(PolicyKind::Offer) => Options::Offer,
+    _ => Options::Set,
+  });
+  let assignee = use_state(|| {
+    props
+      .initial
+      .as_ref()
+      .and_then(Policy::assignee)
+      .cloned()
+      .unwrap_or_default()
+  });
+  let assigner = use_state(|| {
+    props
+      .initial
+      .as_ref()
+      .and_then(Policy::assigner)
+      .cloned()
+      .unwrap_or_default()
+  });
+  let target = use_state(|| match props.initial.as_ref().and_then(Policy::target) {
+    Some(Target::Simple(target)) => (true, target.clone()),
+    Some(Target::Id { id }) => (false, id.clone()),
+    None => (true, String::default()),
+  });
+  let permissions = use_state(|| {
+    props
+      .initial
+      .iter()
+      .flat_map(Policy::permissions)
+      .map(|permission| {
+        (
+          permission.action().clone(),
+          permission.constraints().to_vec(),
+        )
+      })
+      .collect::<Vec<_>>()
+  });
+  let prohibitions = use_state(|| {
+    props
+      .initial
+      .iter()
+      .flat_map(Policy::prohibitions)
+      .map(|prohibition| {
+        (
+          prohibition.action().clone(),
+          prohibition.constraints().to_vec(),
+        )
+      })
+      .collect::<Vec<_>>()
+  });
+  let obligations = use_state(|| {
+    props
+      .initial
+      .iter()
+      .flat_map(Policy::obligations)
+      .map(|obligation| {
+        (
+          obligation.action().clone(),
+          obligation.constraints().to_vec(),
+        )
+      })
+      .collect::<Vec<_>>()
+  });
 
   let onsubmit = use_callback(
     (
       edc_connector_context,
+      activity_log_context,
       identifier.clone(),
       kind.clone(),
       assignee.clone(),
@@ -51,6 +115,7 @@ pub fn CreatePolicy(props: &CreatePolicyProps) -> Html {
     |event: SubmitEvent,
      (
       edc_connector_context,
+      activity_log_context,
       identifier,
       kind,
       assignee,
@@ -64,6 +129,7 @@ pub fn CreatePolicy(props: &CreatePolicyProps) -> Html {
       event.prevent_default();
 
       let edc_connector_context = edc_connector_context.clone();
+      let activity_log_context = activity_log_context.clone();
       let identifier = (**identifier).clone();
       let kind = **kind;
       let assignee = (**assignee).clone();
@@ -145,7 +211,14 @@ pub fn CreatePolicy(props: &CreatePolicyProps) -> Html {
           .build();
 
         if let Some(client) = edc_connector_context.get_client() {
-          let _ = client.policies().create(&new_policy).await;
+          let result = client.policies().create(&new_policy).await;
+          record_creation(
+            &activity_log_context,
+            EntityKind::PolicyDefinition,
+            Some(identifier),
+            &result,
+          );
+
           on_create.emit(());
         }
       })
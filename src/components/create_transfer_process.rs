@@ -1,4 +1,6 @@
-use crate::contexts::use_edc_connector_context;
+use crate::components::{DataDestinationEdit, TransferTypeField};
+use crate::contexts::{record_creation, use_activity_log_context, use_edc_connector_context};
+use crate::models::{DataDestinationDraft, DataDestinationKind, EntityKind};
 use edc_connector_client::types::transfer_process::TransferRequest;
 use patternfly_yew::prelude::*;
 use yew::platform::spawn_local;
@@ -13,41 +15,76 @@ pub struct CreateTransferProcessProps {
 #[component]
 pub fn CreateTransferProcess(props: &CreateTransferProcessProps) -> Html {
   let edc_connector_context = use_edc_connector_context();
+  let activity_log_context = use_activity_log_context();
 
   let contract_id = use_state(|| "".to_string());
   let counter_party_address = use_state(|| "".to_string());
   let transfer_type = use_state(|| "".to_string());
+  let data_destination = use_state(DataDestinationDraft::default);
+  let error = use_state(|| Option::<String>::None);
 
   let onsubmit = use_callback(
     (
       edc_connector_context,
+      activity_log_context,
       contract_id.clone(),
       counter_party_address.clone(),
       transfer_type.clone(),
+      data_destination.clone(),
+      error.clone(),
       props.on_create.clone(),
     ),
     |event: SubmitEvent,
-     (edc_connector_context, contract_id, counter_party_address, transfer_type, on_create)| {
+     (
+      edc_connector_context,
+      activity_log_context,
+      contract_id,
+      counter_party_address,
+      transfer_type,
+      data_destination,
+      error,
+      on_create,
+    )| {
       event.prevent_default();
 
       let edc_connector_context = edc_connector_context.clone();
+      let activity_log_context = activity_log_context.clone();
       let contract_id = (**contract_id).clone();
       let counter_party_address = (**counter_party_address).clone();
       let transfer_type = (**transfer_type).clone();
       let on_create = on_create.clone();
 
+      let data_destination = match data_destination.to_data_address() {
+        Ok(data_destination) => data_destination,
+        Err(data_destination_error) => {
+          error.set(Some(data_destination_error));
+          return;
+        }
+      };
+
       spawn_local(async move {
         let transfer_request = TransferRequest::builder()
           .contract_id(contract_id)
           .counter_party_address(counter_party_address)
-          .transfer_type(transfer_type)
-          .build();
+          .transfer_type(transfer_type);
+
+        let transfer_request = match data_destination {
+          Some(data_destination) => transfer_request.destination(data_destination),
+          None => transfer_request,
+        }
+        .build();
 
         if let Some(client) = edc_connector_context.get_client() {
-          let _ = client
+          let result = client
             .transfer_processes()
             .initiate(&transfer_request)
             .await;
+          record_creation(
+            &activity_log_context,
+            EntityKind::TransferProcess,
+            None,
+            &result,
+          );
 
           on_create.emit(());
         }
@@ -70,16 +107,38 @@ pub fn CreateTransferProcess(props: &CreateTransferProcessProps) -> Html {
   );
 
   let onchange_transfer_type = use_callback(
-    transfer_type.setter(),
-    move |transfer_type, transfer_type_setter| {
+    (transfer_type.setter(), data_destination.clone()),
+    move |transfer_type: String, (transfer_type_setter, data_destination)| {
+      // Push transfers need somewhere to write to; preselect the matching destination form.
+      if data_destination.kind == DataDestinationKind::None
+        && let Some(kind) = DataDestinationKind::for_transfer_type(&transfer_type)
+      {
+        data_destination.set(DataDestinationDraft {
+          kind,
+          ..(**data_destination).clone()
+        });
+      }
+
       transfer_type_setter.set(transfer_type);
     },
   );
 
-  let disabled = false;
+  let onchange_data_destination = use_callback(
+    data_destination.setter(),
+    move |data_destination, data_destination_setter| {
+      data_destination_setter.set(data_destination);
+    },
+  );
+
+  let disabled = transfer_type.is_empty();
 
   html!(
     <Form {onsubmit}>
+      if let Some(error) = (*error).clone() {
+        <Alert inline=true r#type={AlertType::Danger} title="Invalid data destination">
+          { html!(error) }
+        </Alert>
+      }
       <FormGroup label="Contract ID" required=true>
         <TextInput
           required=true
@@ -94,13 +153,15 @@ pub fn CreateTransferProcess(props: &CreateTransferProcessProps) -> Html {
           onchange={onchange_counter_party_address}
         />
       </FormGroup>
-      <FormGroup label="Transfer Type" required=true>
-        <TextInput
-          required=true
-          value={(*transfer_type).to_string()}
-          onchange={onchange_transfer_type}
-        />
-      </FormGroup>
+      <TransferTypeField
+        contract_id={(*contract_id).to_string()}
+        value={(*transfer_type).to_string()}
+        onchange={onchange_transfer_type}
+      />
+      <DataDestinationEdit
+        value={(*data_destination).clone()}
+        onchange={onchange_data_destination}
+      />
       <ActionGroup>
         <Button
           variant={ButtonVariant::Primary}
@@ -0,0 +1,154 @@
+use crate::components::KeyValueEdit;
+use crate::models::{DataDestinationDraft, DataDestinationKind};
+use patternfly_yew::prelude::*;
+use yew::prelude::*;
+
+#[derive(Clone, Debug, PartialEq, Properties)]
+pub struct DataDestinationEditProps {
+  pub value: DataDestinationDraft,
+  pub onchange: Callback<DataDestinationDraft>,
+}
+
+#[component]
+pub fn DataDestinationEdit(props: &DataDestinationEditProps) -> Html {
+  let update = |apply: fn(&mut DataDestinationDraft, String)| {
+    let value = props.value.clone();
+
+    props.onchange.reform(move |input: String| {
+      let mut value = value.clone();
+      apply(&mut value, input);
+      value
+    })
+  };
+
+  let update_entries = |apply: fn(&mut DataDestinationDraft, Vec<(String, String)>)| {
+    let value = props.value.clone();
+
+    props.onchange.reform(move |entries| {
+      let mut value = value.clone();
+      apply(&mut value, entries);
+      value
+    })
+  };
+
+  let onselect_kind = {
+    let value = props.value.clone();
+
+    props.onchange.reform(move |kind| DataDestinationDraft {
+      kind,
+      ..value.clone()
+    })
+  };
+
+  let value = &props.value;
+
+  let fields = match value.kind {
+    DataDestinationKind::None => html!(),
+    DataDestinationKind::HttpData => html!(
+      <>
+        <FormGroup label="Base URL" required=true>
+          <TextInput
+            required=true
+            r#type={TextInputType::Url}
+            value={value.base_url.clone()}
+            onchange={update(|value, base_url| value.base_url = base_url)}
+          />
+        </FormGroup>
+        <FormGroup label="Method">
+          <TextInput
+            placeholder="POST"
+            value={value.method.clone()}
+            onchange={update(|value, method| value.method = method)}
+          />
+        </FormGroup>
+        <FormGroup label="Headers">
+          <KeyValueEdit
+            entries={value.headers.clone()}
+            onchange={update_entries(|value, headers| value.headers = headers)}
+            add_label="Add header"
+          />
+        </FormGroup>
+      </>
+    ),
+    DataDestinationKind::AmazonS3 => html!(
+      <>
+        <FormGroup label="Bucket Name" required=true>
+          <TextInput
+            required=true
+            value={value.bucket_name.clone()}
+            onchange={update(|value, bucket_name| value.bucket_name = bucket_name)}
+          />
+        </FormGroup>
+        <FormGroup label="Region" required=true>
+          <TextInput
+            required=true
+            placeholder="eu-central-1"
+            value={value.region.clone()}
+            onchange={update(|value, region| value.region = region)}
+          />
+        </FormGroup>
+        <FormGroup label="Object Key">
+          <TextInput
+            value={value.object_name.clone()}
+            onchange={update(|value, object_name| value.object_name = object_name)}
+          />
+        </FormGroup>
+      </>
+    ),
+    DataDestinationKind::AzureStorage => html!(
+      <>
+        <FormGroup label="Account Name" required=true>
+          <TextInput
+            required=true
+            value={value.account_name.clone()}
+            onchange={update(|value, account_name| value.account_name = account_name)}
+          />
+        </FormGroup>
+        <FormGroup label="Container" required=true>
+          <TextInput
+            required=true
+            value={value.container_name.clone()}
+            onchange={update(|value, container_name| value.container_name = container_name)}
+          />
+        </FormGroup>
+        <FormGroup label="Blob Name">
+          <TextInput
+            value={value.blob_name.clone()}
+            onchange={update(|value, blob_name| value.blob_name = blob_name)}
+          />
+        </FormGroup>
+      </>
+    ),
+    DataDestinationKind::Custom => html!(
+      <>
+        <FormGroup label="Type" required=true>
+          <TextInput
+            required=true
+            value={value.custom_type.clone()}
+            onchange={update(|value, custom_type| value.custom_type = custom_type)}
+          />
+        </FormGroup>
+        <FormGroup label="Properties">
+          <KeyValueEdit
+            entries={value.properties.clone()}
+            onchange={update_entries(|value, properties| value.properties = properties)}
+            add_label="Add property"
+          />
+        </FormGroup>
+      </>
+    ),
+  };
+
+  html!(
+    <>
+      <FormGroup label="Data Destination">
+        <SimpleSelect<DataDestinationKind>
+          entries={DataDestinationKind::ALL.to_vec()}
+          selected={value.kind}
+          onselect={onselect_kind}
+        />
+      </FormGroup>
+      { fields }
+    </>
+  )
+}
@@ -0,0 +1,201 @@
+use crate::components::{RequestFailed, ResponsePreview};
+use crate::contexts::use_edc_connector_context;
+use crate::models::{EndpointDataReference, MASKED_VALUE};
+use crate::services::{BrowserResponse, error_message, try_edr_request};
+use patternfly_yew::prelude::*;
+use yew::platform::spawn_local;
+use yew::prelude::*;
+use yew::suspense::use_future_with;
+
+#[derive(Clone, Debug, PartialEq, Properties)]
+pub struct EdrPanelProps {
+  pub transfer_process_id: String,
+  #[prop_or_default]
+  pub force_refresh: usize,
+}
+
+/// Shows the EDR a consumer received for a pull transfer and lets it be tried out against the
+/// provider's data plane.
+#[component]
+pub fn EdrPanel(props: &EdrPanelProps) -> HtmlResult {
+  let edc_connector_context = use_edc_connector_context();
+
+  let endpoint_data_reference = use_future_with(
+    (
+      edc_connector_context,
+      props.transfer_process_id.clone(),
+      props.force_refresh,
+    ),
+    |parameters| async move {
+      let (edc_connector_context, transfer_process_id, _) = (*parameters).clone();
+
+      let Some(client) = edc_connector_context.get_client() else {
+        return Err("No connector configured".to_string());
+      };
+
+      client
+        .edrs()
+        .get_data_address(&transfer_process_id)
+        .await
+        .map(|data_address| EndpointDataReference::from(&data_address))
+        .map_err(|error| error_message(&error))
+    },
+  )?;
+
+  Ok(match (*endpoint_data_reference).clone() {
+    Ok(endpoint_data_reference) => html!(<EdrView {endpoint_data_reference} />),
+    Err(error) => html!(
+      <Alert inline=true r#type={AlertType::Info} title="No EDR available for this transfer">
+        { html!(error) }
+      </Alert>
+    ),
+  })
+}
+
+#[derive(Clone, Debug, PartialEq, Properties)]
+struct EdrViewProps {
+  endpoint_data_reference: EndpointDataReference,
+}
+
+#[component]
+fn EdrView(props: &EdrViewProps) -> Html {
+  let revealed = use_state(|| false);
+  let path = use_state(String::new);
+  let response = use_state(|| Option::<Result<BrowserResponse, String>>::None);
+  let sending = use_state(|| false);
+
+  let edr = &props.endpoint_data_reference;
+
+  let ontoggle_reveal = use_callback(revealed.clone(), |_, revealed| {
+    revealed.set(!**revealed);
+  });
+
+  let onchange_path = use_callback(path.setter(), |path, path_setter| {
+    path_setter.set(path);
+  });
+
+  let onsend = use_callback(
+    (edr.clone(), path.clone(), response.clone(), sending.clone()),
+    |_, (edr, path, response, sending)| {
+      let Some(endpoint) = edr.endpoint.clone() else {
+        return;
+      };
+
+      let authorization = edr.authorization.clone();
+      let path = (**path).clone();
+      let response = response.clone();
+      let sending = sending.clone();
+
+      sending.set(true);
+
+      spawn_local(async move {
+        let result = try_edr_request(&endpoint, authorization.as_deref(), &path).await;
+
+        response.set(Some(result));
+        sending.set(false);
+      });
+    },
+  );
+
+  let now = chrono::Utc::now().timestamp();
+
+  let expires = match edr.expires_at {
+    Some(expires_at) => {
+      let timestamp = chrono::DateTime::from_timestamp(expires_at, 0)
+        .unwrap_or_default()
+        .to_string();
+
+      if edr.is_expired(now) {
+        html!(<Label color={Color::Red} label={format!("Expired {timestamp}")} />)
+      } else {
+        html!(timestamp)
+      }
+    }
+    None => html!("Unknown"),
+  };
+
+  let authorization = match &edr.authorization {
+    Some(authorization) => html!(
+      <Split gutter=true>
+        <SplitItem fill=true>
+          <code style="word-break: break-all">
+            { if *revealed { authorization.as_str() } else { MASKED_VALUE } }
+          </code>
+        </SplitItem>
+        <SplitItem>
+          <Button variant={ButtonVariant::Link} onclick={ontoggle_reveal}>
+            { if *revealed { "Hide" } else { "Reveal" } }
+          </Button>
+        </SplitItem>
+      </Split>
+    ),
+    None => html!("None"),
+  };
+
+  html!(
+    <Stack gutter=true>
+      <StackItem>
+        <DescriptionList>
+          <DescriptionGroup term="Endpoint">{ edr.endpoint.clone().unwrap_or_default() }</DescriptionGroup>
+          <DescriptionGroup term="Endpoint Type">
+            { edr.endpoint_type.clone().unwrap_or_default() }
+          </DescriptionGroup>
+          <DescriptionGroup term="Auth Type">{ edr.auth_type.clone().unwrap_or_default() }</DescriptionGroup>
+          <DescriptionGroup term="Authorization Header">{ authorization }</DescriptionGroup>
+          <DescriptionGroup term="Expires">{ expires }</DescriptionGroup>
+          if !edr.properties.is_empty() {
+            <DescriptionGroup term="Properties">
+              <List>
+                { for edr.properties.iter().map(|(name, value)| html_nested!(
+                  <ListItem>{ format!("{name}: {value}") }</ListItem>
+                )) }
+              </List>
+            </DescriptionGroup>
+          }
+        </DescriptionList>
+      </StackItem>
+      if edr.endpoint.is_some() {
+        <StackItem>
+          <Title level={Level::H4}>{ "Try Request" }</Title>
+        </StackItem>
+        <StackItem>
+          <Split gutter=true>
+            <SplitItem fill=true>
+              <TextInput
+                placeholder="Optional path, e.g. items?limit=10"
+                value={(*path).clone()}
+                onchange={onchange_path}
+              />
+            </SplitItem>
+            <SplitItem>
+              <Button variant={ButtonVariant::Secondary} disabled={*sending} onclick={onsend}>
+                { "Send GET" }
+              </Button>
+            </SplitItem>
+          </Split>
+        </StackItem>
+        if let Some(response) = (*response).clone() {
+          <StackItem>{ response_view(response) }</StackItem>
+        }
+      }
+    </Stack>
+  )
+}
+
+fn response_view(response: Result<BrowserResponse, String>) -> Html {
+  match response {
+    Ok(response) => html!(
+      <Stack gutter=true>
+        <StackItem>
+          { format!(
+            "{} {}",
+            response.status,
+            response.content_type.clone().unwrap_or_default()
+          ) }
+        </StackItem>
+        <StackItem><ResponsePreview {response} /></StackItem>
+      </Stack>
+    ),
+    Err(error) => html!(<RequestFailed {error} />),
+  }
+}
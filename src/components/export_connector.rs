@@ -0,0 +1,84 @@
+use crate::contexts::use_edc_connector_context;
+use crate::services::ConnectorBackup;
+use patternfly_yew::prelude::*;
+use yew::platform::spawn_local;
+use yew::prelude::*;
+
+#[component]
+pub fn ExportConnector() -> Html {
+  let edc_connector_context = use_edc_connector_context();
+
+  let exporting = use_state(|| false);
+  let backup = use_state(|| Option::<Result<(String, String), String>>::None);
+
+  let onexport = use_callback(
+    (edc_connector_context, exporting.clone(), backup.clone()),
+    |_, (edc_connector_context, exporting, backup)| {
+      let edc_connector_context = edc_connector_context.clone();
+      let exporting = exporting.clone();
+      let backup = backup.clone();
+
+      exporting.set(true);
+
+      spawn_local(async move {
+        if let Some(client) = edc_connector_context.get_client() {
+          let result = ConnectorBackup::export(&client)
+            .await
+            .map_err(|error| error.to_string())
+            .and_then(|connector_backup| {
+              serde_json::to_string_pretty(&connector_backup)
+                .map(|json| (connector_backup.exported_at.clone(), json))
+                .map_err(|error| error.to_string())
+            });
+
+          backup.set(Some(result));
+        }
+
+        exporting.set(false);
+      });
+    },
+  );
+
+  let result = match (*backup).clone() {
+    Some(Ok((exported_at, json))) => {
+      let href = format!(
+        "data:application/json;charset=utf-8,{}",
+        String::from(web_sys::js_sys::encode_uri_component(&json))
+      );
+      // RFC 3339 timestamps contain colons, which Windows doesn't allow in file names.
+      let download = format!("edc-backup-{}.json", exported_at.replace(':', "-"));
+
+      html!(
+        <Stack gutter=true>
+          <StackItem>
+            <a class="pf-v6-c-button pf-m-secondary" {href} {download}>{ "Download" }</a>
+          </StackItem>
+          <StackItem>
+            <TextArea value={json} readonly=true rows={15} resize={ResizeOrientation::Vertical} />
+          </StackItem>
+        </Stack>
+      )
+    }
+    Some(Err(error)) => html!(
+      <Alert inline=true r#type={AlertType::Danger} title="Export failed">{ html!(error) }</Alert>
+    ),
+    None => html!(),
+  };
+
+  html!(
+    <Stack gutter=true>
+      <StackItem>
+        <Button
+          icon={Icon::Download}
+          variant={ButtonVariant::Primary}
+          onclick={onexport}
+          loading={*exporting}
+          disabled={*exporting}
+        >
+          { "Export" }
+        </Button>
+      </StackItem>
+      <StackItem>{ result }</StackItem>
+    </Stack>
+  )
+}
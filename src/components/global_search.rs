@@ -0,0 +1,39 @@
+use crate::routes::AppRoute;
+use patternfly_yew::prelude::*;
+use yew::prelude::*;
+use yew_nested_router::prelude::use_router;
+
+#[component]
+pub fn GlobalSearch() -> Html {
+  let router = use_router::<AppRoute>();
+  let term = use_state(String::new);
+
+  let onchange = use_callback(term.setter(), |value, term_setter| {
+    term_setter.set(value);
+  });
+
+  let onclear = use_callback(term.setter(), |_, term_setter| {
+    term_setter.set(String::new());
+  });
+
+  let onsearch = use_callback(router, |(_, value): (OnSearchEvent, String), router| {
+    let term = value.trim().to_string();
+
+    if let Some(router) = router
+      && !term.is_empty()
+    {
+      router.push(AppRoute::Search { term });
+    }
+  });
+
+  html!(
+    <SearchInput
+      aria_label="Search by ID"
+      placeholder="Search by ID"
+      value={(*term).clone()}
+      {onchange}
+      {onclear}
+      {onsearch}
+    />
+  )
+}
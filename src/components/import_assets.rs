@@ -0,0 +1,333 @@
+use crate::contexts::{record_creation, use_activity_log_context, use_edc_connector_context};
+use crate::models::{AssetDraft, EntityKind};
+use futures::future::join_all;
+use patternfly_yew::prelude::*;
+use std::collections::HashMap;
+use std::rc::Rc;
+use yew::platform::spawn_local;
+use yew::prelude::*;
+
+const BATCH_SIZE: usize = 10;
+
+#[derive(Copy, Clone, PartialEq, Eq, strum::Display)]
+enum Format {
+  #[strum(to_string = "JSON")]
+  Json,
+  #[strum(to_string = "CSV")]
+  Csv,
+}
+
+#[derive(Clone, Debug, PartialEq)]
+enum ImportStatus {
+  Invalid(String),
+  Ready,
+  Created,
+  Failed(String),
+}
+
+#[derive(Clone, Debug, PartialEq)]
+struct ImportRow {
+  index: usize,
+  asset_draft: AssetDraft,
+  status: ImportStatus,
+}
+
+#[derive(Clone, Debug, PartialEq, Properties)]
+pub struct ImportAssetsProps {
+  #[prop_or_default]
+  pub on_import: Callback<()>,
+}
+
+#[component]
+pub fn ImportAssets(props: &ImportAssetsProps) -> Html {
+  let edc_connector_context = use_edc_connector_context();
+  let activity_log_context = use_activity_log_context();
+
+  let format = use_state(|| Format::Json);
+  let input = use_state(String::new);
+  let parse_error = use_state(|| Option::<String>::None);
+  let rows = use_state(Vec::<ImportRow>::new);
+  let importing = use_state(|| false);
+
+  // Rows validated from a previous input must not be imported after it was changed.
+  let onselect_format = use_callback(
+    (format.setter(), rows.setter()),
+    |format, (format_setter, rows_setter)| {
+      format_setter.set(format);
+      rows_setter.set(vec![]);
+    },
+  );
+
+  let onchange_input = use_callback(
+    (input.setter(), rows.setter()),
+    |input, (input_setter, rows_setter)| {
+      input_setter.set(input);
+      rows_setter.set(vec![]);
+    },
+  );
+
+  let onvalidate = use_callback(
+    (
+      format.clone(),
+      input.clone(),
+      parse_error.clone(),
+      rows.clone(),
+    ),
+    |_, (format, input, parse_error, rows)| {
+      let parsed = match **format {
+        Format::Json => AssetDraft::parse_json(input),
+        Format::Csv => AssetDraft::parse_csv(input),
+      };
+
+      match parsed {
+        Ok(parsed) => {
+          let mut import_rows = parsed
+            .into_iter()
+            .enumerate()
+            .map(|(index, parsed)| {
+              let (asset_draft, status) = match parsed {
+                Ok(asset_draft) => {
+                  let status = match asset_draft.validate() {
+                    Ok(_) => ImportStatus::Ready,
+                    Err(error) => ImportStatus::Invalid(error),
+                  };
+
+                  (asset_draft, status)
+                }
+                Err(error) => (AssetDraft::default(), ImportStatus::Invalid(error)),
+              };
+
+              ImportRow {
+                index: index + 1,
+                asset_draft,
+                status,
+              }
+            })
+            .collect::<Vec<_>>();
+
+          flag_duplicate_ids(&mut import_rows);
+
+          parse_error.set(None);
+          rows.set(import_rows);
+        }
+        Err(error) => {
+          parse_error.set(Some(error));
+          rows.set(vec![]);
+        }
+      }
+    },
+  );
+
+  let onimport = use_callback(
+    (
+      edc_connector_context,
+      activity_log_context,
+      rows.clone(),
+      importing.clone(),
+      props.on_import.clone(),
+    ),
+    |_, (edc_connector_context, activity_log_context, rows, importing, on_import)| {
+      let edc_connector_context = edc_connector_context.clone();
+      let activity_log_context = activity_log_context.clone();
+      let rows = rows.clone();
+      let importing = importing.clone();
+      let on_import = on_import.clone();
+
+      importing.set(true);
+
+      spawn_local(async move {
+        let mut import_rows = (*rows).clone();
+
+        if let Some(client) = edc_connector_context.get_client() {
+          let pending = import_rows
+            .iter()
+            .enumerate()
+            .filter(|(_, import_row)| import_row.status == ImportStatus::Ready)
+            .map(|(position, _)| position)
+            .collect::<Vec<_>>();
+
+          for batch in pending.chunks(BATCH_SIZE) {
+            let results = join_all(batch.iter().map(|position| {
+              let new_asset = import_rows[*position].asset_draft.to_new_asset();
+              let client = client.clone();
+
+              async move { client.assets().create(&new_asset).await }
+            }))
+            .await;
+
+            for (position, result) in batch.iter().zip(results) {
+              record_creation(
+                &activity_log_context,
+                EntityKind::Asset,
+                Some(import_rows[*position].asset_draft.id.clone()),
+                &result,
+              );
+
+              import_rows[*position].status = match result {
+                Ok(_) => ImportStatus::Created,
+                Err(error) => ImportStatus::Failed(error.to_string()),
+              };
+            }
+
+            rows.set(import_rows.clone());
+          }
+        }
+
+        importing.set(false);
+        on_import.emit(());
+      });
+    },
+  );
+
+  let header = html_nested! {
+    <TableHeader<Columns>>
+      <TableColumn<Columns> label="Row" index={Columns::Row} />
+      <TableColumn<Columns> label="ID" index={Columns::Id} />
+      <TableColumn<Columns> label="Name" index={Columns::Name} />
+      <TableColumn<Columns> label="Base URL" index={Columns::BaseUrl} />
+      <TableColumn<Columns> label="Status" index={Columns::Status} />
+    </TableHeader<Columns>>
+  };
+
+  let ready = count_status(&rows, |status| *status == ImportStatus::Ready);
+
+  let summary = format!(
+    "{} rows, {} ready, {} invalid, {} created, {} failed",
+    rows.len(),
+    ready,
+    count_status(&rows, |status| matches!(status, ImportStatus::Invalid(_))),
+    count_status(&rows, |status| matches!(status, ImportStatus::Created)),
+    count_status(&rows, |status| matches!(status, ImportStatus::Failed(_))),
+  );
+
+  let entries = (*rows)
+    .iter()
+    .cloned()
+    .map(ImportRowRenderer)
+    .collect::<Vec<_>>();
+
+  let (entries, _) = use_table_data(MemoizedTableModel::new(Rc::new(entries)));
+
+  let placeholder = match *format {
+    Format::Json => {
+      r#"[{"id": "asset-1", "name": "Asset 1", "baseUrl": "https://example.com", "headers": {"Accept": "application/json"}}]"#
+    }
+    Format::Csv => {
+      "id,name,baseUrl,contentType,proxyPath,proxyQueryParams,proxyMethod,proxyBody,headers"
+    }
+  };
+
+  html!(
+    <Stack gutter=true>
+      <StackItem>
+        <Form>
+          <FormGroup label="Format" required=true>
+            <SimpleSelect<Format>
+              selected={*format}
+              onselect={onselect_format}
+              entries={vec![Format::Json, Format::Csv]}
+            />
+          </FormGroup>
+          <FormGroup label="Assets" required=true>
+            <TextArea
+              value={(*input).clone()}
+              onchange={onchange_input}
+              {placeholder}
+              rows={10}
+              resize={ResizeOrientation::Vertical}
+            />
+          </FormGroup>
+          <ActionGroup>
+            <Button variant={ButtonVariant::Secondary} label="Validate" onclick={onvalidate} />
+            <Button
+              variant={ButtonVariant::Primary}
+              label="Import"
+              onclick={onimport}
+              disabled={ready == 0 || *importing}
+            />
+          </ActionGroup>
+        </Form>
+      </StackItem>
+      if let Some(parse_error) = (*parse_error).clone() {
+        <StackItem>
+          <Alert inline=true r#type={AlertType::Danger} title="Unable to parse input">
+            { html!(parse_error) }
+          </Alert>
+        </StackItem>
+      }
+      if !rows.is_empty() {
+        <StackItem>{ summary }</StackItem>
+        <StackItem>
+          <Table<Columns, UseTableData<Columns, MemoizedTableModel<ImportRowRenderer>>>
+            mode={TableMode::Compact}
+            {header}
+            {entries}
+          />
+        </StackItem>
+      }
+    </Stack>
+  )
+}
+
+/// Marks rows reusing the ID of an earlier row, the connector would reject all but the first.
+fn flag_duplicate_ids(rows: &mut [ImportRow]) {
+  let mut first_rows = HashMap::new();
+
+  for import_row in rows.iter_mut() {
+    let id = import_row.asset_draft.id.trim().to_string();
+
+    if id.is_empty() || import_row.status != ImportStatus::Ready {
+      continue;
+    }
+
+    match first_rows.get(&id) {
+      Some(first_row) => {
+        import_row.status = ImportStatus::Invalid(format!("duplicate of row {first_row}"));
+      }
+      None => {
+        first_rows.insert(id, import_row.index);
+      }
+    }
+  }
+}
+
+fn count_status(rows: &[ImportRow], predicate: impl Fn(&ImportStatus) -> bool) -> usize {
+  rows
+    .iter()
+    .filter(|import_row| predicate(&import_row.status))
+    .count()
+}
+
+#[derive(Clone, Debug, Eq, PartialEq)]
+enum Columns {
+  Row,
+  Id,
+  Name,
+  BaseUrl,
+  Status,
+}
+
+#[derive(Clone, Debug)]
+struct ImportRowRenderer(ImportRow);
+
+impl TableEntryRenderer<Columns> for ImportRowRenderer {
+  fn render_cell(&self, context: CellContext<'_, Columns>) -> Cell {
+    match context.column {
+      Columns::Row => html!(self.0.index.to_string()),
+      Columns::Id => html!(self.0.asset_draft.id.to_string()),
+      Columns::Name => html!(self.0.asset_draft.name.to_string()),
+      Columns::BaseUrl => html!(self.0.asset_draft.base_url.to_string()),
+      Columns::Status => match &self.0.status {
+        ImportStatus::Invalid(error) => {
+          html!(<Label color={Color::Orange} label={format!("Invalid: {error}")} />)
+        }
+        ImportStatus::Ready => html!(<Label color={Color::Blue} label="Ready" />),
+        ImportStatus::Created => html!(<Label color={Color::Green} label="Created" />),
+        ImportStatus::Failed(error) => {
+          html!(<Label color={Color::Red} label={format!("Failed: {error}")} />)
+        }
+      },
+    }
+    .into()
+  }
+}
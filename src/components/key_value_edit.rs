@@ -0,0 +1,91 @@
+use patternfly_yew::prelude::*;
+use yew::prelude::*;
+
+#[derive(Clone, Debug, PartialEq, Properties)]
+pub struct KeyValueEditProps {
+  pub entries: Vec<(String, String)>,
+  pub onchange: Callback<Vec<(String, String)>>,
+  #[prop_or("Name".into())]
+  pub key_placeholder: AttrValue,
+  #[prop_or("Value".into())]
+  pub value_placeholder: AttrValue,
+  #[prop_or("Add entry".into())]
+  pub add_label: AttrValue,
+}
+
+/// Edits an ordered list of name/value pairs, empty names included so rows can be filled in.
+#[component]
+pub fn KeyValueEdit(props: &KeyValueEditProps) -> Html {
+  let onadd = use_callback(
+    (props.entries.clone(), props.onchange.clone()),
+    |_, (entries, onchange)| {
+      let mut entries = entries.clone();
+      entries.push((String::new(), String::new()));
+      onchange.emit(entries);
+    },
+  );
+
+  let onchange_entry = use_callback(
+    (props.entries.clone(), props.onchange.clone()),
+    |(index, entry), (entries, onchange)| {
+      let mut entries = entries.clone();
+      entries[index] = entry;
+      onchange.emit(entries);
+    },
+  );
+
+  let onremove = use_callback(
+    (props.entries.clone(), props.onchange.clone()),
+    |index, (entries, onchange)| {
+      let mut entries = entries.clone();
+      entries.remove(index);
+      onchange.emit(entries);
+    },
+  );
+
+  html!(
+    <Stack gutter=true>
+      { for props.entries.iter().enumerate().map(|(index, (key, value))| {
+        let value_for_key = value.clone();
+        let key_for_value = key.clone();
+        let onchange_key = onchange_entry.reform(move |key| (index, (key, value_for_key.clone())));
+        let onchange_value =
+          onchange_entry.reform(move |value| (index, (key_for_value.clone(), value)));
+
+        html_nested!(
+          <StackItem>
+            <Flex>
+              <FlexItem modifiers={[FlexModifier::Flex1]}>
+                <TextInput
+                  placeholder={props.key_placeholder.clone()}
+                  value={key.clone()}
+                  onchange={onchange_key}
+                />
+              </FlexItem>
+              <FlexItem modifiers={[FlexModifier::Flex1]}>
+                <TextInput
+                  placeholder={props.value_placeholder.clone()}
+                  value={value.clone()}
+                  onchange={onchange_value}
+                />
+              </FlexItem>
+              <FlexItem>
+                <Button
+                  icon={Icon::Trash}
+                  variant={ButtonVariant::Plain}
+                  aria_label="Remove"
+                  onclick={onremove.reform(move |_| index)}
+                />
+              </FlexItem>
+            </Flex>
+          </StackItem>
+        )
+      }) }
+      <StackItem>
+        <Button icon={Icon::Plus} variant={ButtonVariant::Secondary} onclick={onadd}>
+          { props.add_label.clone() }
+        </Button>
+      </StackItem>
+    </Stack>
+  )
+}
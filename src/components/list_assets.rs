@@ -1,34 +1,141 @@
-use crate::models::AssetItem;
+use crate::components::{QueryFilterToolbar, sorted_list_query, table_sort_by};
+use crate::models::{AssetItem, ListQuery, QueryProperty};
+use crate::routes::AppRoute;
+use crate::services::page_total_entries;
 use patternfly_yew::prelude::*;
 use std::rc::Rc;
 use yew::prelude::*;
+use yew_nested_router::components::Link;
+
+const SORT_COLUMNS: [(Columns, QueryProperty); 2] = [
+  (Columns::Id, QueryProperty::new("ID", "id")),
+  (
+    Columns::Name,
+    QueryProperty::new("Name", "https://w3id.org/edc/v0.0.1/ns/name"),
+  ),
+];
+
+const FILTER_PROPERTIES: [QueryProperty; 3] = [
+  QueryProperty::new("ID", "id"),
+  QueryProperty::new("Name", "https://w3id.org/edc/v0.0.1/ns/name"),
+  QueryProperty::new("Content Type", "https://w3id.org/edc/v0.0.1/ns/contenttype"),
+];
 
 #[derive(Clone, Debug, PartialEq, Properties)]
 pub struct ListAssetsProps {
   pub asset_items: Vec<AssetItem>,
   pub offset: usize,
+  #[prop_or_default]
+  pub total_entries: Option<usize>,
   pub limit: usize,
   pub onoffset: Callback<usize>,
   pub onlimit: Callback<usize>,
+  pub list_query: ListQuery,
+  pub onquery: Callback<ListQuery>,
   pub ondelete: Callback<String>,
+  pub onduplicate: Callback<String>,
+  /// Asset properties shown as extra columns, e.g. `dct:title`.
+  #[prop_or_default]
+  pub property_columns: Vec<String>,
+  #[prop_or_default]
+  pub onproperty_columns: Callback<Vec<String>>,
 }
 
 #[component]
 pub fn ListAssets(props: &ListAssetsProps) -> Html {
+  let sortby = table_sort_by(&SORT_COLUMNS, &props.list_query);
+
+  let onsort = use_callback(
+    (props.list_query.clone(), props.onquery.clone()),
+    |sort_by, (list_query, onquery)| {
+      onquery.emit(sorted_list_query(&SORT_COLUMNS, list_query, sort_by));
+    },
+  );
+
+  let onchange_filters = use_callback(
+    (props.list_query.clone(), props.onquery.clone()),
+    |filters, (list_query, onquery)| {
+      onquery.emit(ListQuery {
+        filters,
+        ..list_query.clone()
+      });
+    },
+  );
+
+  let new_property_column = use_state(String::new);
+
+  let onchange_new_property_column = use_callback(
+    new_property_column.setter(),
+    |name, new_property_column_setter| {
+      new_property_column_setter.set(name);
+    },
+  );
+
+  let onadd_property_column = use_callback(
+    (
+      new_property_column.clone(),
+      props.property_columns.clone(),
+      props.onproperty_columns.clone(),
+    ),
+    |_, (new_property_column, property_columns, onproperty_columns)| {
+      let name = new_property_column.trim().to_string();
+
+      if name.is_empty() || property_columns.contains(&name) {
+        return;
+      }
+
+      let mut property_columns = property_columns.clone();
+      property_columns.push(name);
+      onproperty_columns.emit(property_columns);
+      new_property_column.set(String::new());
+    },
+  );
+
+  let onremove_property_column = use_callback(
+    (
+      props.property_columns.clone(),
+      props.onproperty_columns.clone(),
+    ),
+    |name: String, (property_columns, onproperty_columns)| {
+      onproperty_columns.emit(
+        property_columns
+          .iter()
+          .filter(|property_column| **property_column != name)
+          .cloned()
+          .collect(),
+      );
+    },
+  );
+
   let header = html_nested! {
     <TableHeader<Columns>>
-      <TableColumn<Columns> label="Name" index={Columns::Name} />
-      <TableColumn<Columns> label="ID" index={Columns::Id} />
-      <TableColumn<Columns> label="Base URL" index={Columns::BaseUrl} />
-      <TableColumn<Columns> label="Proxy Path" index={Columns::ProxyPath} />
-      <TableColumn<Columns> label="Proxy Query Parameters" index={Columns::ProxyQueryParameters} />
-      <TableColumn<Columns> label="Proxy Method" index={Columns::ProxyMethod} />
-      <TableColumn<Columns> label="Proxy Body" index={Columns::ProxyBody} />
+      <TableColumn<Columns>
+        label="Name"
+        index={Columns::Name}
+        sortby={sortby.clone()}
+        onsort={onsort.clone()}
+      />
+      <TableColumn<Columns>
+        label="ID"
+        index={Columns::Id}
+        sortby={sortby.clone()}
+        onsort={onsort.clone()}
+      />
+      <TableColumn<Columns> label="Data Address Type" index={Columns::DataAddressType} />
+      <TableColumn<Columns> label="Location" index={Columns::Location} />
+      { for props.property_columns.iter().map(|name| html_nested!(
+        <TableColumn<Columns> label={name.clone()} index={Columns::Property(name.clone())} />
+      )) }
       <TableColumn<Columns> label="" index={Columns::Actions} />
     </TableHeader<Columns>>
   };
 
-  let total_entries: Option<usize> = None;
+  let total_entries = page_total_entries(
+    props.offset,
+    props.limit,
+    props.asset_items.len(),
+    props.total_entries,
+  );
 
   let nav_callback = use_callback(
     (
@@ -41,7 +148,7 @@ pub fn ListAssets(props: &ListAssetsProps) -> Html {
       let offset = match page {
         Navigation::First => 0,
         Navigation::Last => (total_entries.unwrap_or_default().saturating_sub(1) / limit) * limit,
-        Navigation::Previous => *offset - limit,
+        Navigation::Previous => offset.saturating_sub(*limit),
         Navigation::Next => *offset + limit,
         Navigation::Page(n) => n * limit,
       };
@@ -56,6 +163,7 @@ pub fn ListAssets(props: &ListAssetsProps) -> Html {
     .map(|asset_item| AssetRenderer {
       asset_item: asset_item.clone(),
       ondelete: props.ondelete.clone(),
+      onduplicate: props.onduplicate.clone(),
     })
     .collect();
 
@@ -65,8 +173,44 @@ pub fn ListAssets(props: &ListAssetsProps) -> Html {
     <>
       <Toolbar>
         <ToolbarContent>
+          <ToolbarItem>
+            <QueryFilterToolbar
+              properties={FILTER_PROPERTIES.to_vec()}
+              filters={props.list_query.filters.clone()}
+              onchange={onchange_filters}
+            />
+          </ToolbarItem>
+          <ToolbarItem>
+            <TextInput
+              placeholder="Property column, e.g. dct:title"
+              value={(*new_property_column).clone()}
+              onchange={onchange_new_property_column}
+            />
+          </ToolbarItem>
+          <ToolbarItem>
+            <Button variant={ButtonVariant::Secondary} onclick={onadd_property_column}>
+              { "Add Column" }
+            </Button>
+          </ToolbarItem>
+          <ToolbarItem>
+            <Flex>
+              { for props.property_columns.iter().map(|name| {
+                let name = name.clone();
+
+                html_nested!(
+                  <FlexItem>
+                    <Label
+                      label={name.clone()}
+                      onclose={onremove_property_column.reform(move |_| name.clone())}
+                    />
+                  </FlexItem>
+                )
+              }) }
+            </Flex>
+          </ToolbarItem>
           <ToolbarItem r#type={ToolbarItemType::Pagination}>
             <Pagination
+              {total_entries}
               offset={props.offset}
               entries_per_page_choices={vec![5, 10, 25, 50, 100]}
               selected_choice={props.limit}
@@ -89,11 +233,9 @@ pub fn ListAssets(props: &ListAssetsProps) -> Html {
 enum Columns {
   Id,
   Name,
-  BaseUrl,
-  ProxyPath,
-  ProxyQueryParameters,
-  ProxyMethod,
-  ProxyBody,
+  DataAddressType,
+  Location,
+  Property(String),
   Actions,
 }
 
@@ -101,22 +243,49 @@ enum Columns {
 struct AssetRenderer {
   asset_item: AssetItem,
   ondelete: Callback<String>,
+  onduplicate: Callback<String>,
 }
 
 impl TableEntryRenderer<Columns> for AssetRenderer {
   fn render_cell(&self, context: CellContext<'_, Columns>) -> Cell {
     match context.column {
-      Columns::Id => html! { self.asset_item.id.to_owned() },
+      Columns::Id => {
+        let id = self.asset_item.id.clone();
+
+        html!(<Link<AppRoute> to={AppRoute::Asset { id: id.clone() }}>{ id }</Link<AppRoute>>)
+      }
       Columns::Name => html!(self.asset_item.name.to_owned()),
-      Columns::BaseUrl => html!(self.asset_item.base_url.to_owned()),
-      Columns::ProxyPath => html!(self.asset_item.proxy_path),
-      Columns::ProxyQueryParameters => html!(self.asset_item.proxy_query_params),
-      Columns::ProxyMethod => html!(self.asset_item.proxy_method),
-      Columns::ProxyBody => html!(self.asset_item.proxy_body),
+      Columns::DataAddressType => {
+        html!(<Label label={self.asset_item.data_address_type.clone()} />)
+      }
+      Columns::Location => html!(self.asset_item.location.to_owned()),
+      Columns::Property(name) => {
+        html!(
+          self
+            .asset_item
+            .property(name)
+            .unwrap_or_default()
+            .to_owned()
+        )
+      }
       Columns::Actions => {
         let asset_id = self.asset_item.id.to_string();
+        let duplicate_id = asset_id.clone();
 
-        html!(<DeleteAsset {asset_id} ondelete={self.ondelete.clone()} />)
+        html!(
+          <Flex>
+            <FlexItem>
+              <Button
+                variant={ButtonVariant::Secondary}
+                icon={Icon::Copy}
+                onclick={self.onduplicate.reform(move |_| duplicate_id.clone())}
+              >
+                { "Duplicate" }
+              </Button>
+            </FlexItem>
+            <FlexItem><DeleteAsset {asset_id} ondelete={self.ondelete.clone()} /></FlexItem>
+          </Flex>
+        )
       }
     }
     .into()
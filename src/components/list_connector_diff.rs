@@ -0,0 +1,104 @@
+use crate::models::EntityKind;
+use crate::services::{DiffEntry, DiffStatus};
+use patternfly_yew::prelude::*;
+use std::collections::BTreeSet;
+use std::rc::Rc;
+use yew::prelude::*;
+
+#[derive(Clone, Debug, PartialEq, Properties)]
+pub struct ListConnectorDiffProps {
+  pub entries: Vec<DiffEntry>,
+  pub selection: BTreeSet<(EntityKind, String)>,
+  #[prop_or_default]
+  pub onselect: Callback<((EntityKind, String), bool)>,
+}
+
+#[component]
+pub fn ListConnectorDiff(props: &ListConnectorDiffProps) -> Html {
+  let header = html_nested! {
+    <TableHeader<Columns>>
+      <TableColumn<Columns> index={Columns::Select} />
+      <TableColumn<Columns> label="Kind" index={Columns::Kind} />
+      <TableColumn<Columns> label="ID" index={Columns::Id} />
+      <TableColumn<Columns> label="Status" index={Columns::Status} />
+      <TableColumn<Columns> label="Changes" index={Columns::Changes} />
+    </TableHeader<Columns>>
+  };
+
+  let entries = props
+    .entries
+    .iter()
+    .cloned()
+    .map(|diff_entry| {
+      let key = (diff_entry.kind, diff_entry.id.clone());
+
+      DiffEntryRenderer {
+        selected: props.selection.contains(&key),
+        onselect: props
+          .onselect
+          .reform(move |selected| (key.clone(), selected)),
+        diff_entry,
+      }
+    })
+    .collect::<Vec<_>>();
+
+  let (entries, _) = use_table_data(MemoizedTableModel::new(Rc::new(entries)));
+
+  html!(
+    <Table<Columns, UseTableData<Columns, MemoizedTableModel<DiffEntryRenderer>>>
+      mode={TableMode::Compact}
+      {header}
+      {entries}
+    />
+  )
+}
+
+#[derive(Clone, Debug, Eq, PartialEq)]
+enum Columns {
+  Select,
+  Kind,
+  Id,
+  Status,
+  Changes,
+}
+
+#[derive(Clone, Debug)]
+struct DiffEntryRenderer {
+  diff_entry: DiffEntry,
+  selected: bool,
+  onselect: Callback<bool>,
+}
+
+impl TableEntryRenderer<Columns> for DiffEntryRenderer {
+  fn render_cell(&self, context: CellContext<'_, Columns>) -> Cell {
+    match context.column {
+      Columns::Select => {
+        let onchange = self.onselect.reform(bool::from);
+
+        html!(<Checkbox checked={self.selected} {onchange} />)
+      }
+      Columns::Kind => html!(self.diff_entry.kind.to_string()),
+      Columns::Id => html!(self.diff_entry.id.to_string()),
+      Columns::Status => match self.diff_entry.status {
+        DiffStatus::Added => html!(<Label color={Color::Green} label="Only in other" />),
+        DiffStatus::Removed => html!(<Label color={Color::Orange} label="Only in current" />),
+        DiffStatus::Changed => html!(<Label color={Color::Blue} label="Changed" />),
+      },
+      Columns::Changes => html!(
+        <List>
+          { for self.diff_entry.changes.iter().map(|change| html_nested!(
+            <ListItem>
+              { format!(
+                "{}: {} → {}",
+                change.path,
+                change.left.as_deref().unwrap_or("—"),
+                change.right.as_deref().unwrap_or("—"),
+              ) }
+            </ListItem>
+          )) }
+        </List>
+      ),
+    }
+    .into()
+  }
+}
@@ -1,31 +1,113 @@
-use crate::models::ContractAgreementItem;
+use crate::components::{QueryFilterToolbar, sorted_list_query, table_sort_by};
+use crate::models::{ContractAgreementItem, ListQuery, QueryProperty};
+use crate::routes::AppRoute;
+use crate::services::page_total_entries;
 use patternfly_yew::prelude::*;
 use std::rc::Rc;
 use yew::prelude::*;
+use yew_nested_router::components::Link;
+
+const SORT_COLUMNS: [(Columns, QueryProperty); 5] = [
+  (Columns::Id, QueryProperty::new("ID", "id")),
+  (
+    Columns::ContractSigningDate,
+    QueryProperty::new("Contract Signing Date", "contractSigningDate"),
+  ),
+  (
+    Columns::ConsumerId,
+    QueryProperty::new("Consumer ID", "consumerId"),
+  ),
+  (
+    Columns::ProviderId,
+    QueryProperty::new("Provider ID", "providerId"),
+  ),
+  (Columns::AssetId, QueryProperty::new("Asset ID", "assetId")),
+];
+
+const FILTER_PROPERTIES: [QueryProperty; 5] = [
+  QueryProperty::new("ID", "id"),
+  QueryProperty::new("Contract Signing Date", "contractSigningDate"),
+  QueryProperty::new("Consumer ID", "consumerId"),
+  QueryProperty::new("Provider ID", "providerId"),
+  QueryProperty::new("Asset ID", "assetId"),
+];
 
 #[derive(Clone, Debug, PartialEq, Properties)]
 pub struct ListContractAgreementsProps {
   pub contract_agreement_items: Vec<ContractAgreementItem>,
   pub offset: usize,
+  #[prop_or_default]
+  pub total_entries: Option<usize>,
   pub limit: usize,
   pub onoffset: Callback<usize>,
   pub onlimit: Callback<usize>,
+  pub list_query: ListQuery,
+  pub onquery: Callback<ListQuery>,
 }
 
 #[component]
 pub fn ListContractAgreements(props: &ListContractAgreementsProps) -> Html {
+  let sortby = table_sort_by(&SORT_COLUMNS, &props.list_query);
+
+  let onsort = use_callback(
+    (props.list_query.clone(), props.onquery.clone()),
+    |sort_by, (list_query, onquery)| {
+      onquery.emit(sorted_list_query(&SORT_COLUMNS, list_query, sort_by));
+    },
+  );
+
+  let onchange_filters = use_callback(
+    (props.list_query.clone(), props.onquery.clone()),
+    |filters, (list_query, onquery)| {
+      onquery.emit(ListQuery {
+        filters,
+        ..list_query.clone()
+      });
+    },
+  );
+
   let header = html_nested! {
     <TableHeader<Columns>>
-      <TableColumn<Columns> label="ID" index={Columns::Id} />
-      <TableColumn<Columns> label="Contract Signing Date" index={Columns::ContractSigningDate} />
-      <TableColumn<Columns> label="Consumer ID" index={Columns::ConsumerId} />
-      <TableColumn<Columns> label="Provider ID" index={Columns::ProviderId} />
-      <TableColumn<Columns> label="Asset ID" index={Columns::AssetId} />
+      <TableColumn<Columns>
+        label="ID"
+        index={Columns::Id}
+        sortby={sortby.clone()}
+        onsort={onsort.clone()}
+      />
+      <TableColumn<Columns>
+        label="Contract Signing Date"
+        index={Columns::ContractSigningDate}
+        sortby={sortby.clone()}
+        onsort={onsort.clone()}
+      />
+      <TableColumn<Columns>
+        label="Consumer ID"
+        index={Columns::ConsumerId}
+        sortby={sortby.clone()}
+        onsort={onsort.clone()}
+      />
+      <TableColumn<Columns>
+        label="Provider ID"
+        index={Columns::ProviderId}
+        sortby={sortby.clone()}
+        onsort={onsort.clone()}
+      />
+      <TableColumn<Columns>
+        label="Asset ID"
+        index={Columns::AssetId}
+        sortby={sortby.clone()}
+        onsort={onsort.clone()}
+      />
       <TableColumn<Columns> label="Policy ID" index={Columns::PolicyID} />
     </TableHeader<Columns>>
   };
 
-  let total_entries: Option<usize> = None;
+  let total_entries = page_total_entries(
+    props.offset,
+    props.limit,
+    props.contract_agreement_items.len(),
+    props.total_entries,
+  );
 
   let nav_callback = use_callback(
     (
@@ -38,7 +120,7 @@ pub fn ListContractAgreements(props: &ListContractAgreementsProps) -> Html {
       let offset = match page {
         Navigation::First => 0,
         Navigation::Last => (total_entries.unwrap_or_default().saturating_sub(1) / limit) * limit,
-        Navigation::Previous => *offset - limit,
+        Navigation::Previous => offset.saturating_sub(*limit),
         Navigation::Next => *offset + limit,
         Navigation::Page(n) => n * limit,
       };
@@ -58,8 +140,16 @@ pub fn ListContractAgreements(props: &ListContractAgreementsProps) -> Html {
     <>
       <Toolbar>
         <ToolbarContent>
+          <ToolbarItem>
+            <QueryFilterToolbar
+              properties={FILTER_PROPERTIES.to_vec()}
+              filters={props.list_query.filters.clone()}
+              onchange={onchange_filters}
+            />
+          </ToolbarItem>
           <ToolbarItem r#type={ToolbarItemType::Pagination}>
             <Pagination
+              {total_entries}
               offset={props.offset}
               entries_per_page_choices={vec![5, 10, 25, 50, 100]}
               selected_choice={props.limit}
@@ -96,7 +186,11 @@ impl ContractAgreementItemRenderer {}
 impl TableEntryRenderer<Columns> for ContractAgreementItemRenderer {
   fn render_cell(&self, context: CellContext<'_, Columns>) -> Cell {
     match context.column {
-      Columns::Id => html! { self.0.id.to_string() },
+      Columns::Id => {
+        let id = self.0.id.clone();
+
+        html!(<Link<AppRoute> to={AppRoute::ContractAgreement { id: id.clone() }}>{ id }</Link<AppRoute>>)
+      }
       Columns::ContractSigningDate => html!(self.0.signing_date.to_string()),
       Columns::ConsumerId => html!(self.0.consumer_id.to_string()),
       Columns::ProviderId => html! { self.0.provider_id.to_string() },
@@ -1,30 +1,96 @@
-use crate::models::ContractDefinitionItem;
+use crate::components::{QueryFilterToolbar, sorted_list_query, table_sort_by};
+use crate::models::{ContractDefinitionItem, ListQuery, QueryProperty};
+use crate::routes::AppRoute;
+use crate::services::page_total_entries;
 use patternfly_yew::prelude::*;
 use std::rc::Rc;
 use yew::prelude::*;
+use yew_nested_router::components::Link;
+
+const SORT_COLUMNS: [(Columns, QueryProperty); 3] = [
+  (Columns::Id, QueryProperty::new("ID", "id")),
+  (
+    Columns::AccessPolicyId,
+    QueryProperty::new("Access Policy ID", "accessPolicyId"),
+  ),
+  (
+    Columns::ContractPolicyId,
+    QueryProperty::new("Contract Policy ID", "contractPolicyId"),
+  ),
+];
+
+const FILTER_PROPERTIES: [QueryProperty; 3] = [
+  QueryProperty::new("ID", "id"),
+  QueryProperty::new("Access Policy ID", "accessPolicyId"),
+  QueryProperty::new("Contract Policy ID", "contractPolicyId"),
+];
 
 #[derive(Clone, Debug, PartialEq, Properties)]
 pub struct ListContractDefinitionsProps {
   pub contract_definition_items: Vec<ContractDefinitionItem>,
   pub offset: usize,
+  #[prop_or_default]
+  pub total_entries: Option<usize>,
   pub limit: usize,
   pub onoffset: Callback<usize>,
   pub onlimit: Callback<usize>,
+  pub list_query: ListQuery,
+  pub onquery: Callback<ListQuery>,
   pub ondelete: Callback<String>,
+  pub onduplicate: Callback<String>,
 }
 
 #[component]
 pub fn ListContractDefinitions(props: &ListContractDefinitionsProps) -> Html {
+  let sortby = table_sort_by(&SORT_COLUMNS, &props.list_query);
+
+  let onsort = use_callback(
+    (props.list_query.clone(), props.onquery.clone()),
+    |sort_by, (list_query, onquery)| {
+      onquery.emit(sorted_list_query(&SORT_COLUMNS, list_query, sort_by));
+    },
+  );
+
+  let onchange_filters = use_callback(
+    (props.list_query.clone(), props.onquery.clone()),
+    |filters, (list_query, onquery)| {
+      onquery.emit(ListQuery {
+        filters,
+        ..list_query.clone()
+      });
+    },
+  );
+
   let header = html_nested! {
     <TableHeader<Columns>>
-      <TableColumn<Columns> label="ID" index={Columns::Id} />
-      <TableColumn<Columns> label="Access Policy ID" index={Columns::AccessPolicyId} />
-      <TableColumn<Columns> label="Contract Policy ID" index={Columns::ContractPolicyId} />
+      <TableColumn<Columns>
+        label="ID"
+        index={Columns::Id}
+        sortby={sortby.clone()}
+        onsort={onsort.clone()}
+      />
+      <TableColumn<Columns>
+        label="Access Policy ID"
+        index={Columns::AccessPolicyId}
+        sortby={sortby.clone()}
+        onsort={onsort.clone()}
+      />
+      <TableColumn<Columns>
+        label="Contract Policy ID"
+        index={Columns::ContractPolicyId}
+        sortby={sortby.clone()}
+        onsort={onsort.clone()}
+      />
       <TableColumn<Columns> label="" index={Columns::Actions} />
     </TableHeader<Columns>>
   };
 
-  let total_entries: Option<usize> = None;
+  let total_entries = page_total_entries(
+    props.offset,
+    props.limit,
+    props.contract_definition_items.len(),
+    props.total_entries,
+  );
 
   let nav_callback = use_callback(
     (
@@ -37,7 +103,7 @@ pub fn ListContractDefinitions(props: &ListContractDefinitionsProps) -> Html {
       let offset = match page {
         Navigation::First => 0,
         Navigation::Last => (total_entries.unwrap_or_default().saturating_sub(1) / limit) * limit,
-        Navigation::Previous => *offset - limit,
+        Navigation::Previous => offset.saturating_sub(*limit),
         Navigation::Next => *offset + limit,
         Navigation::Page(n) => n * limit,
       };
@@ -51,6 +117,7 @@ pub fn ListContractDefinitions(props: &ListContractDefinitionsProps) -> Html {
     .map(|contract_definition_item| ContractDefinitionItemRenderer {
       contract_definition_item: contract_definition_item.clone(),
       ondelete: props.ondelete.clone(),
+      onduplicate: props.onduplicate.clone(),
     })
     .collect();
 
@@ -60,8 +127,16 @@ pub fn ListContractDefinitions(props: &ListContractDefinitionsProps) -> Html {
     <>
       <Toolbar>
         <ToolbarContent>
+          <ToolbarItem>
+            <QueryFilterToolbar
+              properties={FILTER_PROPERTIES.to_vec()}
+              filters={props.list_query.filters.clone()}
+              onchange={onchange_filters}
+            />
+          </ToolbarItem>
           <ToolbarItem r#type={ToolbarItemType::Pagination}>
             <Pagination
+              {total_entries}
               offset={props.offset}
               entries_per_page_choices={vec![5, 10, 25, 50, 100]}
               selected_choice={props.limit}
@@ -92,6 +167,7 @@ enum Columns {
 struct ContractDefinitionItemRenderer {
   contract_definition_item: ContractDefinitionItem,
   ondelete: Callback<String>,
+  onduplicate: Callback<String>,
 }
 
 impl ContractDefinitionItemRenderer {}
@@ -99,15 +175,39 @@ impl ContractDefinitionItemRenderer {}
 impl TableEntryRenderer<Columns> for ContractDefinitionItemRenderer {
   fn render_cell(&self, context: CellContext<'_, Columns>) -> Cell {
     match context.column {
-      Columns::Id => html! { self.contract_definition_item.id.to_string() },
-      Columns::AccessPolicyId => html!(self.contract_definition_item.access_policy_id.to_string()),
-      Columns::ContractPolicyId => html!(
-        self.contract_definition_item.contract_policy_id.to_string()
-      ),
+      Columns::Id => {
+        let id = self.contract_definition_item.id.clone();
+
+        html!(<Link<AppRoute> to={AppRoute::ContractDefinition { id: id.clone() }}>{ id }</Link<AppRoute>>)
+      }
+      Columns::AccessPolicyId => {
+        let id = self.contract_definition_item.access_policy_id.clone();
+
+        html!(<Link<AppRoute> to={AppRoute::PolicyDefinition { id: id.clone() }}>{ id }</Link<AppRoute>>)
+      }
+      Columns::ContractPolicyId => {
+        let id = self.contract_definition_item.contract_policy_id.clone();
+
+        html!(<Link<AppRoute> to={AppRoute::PolicyDefinition { id: id.clone() }}>{ id }</Link<AppRoute>>)
+      }
       Columns::Actions => {
         let contract_definition_id = self.contract_definition_item.id.to_string();
+        let duplicate_id = contract_definition_id.clone();
 
-        html!(<DeleteContractDefinition {contract_definition_id} ondelete={self.ondelete.clone()} />)
+        html!(
+          <Flex>
+            <FlexItem>
+              <Button
+                variant={ButtonVariant::Secondary}
+                icon={Icon::Copy}
+                onclick={self.onduplicate.reform(move |_| duplicate_id.clone())}
+              >
+                { "Duplicate" }
+              </Button>
+            </FlexItem>
+            <FlexItem><DeleteContractDefinition {contract_definition_id} ondelete={self.ondelete.clone()} /></FlexItem>
+          </Flex>
+        )
       }
     }
     .into()
@@ -1,32 +1,134 @@
-use crate::models::ContractNegotiationItem;
+use crate::components::{QueryFilterToolbar, WatchButton, sorted_list_query, table_sort_by};
+use crate::contexts::WatchedKind;
+use crate::hooks::use_changed_states;
+use crate::models::{ContractNegotiationItem, ListQuery, QueryProperty};
+use crate::routes::AppRoute;
+use crate::services::page_total_entries;
 use patternfly_yew::prelude::*;
 use std::rc::Rc;
 use yew::prelude::*;
+use yew_nested_router::components::Link;
+
+const SORT_COLUMNS: [(Columns, QueryProperty); 7] = [
+  (Columns::Id, QueryProperty::new("ID", "id")),
+  (Columns::State, QueryProperty::new("State", "state")),
+  (
+    Columns::ContractAgreementId,
+    QueryProperty::new("Contract Agreement ID", "contractAgreement.id"),
+  ),
+  (
+    Columns::CounterPartyId,
+    QueryProperty::new("Counter Party ID", "counterPartyId"),
+  ),
+  (
+    Columns::CounterPartyAddress,
+    QueryProperty::new("Counter Party Address", "counterPartyAddress"),
+  ),
+  (
+    Columns::Protocol,
+    QueryProperty::new("Protocol", "protocol"),
+  ),
+  (Columns::Kind, QueryProperty::new("Kind", "type")),
+];
+
+const FILTER_PROPERTIES: [QueryProperty; 7] = [
+  QueryProperty::new("ID", "id"),
+  QueryProperty::new("State", "state"),
+  QueryProperty::new("Contract Agreement ID", "contractAgreement.id"),
+  QueryProperty::new("Counter Party ID", "counterPartyId"),
+  QueryProperty::new("Counter Party Address", "counterPartyAddress"),
+  QueryProperty::new("Protocol", "protocol"),
+  QueryProperty::new("Kind", "type"),
+];
 
 #[derive(Clone, Debug, PartialEq, Properties)]
 pub struct ListContractNegotiationsProps {
   pub contract_negotiation_items: Vec<ContractNegotiationItem>,
   pub offset: usize,
+  #[prop_or_default]
+  pub total_entries: Option<usize>,
   pub limit: usize,
   pub onoffset: Callback<usize>,
   pub onlimit: Callback<usize>,
+  pub list_query: ListQuery,
+  pub onquery: Callback<ListQuery>,
 }
 
 #[component]
 pub fn ListContractNegotiations(props: &ListContractNegotiationsProps) -> Html {
+  let sortby = table_sort_by(&SORT_COLUMNS, &props.list_query);
+
+  let onsort = use_callback(
+    (props.list_query.clone(), props.onquery.clone()),
+    |sort_by, (list_query, onquery)| {
+      onquery.emit(sorted_list_query(&SORT_COLUMNS, list_query, sort_by));
+    },
+  );
+
+  let onchange_filters = use_callback(
+    (props.list_query.clone(), props.onquery.clone()),
+    |filters, (list_query, onquery)| {
+      onquery.emit(ListQuery {
+        filters,
+        ..list_query.clone()
+      });
+    },
+  );
+
   let header = html_nested! {
     <TableHeader<Columns>>
-      <TableColumn<Columns> label="ID" index={Columns::Id} />
-      <TableColumn<Columns> label="State" index={Columns::State} />
-      <TableColumn<Columns> label="Contract Agreement ID" index={Columns::ContractAgreementId} />
-      <TableColumn<Columns> label="Counter Party ID" index={Columns::CounterPartyId} />
-      <TableColumn<Columns> label="Counter Party Address" index={Columns::CounterPartyAddress} />
-      <TableColumn<Columns> label="Protocol" index={Columns::Protocol} />
-      <TableColumn<Columns> label="Kind" index={Columns::Kind} />
+      <TableColumn<Columns>
+        label="ID"
+        index={Columns::Id}
+        sortby={sortby.clone()}
+        onsort={onsort.clone()}
+      />
+      <TableColumn<Columns>
+        label="State"
+        index={Columns::State}
+        sortby={sortby.clone()}
+        onsort={onsort.clone()}
+      />
+      <TableColumn<Columns>
+        label="Contract Agreement ID"
+        index={Columns::ContractAgreementId}
+        sortby={sortby.clone()}
+        onsort={onsort.clone()}
+      />
+      <TableColumn<Columns>
+        label="Counter Party ID"
+        index={Columns::CounterPartyId}
+        sortby={sortby.clone()}
+        onsort={onsort.clone()}
+      />
+      <TableColumn<Columns>
+        label="Counter Party Address"
+        index={Columns::CounterPartyAddress}
+        sortby={sortby.clone()}
+        onsort={onsort.clone()}
+      />
+      <TableColumn<Columns>
+        label="Protocol"
+        index={Columns::Protocol}
+        sortby={sortby.clone()}
+        onsort={onsort.clone()}
+      />
+      <TableColumn<Columns>
+        label="Kind"
+        index={Columns::Kind}
+        sortby={sortby.clone()}
+        onsort={onsort.clone()}
+      />
+      <TableColumn<Columns> label="" index={Columns::Actions} />
     </TableHeader<Columns>>
   };
 
-  let total_entries: Option<usize> = None;
+  let total_entries = page_total_entries(
+    props.offset,
+    props.limit,
+    props.contract_negotiation_items.len(),
+    props.total_entries,
+  );
 
   let nav_callback = use_callback(
     (
@@ -39,7 +141,7 @@ pub fn ListContractNegotiations(props: &ListContractNegotiationsProps) -> Html {
       let offset = match page {
         Navigation::First => 0,
         Navigation::Last => (total_entries.unwrap_or_default().saturating_sub(1) / limit) * limit,
-        Navigation::Previous => *offset - limit,
+        Navigation::Previous => offset.saturating_sub(*limit),
         Navigation::Next => *offset + limit,
         Navigation::Page(n) => n * limit,
       };
@@ -47,11 +149,27 @@ pub fn ListContractNegotiations(props: &ListContractNegotiationsProps) -> Html {
     },
   );
 
+  let changed_states = use_changed_states(
+    props
+      .contract_negotiation_items
+      .iter()
+      .map(|contract_negotiation_item| {
+        (
+          contract_negotiation_item.id.clone(),
+          contract_negotiation_item.state.clone(),
+        )
+      })
+      .collect(),
+  );
+
   let rows = props
     .contract_negotiation_items
     .iter()
     .map(|contract_negotiation_item| {
-      ContractNegotiationItemRenderer(contract_negotiation_item.clone())
+      ContractNegotiationItemRenderer(
+        contract_negotiation_item.clone(),
+        changed_states.contains(&contract_negotiation_item.id),
+      )
     })
     .collect();
 
@@ -61,8 +179,16 @@ pub fn ListContractNegotiations(props: &ListContractNegotiationsProps) -> Html {
     <>
       <Toolbar>
         <ToolbarContent>
+          <ToolbarItem>
+            <QueryFilterToolbar
+              properties={FILTER_PROPERTIES.to_vec()}
+              filters={props.list_query.filters.clone()}
+              onchange={onchange_filters}
+            />
+          </ToolbarItem>
           <ToolbarItem r#type={ToolbarItemType::Pagination}>
             <Pagination
+              {total_entries}
               offset={props.offset}
               entries_per_page_choices={vec![5, 10, 25, 50, 100]}
               selected_choice={props.limit}
@@ -90,21 +216,37 @@ enum Columns {
   CounterPartyAddress,
   Protocol,
   Kind,
+  Actions,
 }
 
 #[derive(Clone, Debug)]
-struct ContractNegotiationItemRenderer(ContractNegotiationItem);
+struct ContractNegotiationItemRenderer(ContractNegotiationItem, bool);
 
 impl TableEntryRenderer<Columns> for ContractNegotiationItemRenderer {
   fn render_cell(&self, context: CellContext<'_, Columns>) -> Cell {
     match context.column {
-      Columns::Id => html! { self.0.id.to_string() },
-      Columns::State => html! { self.0.state.to_string() },
-      Columns::ContractAgreementId => html! { self.0.contract_agreement_id.to_string() },
+      Columns::Id => {
+        let id = self.0.id.clone();
+
+        html!(<Link<AppRoute> to={AppRoute::ContractNegotiation { id: id.clone() }}>{ id }</Link<AppRoute>>)
+      }
+      Columns::State if self.1 => html!(
+        <Label color={Color::Blue} label={self.0.state.clone()} />
+      ),
+      Columns::State => html!(self.0.state.to_string()),
+      Columns::ContractAgreementId if self.0.contract_agreement_id.is_empty() => html!(),
+      Columns::ContractAgreementId => {
+        let id = self.0.contract_agreement_id.clone();
+
+        html!(<Link<AppRoute> to={AppRoute::ContractAgreement { id: id.clone() }}>{ id }</Link<AppRoute>>)
+      }
       Columns::CounterPartyId => html! { self.0.counter_party_id.to_string() },
       Columns::CounterPartyAddress => html! { self.0.counter_party_address.to_string() },
       Columns::Protocol => html! { self.0.protocol.to_string() },
       Columns::Kind => html! { self.0.kind.to_string() },
+      Columns::Actions => {
+        html!(<WatchButton kind={WatchedKind::ContractNegotiation} id={self.0.id.clone()} />)
+      }
     }
     .into()
   }
@@ -0,0 +1,83 @@
+use crate::models::{DataPlaneInstanceItem, DataPlaneInstanceState};
+use patternfly_yew::prelude::*;
+use std::rc::Rc;
+use yew::prelude::*;
+
+#[derive(Clone, Debug, PartialEq, Properties)]
+pub struct ListDataPlanesProps {
+  pub data_plane_instance_items: Vec<DataPlaneInstanceItem>,
+}
+
+#[component]
+pub fn ListDataPlanes(props: &ListDataPlanesProps) -> Html {
+  let header = html_nested! {
+    <TableHeader<Columns>>
+      <TableColumn<Columns> label="ID" index={Columns::Id} />
+      <TableColumn<Columns> label="URL" index={Columns::Url} />
+      <TableColumn<Columns> label="State" index={Columns::State} />
+      <TableColumn<Columns> label="Source Types" index={Columns::SourceTypes} />
+      <TableColumn<Columns> label="Destination Types" index={Columns::DestinationTypes} />
+      <TableColumn<Columns> label="Transfer Types" index={Columns::TransferTypes} />
+    </TableHeader<Columns>>
+  };
+
+  let rows = props
+    .data_plane_instance_items
+    .iter()
+    .map(|data_plane_instance_item| DataPlaneInstanceItemRenderer(data_plane_instance_item.clone()))
+    .collect();
+
+  let (entries, _) = use_table_data(MemoizedTableModel::new(Rc::new(rows)));
+
+  html!(
+    <Table<Columns, UseTableData<Columns, MemoizedTableModel<DataPlaneInstanceItemRenderer>>>
+      mode={TableMode::Compact}
+      {header}
+      {entries}
+    />
+  )
+}
+
+#[derive(Clone, Debug, Eq, PartialEq)]
+enum Columns {
+  Id,
+  Url,
+  State,
+  SourceTypes,
+  DestinationTypes,
+  TransferTypes,
+}
+
+#[derive(Clone, Debug)]
+struct DataPlaneInstanceItemRenderer(DataPlaneInstanceItem);
+
+impl TableEntryRenderer<Columns> for DataPlaneInstanceItemRenderer {
+  fn render_cell(&self, context: CellContext<'_, Columns>) -> Cell {
+    match context.column {
+      Columns::Id => html!(self.0.id.to_string()),
+      Columns::Url => html!(self.0.url.to_string()),
+      Columns::State => {
+        let color = match self.0.state {
+          DataPlaneInstanceState::Available => Color::Green,
+          DataPlaneInstanceState::Registered => Color::Blue,
+          DataPlaneInstanceState::Unavailable => Color::Red,
+          _ => Color::Grey,
+        };
+
+        html!(<Label {color} label={self.0.state.to_string()} />)
+      }
+      Columns::SourceTypes => types_list(&self.0.allowed_source_types),
+      Columns::DestinationTypes => types_list(&self.0.allowed_destination_types),
+      Columns::TransferTypes => types_list(&self.0.allowed_transfer_types),
+    }
+    .into()
+  }
+}
+
+fn types_list(types: &[String]) -> Html {
+  html!(
+    <List>
+      { for types.iter().map(|r#type| html_nested!(<ListItem>{ r#type.clone() }</ListItem>)) }
+    </List>
+  )
+}
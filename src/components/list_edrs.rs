@@ -0,0 +1,265 @@
+use crate::components::{QueryFilterToolbar, sorted_list_query, table_sort_by};
+use crate::models::{EdrEntryItem, ListQuery, QueryProperty};
+use crate::routes::AppRoute;
+use crate::services::page_total_entries;
+use patternfly_yew::prelude::*;
+use std::rc::Rc;
+use yew::prelude::*;
+use yew_nested_router::components::Link;
+
+const SORT_COLUMNS: [(Columns, QueryProperty); 5] = [
+  (
+    Columns::TransferProcessId,
+    QueryProperty::new("Transfer Process ID", "transferProcessId"),
+  ),
+  (Columns::AssetId, QueryProperty::new("Asset ID", "assetId")),
+  (
+    Columns::AgreementId,
+    QueryProperty::new("Agreement ID", "agreementId"),
+  ),
+  (
+    Columns::ProviderId,
+    QueryProperty::new("Provider ID", "providerId"),
+  ),
+  (
+    Columns::CreatedAt,
+    QueryProperty::new("Created At", "createdAt"),
+  ),
+];
+
+const FILTER_PROPERTIES: [QueryProperty; 4] = [
+  QueryProperty::new("Transfer Process ID", "transferProcessId"),
+  QueryProperty::new("Asset ID", "assetId"),
+  QueryProperty::new("Agreement ID", "agreementId"),
+  QueryProperty::new("Provider ID", "providerId"),
+];
+
+#[derive(Clone, Debug, PartialEq, Properties)]
+pub struct ListEdrsProps {
+  pub edr_entry_items: Vec<EdrEntryItem>,
+  pub offset: usize,
+  #[prop_or_default]
+  pub total_entries: Option<usize>,
+  pub limit: usize,
+  pub onoffset: Callback<usize>,
+  pub onlimit: Callback<usize>,
+  pub list_query: ListQuery,
+  pub onquery: Callback<ListQuery>,
+  pub onrefresh: Callback<String>,
+  pub ondelete: Callback<String>,
+}
+
+#[component]
+pub fn ListEdrs(props: &ListEdrsProps) -> Html {
+  let sortby = table_sort_by(&SORT_COLUMNS, &props.list_query);
+
+  let onsort = use_callback(
+    (props.list_query.clone(), props.onquery.clone()),
+    |sort_by, (list_query, onquery)| {
+      onquery.emit(sorted_list_query(&SORT_COLUMNS, list_query, sort_by));
+    },
+  );
+
+  let onchange_filters = use_callback(
+    (props.list_query.clone(), props.onquery.clone()),
+    |filters, (list_query, onquery)| {
+      onquery.emit(ListQuery {
+        filters,
+        ..list_query.clone()
+      });
+    },
+  );
+
+  let header = html_nested! {
+    <TableHeader<Columns>>
+      <TableColumn<Columns>
+        label="Transfer Process ID"
+        index={Columns::TransferProcessId}
+        sortby={sortby.clone()}
+        onsort={onsort.clone()}
+      />
+      <TableColumn<Columns>
+        label="Asset ID"
+        index={Columns::AssetId}
+        sortby={sortby.clone()}
+        onsort={onsort.clone()}
+      />
+      <TableColumn<Columns>
+        label="Agreement ID"
+        index={Columns::AgreementId}
+        sortby={sortby.clone()}
+        onsort={onsort.clone()}
+      />
+      <TableColumn<Columns>
+        label="Provider ID"
+        index={Columns::ProviderId}
+        sortby={sortby.clone()}
+        onsort={onsort.clone()}
+      />
+      <TableColumn<Columns>
+        label="Created At"
+        index={Columns::CreatedAt}
+        sortby={sortby.clone()}
+        onsort={onsort.clone()}
+      />
+      <TableColumn<Columns> label="Expires" index={Columns::Expires} />
+      <TableColumn<Columns> label="" index={Columns::Actions} />
+    </TableHeader<Columns>>
+  };
+
+  let total_entries = page_total_entries(
+    props.offset,
+    props.limit,
+    props.edr_entry_items.len(),
+    props.total_entries,
+  );
+
+  let nav_callback = use_callback(
+    (
+      props.offset,
+      props.limit,
+      total_entries,
+      props.onoffset.clone(),
+    ),
+    |page: Navigation, (offset, limit, total_entries, onoffset)| {
+      let offset = match page {
+        Navigation::First => 0,
+        Navigation::Last => (total_entries.unwrap_or_default().saturating_sub(1) / limit) * limit,
+        Navigation::Previous => offset.saturating_sub(*limit),
+        Navigation::Next => *offset + limit,
+        Navigation::Page(n) => n * limit,
+      };
+
+      onoffset.emit(offset);
+    },
+  );
+
+  let now = chrono::Utc::now().timestamp();
+
+  let rows = props
+    .edr_entry_items
+    .iter()
+    .map(|edr_entry_item| EdrEntryRenderer {
+      edr_entry_item: edr_entry_item.clone(),
+      now,
+      onrefresh: props.onrefresh.clone(),
+      ondelete: props.ondelete.clone(),
+    })
+    .collect();
+
+  let (entries, _) = use_table_data(MemoizedTableModel::new(Rc::new(rows)));
+
+  html!(
+    <>
+      <Toolbar>
+        <ToolbarContent>
+          <ToolbarItem>
+            <QueryFilterToolbar
+              properties={FILTER_PROPERTIES.to_vec()}
+              filters={props.list_query.filters.clone()}
+              onchange={onchange_filters}
+            />
+          </ToolbarItem>
+          <ToolbarItem r#type={ToolbarItemType::Pagination}>
+            <Pagination
+              {total_entries}
+              offset={props.offset}
+              entries_per_page_choices={vec![5, 10, 25, 50, 100]}
+              selected_choice={props.limit}
+              onlimit={&props.onlimit}
+              onnavigation={&nav_callback}
+            />
+          </ToolbarItem>
+        </ToolbarContent>
+      </Toolbar>
+      <Table<Columns, UseTableData<Columns, MemoizedTableModel<EdrEntryRenderer>>>
+        mode={TableMode::Compact}
+        {header}
+        {entries}
+      />
+    </>
+  )
+}
+
+#[derive(Clone, Debug, Eq, PartialEq)]
+enum Columns {
+  TransferProcessId,
+  AssetId,
+  AgreementId,
+  ProviderId,
+  CreatedAt,
+  Expires,
+  Actions,
+}
+
+#[derive(Clone, Debug)]
+struct EdrEntryRenderer {
+  edr_entry_item: EdrEntryItem,
+  now: i64,
+  onrefresh: Callback<String>,
+  ondelete: Callback<String>,
+}
+
+impl TableEntryRenderer<Columns> for EdrEntryRenderer {
+  fn render_cell(&self, context: CellContext<'_, Columns>) -> Cell {
+    let item = &self.edr_entry_item;
+
+    match context.column {
+      Columns::TransferProcessId => {
+        let id = item.transfer_process_id.clone();
+
+        html!(<Link<AppRoute> to={AppRoute::TransferProcess { id: id.clone() }}>{ id }</Link<AppRoute>>)
+      }
+      Columns::AssetId => html!(item.asset_id.clone()),
+      Columns::AgreementId => {
+        let id = item.agreement_id.clone();
+
+        html!(<Link<AppRoute> to={AppRoute::ContractAgreement { id: id.clone() }}>{ id }</Link<AppRoute>>)
+      }
+      Columns::ProviderId => html!(item.provider_id.clone()),
+      Columns::CreatedAt => html!(item.created_at.clone()),
+      Columns::Expires => match item.expires_at {
+        Some(expires_at) => {
+          let timestamp = chrono::DateTime::from_timestamp(expires_at, 0)
+            .unwrap_or_default()
+            .to_string();
+
+          if expires_at <= self.now {
+            html!(<Label color={Color::Red} label={format!("Expired {timestamp}")} />)
+          } else {
+            html!(timestamp)
+          }
+        }
+        None => html!("Unknown"),
+      },
+      Columns::Actions => {
+        let id = item.transfer_process_id.clone();
+        let refresh_id = id.clone();
+
+        html!(
+          <Flex>
+            <FlexItem>
+              <Button
+                variant={ButtonVariant::Secondary}
+                icon={Icon::SyncAlt}
+                onclick={self.onrefresh.reform(move |_| refresh_id.clone())}
+              >
+                { "Refresh" }
+              </Button>
+            </FlexItem>
+            <FlexItem>
+              <Button
+                variant={ButtonVariant::Danger}
+                icon={Icon::Trash}
+                onclick={self.ondelete.reform(move |_| id.clone())}
+              >
+                { "Delete" }
+              </Button>
+            </FlexItem>
+          </Flex>
+        )
+      }
+    }
+    .into()
+  }
+}
@@ -1,23 +1,64 @@
-use crate::models::PolicyDefinitionItem;
+use crate::components::{QueryFilterToolbar, sorted_list_query, table_sort_by};
+use crate::models::{ListQuery, PolicyDefinitionItem, QueryProperty};
+use crate::routes::AppRoute;
+use crate::services::page_total_entries;
 use patternfly_yew::prelude::*;
 use std::rc::Rc;
 use yew::prelude::*;
+use yew_nested_router::components::Link;
+
+const SORT_COLUMNS: [(Columns, QueryProperty); 1] = [(Columns::Id, QueryProperty::new("ID", "id"))];
+
+const FILTER_PROPERTIES: [QueryProperty; 3] = [
+  QueryProperty::new("ID", "id"),
+  QueryProperty::new("Assignee", "policy.assignee"),
+  QueryProperty::new("Assigner", "policy.assigner"),
+];
 
 #[derive(Clone, Debug, PartialEq, Properties)]
 pub struct ListPoliciesProps {
   pub policy_definition_items: Vec<PolicyDefinitionItem>,
   pub offset: usize,
+  #[prop_or_default]
+  pub total_entries: Option<usize>,
   pub limit: usize,
   pub onoffset: Callback<usize>,
   pub onlimit: Callback<usize>,
+  pub list_query: ListQuery,
+  pub onquery: Callback<ListQuery>,
   pub ondelete: Callback<String>,
+  pub onduplicate: Callback<String>,
 }
 
 #[component]
 pub fn ListPolicies(props: &ListPoliciesProps) -> Html {
+  let sortby = table_sort_by(&SORT_COLUMNS, &props.list_query);
+
+  let onsort = use_callback(
+    (props.list_query.clone(), props.onquery.clone()),
+    |sort_by, (list_query, onquery)| {
+      onquery.emit(sorted_list_query(&SORT_COLUMNS, list_query, sort_by));
+    },
+  );
+
+  let onchange_filters = use_callback(
+    (props.list_query.clone(), props.onquery.clone()),
+    |filters, (list_query, onquery)| {
+      onquery.emit(ListQuery {
+        filters,
+        ..list_query.clone()
+      });
+    },
+  );
+
   let header = html_nested! {
     <TableHeader<Columns>>
-      <TableColumn<Columns> label="ID" index={Columns::Id} />
+      <TableColumn<Columns>
+        label="ID"
+        index={Columns::Id}
+        sortby={sortby.clone()}
+        onsort={onsort.clone()}
+      />
       <TableColumn<Columns> label="Kind" index={Columns::Kind} />
       <TableColumn<Columns> label="Assignee" index={Columns::Assignee} />
       <TableColumn<Columns> label="Assigner" index={Columns::Assigner} />
@@ -25,7 +66,12 @@ pub fn ListPolicies(props: &ListPoliciesProps) -> Html {
     </TableHeader<Columns>>
   };
 
-  let total_entries: Option<usize> = None;
+  let total_entries = page_total_entries(
+    props.offset,
+    props.limit,
+    props.policy_definition_items.len(),
+    props.total_entries,
+  );
 
   let nav_callback = use_callback(
     (
@@ -38,7 +84,7 @@ pub fn ListPolicies(props: &ListPoliciesProps) -> Html {
       let offset = match page {
         Navigation::First => 0,
         Navigation::Last => (total_entries.unwrap_or_default().saturating_sub(1) / limit) * limit,
-        Navigation::Previous => *offset - limit,
+        Navigation::Previous => offset.saturating_sub(*limit),
         Navigation::Next => *offset + limit,
         Navigation::Page(n) => n * limit,
       };
@@ -52,6 +98,7 @@ pub fn ListPolicies(props: &ListPoliciesProps) -> Html {
     .map(|policy_definition_item| PolicyDefinitionItemRenderer {
       policy_definition_item: policy_definition_item.clone(),
       ondelete: props.ondelete.clone(),
+      onduplicate: props.onduplicate.clone(),
     })
     .collect();
 
@@ -61,8 +108,16 @@ pub fn ListPolicies(props: &ListPoliciesProps) -> Html {
     <>
       <Toolbar>
         <ToolbarContent>
+          <ToolbarItem>
+            <QueryFilterToolbar
+              properties={FILTER_PROPERTIES.to_vec()}
+              filters={props.list_query.filters.clone()}
+              onchange={onchange_filters}
+            />
+          </ToolbarItem>
           <ToolbarItem r#type={ToolbarItemType::Pagination}>
             <Pagination
+              {total_entries}
               offset={props.offset}
               entries_per_page_choices={vec![5, 10, 25, 50, 100]}
               selected_choice={props.limit}
@@ -94,12 +149,17 @@ enum Columns {
 struct PolicyDefinitionItemRenderer {
   policy_definition_item: PolicyDefinitionItem,
   ondelete: Callback<String>,
+  onduplicate: Callback<String>,
 }
 
 impl TableEntryRenderer<Columns> for PolicyDefinitionItemRenderer {
   fn render_cell(&self, context: CellContext<'_, Columns>) -> Cell {
     match context.column {
-      Columns::Id => html! { self.policy_definition_item.id.to_string() },
+      Columns::Id => {
+        let id = self.policy_definition_item.id.clone();
+
+        html!(<Link<AppRoute> to={AppRoute::PolicyDefinition { id: id.clone() }}>{ id }</Link<AppRoute>>)
+      }
       Columns::Kind => html! { self.policy_definition_item.kind.to_string() },
       Columns::Assignee => html!(
         self
@@ -118,8 +178,22 @@ impl TableEntryRenderer<Columns> for PolicyDefinitionItemRenderer {
       Columns::Actions => {
         let policy_id = self.policy_definition_item.id.to_string();
         let ondelete = self.ondelete.clone();
+        let duplicate_id = policy_id.clone();
 
-        html!(<DeletePolicy {policy_id} {ondelete} />)
+        html!(
+          <Flex>
+            <FlexItem>
+              <Button
+                variant={ButtonVariant::Secondary}
+                icon={Icon::Copy}
+                onclick={self.onduplicate.reform(move |_| duplicate_id.clone())}
+              >
+                { "Duplicate" }
+              </Button>
+            </FlexItem>
+            <FlexItem><DeletePolicy {policy_id} {ondelete} /></FlexItem>
+          </Flex>
+        )
       }
     }
     .into()
@@ -0,0 +1,153 @@
+use crate::models::{FilterOperator, ListQuery, QueryFilter, QueryProperty, QuerySort};
+use patternfly_yew::prelude::*;
+use yew::prelude::*;
+
+#[derive(Clone, Debug, PartialEq, Properties)]
+pub struct QueryFilterToolbarProps {
+  pub properties: Vec<QueryProperty>,
+  pub filters: Vec<QueryFilter>,
+  pub onchange: Callback<Vec<QueryFilter>>,
+}
+
+#[component]
+pub fn QueryFilterToolbar(props: &QueryFilterToolbarProps) -> Html {
+  let property = use_state(|| props.properties.first().copied());
+  let operator = use_state(|| FilterOperator::Equal);
+  let value = use_state(String::new);
+
+  let onselect_property = use_callback(property.setter(), |property, property_setter| {
+    property_setter.set(Some(property));
+  });
+
+  let onselect_operator = use_callback(operator.setter(), |operator, operator_setter| {
+    operator_setter.set(operator);
+  });
+
+  let onchange_value = use_callback(value.setter(), |value, value_setter| {
+    value_setter.set(value);
+  });
+
+  let onadd = use_callback(
+    (
+      property.clone(),
+      operator.clone(),
+      value.clone(),
+      props.filters.clone(),
+      props.onchange.clone(),
+    ),
+    |_, (property, operator, value, filters, onchange)| {
+      if let Some(property) = **property {
+        let mut filters = filters.clone();
+
+        filters.push(QueryFilter {
+          property: property.path.to_string(),
+          operator: **operator,
+          value: (**value).clone(),
+        });
+
+        value.set(String::new());
+        onchange.emit(filters);
+      }
+    },
+  );
+
+  let labels = props.filters.iter().enumerate().map(|(index, filter)| {
+    let filters = props.filters.clone();
+    let onclose = props.onchange.reform(move |_| {
+      let mut filters = filters.clone();
+      filters.remove(index);
+      filters
+    });
+
+    let property = props
+      .properties
+      .iter()
+      .find(|property| property.path == filter.property)
+      .map(|property| property.label)
+      .unwrap_or(&filter.property);
+    let label = format!("{property} {} {}", filter.operator, filter.value);
+
+    html!(<Label {label} {onclose} />)
+  });
+
+  html!(
+    <Stack gutter=true>
+      <StackItem>
+        <Split gutter=true>
+          <SplitItem>
+            <SimpleSelect<QueryProperty>
+              selected={*property}
+              onselect={onselect_property}
+              entries={props.properties.clone()}
+            />
+          </SplitItem>
+          <SplitItem>
+            <SimpleSelect<FilterOperator>
+              selected={*operator}
+              onselect={onselect_operator}
+              entries={FilterOperator::ALL.to_vec()}
+            />
+          </SplitItem>
+          <SplitItem>
+            <TextInput value={(*value).clone()} onchange={onchange_value} placeholder="Value" />
+          </SplitItem>
+          <SplitItem>
+            <Button
+              icon={Icon::Filter}
+              variant={ButtonVariant::Secondary}
+              onclick={onadd}
+              disabled={property.is_none() || value.is_empty()}
+            >
+              { "Add Filter" }
+            </Button>
+          </SplitItem>
+        </Split>
+      </StackItem>
+      if !props.filters.is_empty() {
+        <StackItem>
+          <Split gutter=true>
+            { for labels.map(|label| html_nested!(<SplitItem>{ label }</SplitItem>)) }
+          </Split>
+        </StackItem>
+      }
+    </Stack>
+  )
+}
+
+pub fn table_sort_by<C: Clone + Eq>(
+  sort_columns: &[(C, QueryProperty)],
+  list_query: &ListQuery,
+) -> Option<TableHeaderSortBy<C>> {
+  let sort = list_query.sort.as_ref()?;
+
+  sort_columns
+    .iter()
+    .find(|(_, property)| property.path == sort.property)
+    .map(|(column, _)| TableHeaderSortBy {
+      index: column.clone(),
+      order: if sort.ascending {
+        Order::Ascending
+      } else {
+        Order::Descending
+      },
+    })
+}
+
+pub fn sorted_list_query<C: Clone + Eq>(
+  sort_columns: &[(C, QueryProperty)],
+  list_query: &ListQuery,
+  sort_by: TableHeaderSortBy<C>,
+) -> ListQuery {
+  let sort = sort_columns
+    .iter()
+    .find(|(column, _)| *column == sort_by.index)
+    .map(|(_, property)| QuerySort {
+      property: property.path.to_string(),
+      ascending: sort_by.order == Order::Ascending,
+    });
+
+  ListQuery {
+    sort,
+    ..list_query.clone()
+  }
+}
@@ -0,0 +1,112 @@
+use crate::models::SecretItem;
+use crate::routes::AppRoute;
+use patternfly_yew::prelude::*;
+use std::rc::Rc;
+use yew::prelude::*;
+use yew_nested_router::components::Link;
+
+#[derive(Clone, Debug, PartialEq, Properties)]
+pub struct ListSecretsProps {
+  pub secret_items: Vec<SecretItem>,
+  pub onedit: Callback<SecretItem>,
+  pub ondelete: Callback<String>,
+}
+
+#[component]
+pub fn ListSecrets(props: &ListSecretsProps) -> Html {
+  let header = html_nested! {
+    <TableHeader<Columns>>
+      <TableColumn<Columns> label="Key" index={Columns::Id} />
+      <TableColumn<Columns> label="Vault" index={Columns::Stored} />
+      <TableColumn<Columns> label="Referenced By" index={Columns::ReferencedBy} />
+      <TableColumn<Columns> label="" index={Columns::Actions} />
+    </TableHeader<Columns>>
+  };
+
+  let rows = props
+    .secret_items
+    .iter()
+    .map(|secret_item| SecretItemRenderer {
+      secret_item: secret_item.clone(),
+      onedit: props.onedit.clone(),
+      ondelete: props.ondelete.clone(),
+    })
+    .collect();
+
+  let (entries, _) = use_table_data(MemoizedTableModel::new(Rc::new(rows)));
+
+  html!(
+    <Table<Columns, UseTableData<Columns, MemoizedTableModel<SecretItemRenderer>>>
+      mode={TableMode::Compact}
+      {header}
+      {entries}
+    />
+  )
+}
+
+#[derive(Clone, Debug, Eq, PartialEq)]
+enum Columns {
+  Id,
+  Stored,
+  ReferencedBy,
+  Actions,
+}
+
+#[derive(Clone, Debug)]
+struct SecretItemRenderer {
+  secret_item: SecretItem,
+  onedit: Callback<SecretItem>,
+  ondelete: Callback<String>,
+}
+
+impl TableEntryRenderer<Columns> for SecretItemRenderer {
+  fn render_cell(&self, context: CellContext<'_, Columns>) -> Cell {
+    let item = &self.secret_item;
+
+    match context.column {
+      Columns::Id => html!(item.id.clone()),
+      Columns::Stored => match item.stored {
+        Some(true) => html!(<Label color={Color::Green} label="Stored" />),
+        Some(false) => html!(<Label color={Color::Red} label="Missing" />),
+        None => html!(<Label color={Color::Grey} label="Unknown" />),
+      },
+      Columns::ReferencedBy => html!(
+        <List>
+          { for item.referenced_by.iter().map(|id| html_nested!(
+            <ListItem>
+              <Link<AppRoute> to={AppRoute::Asset { id: id.clone() }}>{ id.clone() }</Link<AppRoute>>
+            </ListItem>
+          )) }
+        </List>
+      ),
+      Columns::Actions => {
+        let secret_item = item.clone();
+        let id = item.id.clone();
+
+        html!(
+          <Flex>
+            <FlexItem>
+              <Button
+                variant={ButtonVariant::Secondary}
+                onclick={self.onedit.reform(move |_| secret_item.clone())}
+              >
+                { if item.stored == Some(false) { "Set Value" } else { "Update Value" } }
+              </Button>
+            </FlexItem>
+            <FlexItem>
+              <Button
+                variant={ButtonVariant::Danger}
+                icon={Icon::Trash}
+                disabled={item.stored == Some(false)}
+                onclick={self.ondelete.reform(move |_| id.clone())}
+              >
+                { "Delete" }
+              </Button>
+            </FlexItem>
+          </Flex>
+        )
+      }
+    }
+    .into()
+  }
+}
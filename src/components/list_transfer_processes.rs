@@ -1,31 +1,120 @@
-use crate::models::TransferProcessItem;
+use crate::components::{QueryFilterToolbar, WatchButton, sorted_list_query, table_sort_by};
+use crate::contexts::WatchedKind;
+use crate::hooks::use_changed_states;
+use crate::models::{ListQuery, QueryProperty, TransferProcessItem};
+use crate::routes::AppRoute;
+use crate::services::page_total_entries;
 use patternfly_yew::prelude::*;
 use std::rc::Rc;
 use yew::prelude::*;
+use yew_nested_router::components::Link;
+
+const SORT_COLUMNS: [(Columns, QueryProperty); 6] = [
+  (Columns::Id, QueryProperty::new("ID", "id")),
+  (Columns::State, QueryProperty::new("State", "state")),
+  (Columns::AssetId, QueryProperty::new("Asset ID", "assetId")),
+  (
+    Columns::ContractId,
+    QueryProperty::new("Contract ID", "contractId"),
+  ),
+  (
+    Columns::TransferType,
+    QueryProperty::new("Transfer Type", "transferType"),
+  ),
+  (Columns::Kind, QueryProperty::new("Kind", "type")),
+];
+
+const FILTER_PROPERTIES: [QueryProperty; 6] = [
+  QueryProperty::new("ID", "id"),
+  QueryProperty::new("State", "state"),
+  QueryProperty::new("Asset ID", "assetId"),
+  QueryProperty::new("Contract ID", "contractId"),
+  QueryProperty::new("Transfer Type", "transferType"),
+  QueryProperty::new("Kind", "type"),
+];
 
 #[derive(Clone, Debug, PartialEq, Properties)]
 pub struct ListTransferProcessesProps {
   pub transfer_processe_items: Vec<TransferProcessItem>,
   pub offset: usize,
+  #[prop_or_default]
+  pub total_entries: Option<usize>,
   pub limit: usize,
   pub onoffset: Callback<usize>,
   pub onlimit: Callback<usize>,
+  pub list_query: ListQuery,
+  pub onquery: Callback<ListQuery>,
 }
 
 #[component]
 pub fn ListTransferProcesses(props: &ListTransferProcessesProps) -> Html {
+  let sortby = table_sort_by(&SORT_COLUMNS, &props.list_query);
+
+  let onsort = use_callback(
+    (props.list_query.clone(), props.onquery.clone()),
+    |sort_by, (list_query, onquery)| {
+      onquery.emit(sorted_list_query(&SORT_COLUMNS, list_query, sort_by));
+    },
+  );
+
+  let onchange_filters = use_callback(
+    (props.list_query.clone(), props.onquery.clone()),
+    |filters, (list_query, onquery)| {
+      onquery.emit(ListQuery {
+        filters,
+        ..list_query.clone()
+      });
+    },
+  );
+
   let header = html_nested! {
     <TableHeader<Columns>>
-      <TableColumn<Columns> label="ID" index={Columns::Id} />
-      <TableColumn<Columns> label="State" index={Columns::State} />
-      <TableColumn<Columns> label="Asset ID" index={Columns::AssetId} />
-      <TableColumn<Columns> label="Contract ID" index={Columns::ContractId} />
-      <TableColumn<Columns> label="Transfer Type" index={Columns::TransferType} />
-      <TableColumn<Columns> label="Kind" index={Columns::Kind} />
+      <TableColumn<Columns>
+        label="ID"
+        index={Columns::Id}
+        sortby={sortby.clone()}
+        onsort={onsort.clone()}
+      />
+      <TableColumn<Columns>
+        label="State"
+        index={Columns::State}
+        sortby={sortby.clone()}
+        onsort={onsort.clone()}
+      />
+      <TableColumn<Columns>
+        label="Asset ID"
+        index={Columns::AssetId}
+        sortby={sortby.clone()}
+        onsort={onsort.clone()}
+      />
+      <TableColumn<Columns>
+        label="Contract ID"
+        index={Columns::ContractId}
+        sortby={sortby.clone()}
+        onsort={onsort.clone()}
+      />
+      <TableColumn<Columns>
+        label="Transfer Type"
+        index={Columns::TransferType}
+        sortby={sortby.clone()}
+        onsort={onsort.clone()}
+      />
+      <TableColumn<Columns>
+        label="Kind"
+        index={Columns::Kind}
+        sortby={sortby.clone()}
+        onsort={onsort.clone()}
+      />
+      <TableColumn<Columns> label="" index={Columns::Actions} />
     </TableHeader<Columns>>
   };
 
-  let total_entries: Option<usize> = None;
+  let total_entries = page_total_entries(
+    props.offset,
+    props.limit,
+    props.transfer_processe_items.len(),
+    props.total_entries,
+  );
 
   let nav_callback = use_callback(
     (
@@ -38,7 +127,7 @@ pub fn ListTransferProcesses(props: &ListTransferProcessesProps) -> Html {
       let offset = match page {
         Navigation::First => 0,
         Navigation::Last => (total_entries.unwrap_or_default().saturating_sub(1) / limit) * limit,
-        Navigation::Previous => *offset - limit,
+        Navigation::Previous => offset.saturating_sub(*limit),
         Navigation::Next => *offset + limit,
         Navigation::Page(n) => n * limit,
       };
@@ -46,10 +135,28 @@ pub fn ListTransferProcesses(props: &ListTransferProcessesProps) -> Html {
     },
   );
 
+  let changed_states = use_changed_states(
+    props
+      .transfer_processe_items
+      .iter()
+      .map(|transfer_process_item| {
+        (
+          transfer_process_item.id.clone(),
+          transfer_process_item.state.clone(),
+        )
+      })
+      .collect(),
+  );
+
   let rows = props
     .transfer_processe_items
     .iter()
-    .map(|transfer_process_item| ListTransferProcessRenderer(transfer_process_item.clone()))
+    .map(|transfer_process_item| {
+      ListTransferProcessRenderer(
+        transfer_process_item.clone(),
+        changed_states.contains(&transfer_process_item.id),
+      )
+    })
     .collect();
 
   let (entries, _) = use_table_data(MemoizedTableModel::new(Rc::new(rows)));
@@ -58,8 +165,16 @@ pub fn ListTransferProcesses(props: &ListTransferProcessesProps) -> Html {
     <>
       <Toolbar>
         <ToolbarContent>
+          <ToolbarItem>
+            <QueryFilterToolbar
+              properties={FILTER_PROPERTIES.to_vec()}
+              filters={props.list_query.filters.clone()}
+              onchange={onchange_filters}
+            />
+          </ToolbarItem>
           <ToolbarItem r#type={ToolbarItemType::Pagination}>
             <Pagination
+              {total_entries}
               offset={props.offset}
               entries_per_page_choices={vec![5, 10, 25, 50, 100]}
               selected_choice={props.limit}
@@ -86,20 +201,35 @@ enum Columns {
   ContractId,
   TransferType,
   Kind,
+  Actions,
 }
 
 #[derive(Clone, Debug)]
-struct ListTransferProcessRenderer(TransferProcessItem);
+struct ListTransferProcessRenderer(TransferProcessItem, bool);
 
 impl TableEntryRenderer<Columns> for ListTransferProcessRenderer {
   fn render_cell(&self, context: CellContext<'_, Columns>) -> Cell {
     match context.column {
-      Columns::Id => html! { self.0.id.to_string() },
+      Columns::Id => {
+        let id = self.0.id.clone();
+
+        html!(<Link<AppRoute> to={AppRoute::TransferProcess { id: id.clone() }}>{ id }</Link<AppRoute>>)
+      }
+      Columns::State if self.1 => html!(
+        <Label color={Color::Blue} label={self.0.state.clone()} />
+      ),
       Columns::State => html!(self.0.state.to_string()),
       Columns::AssetId => html!(self.0.asset_id.to_string()),
-      Columns::ContractId => html! { self.0.contract_id.to_string() },
+      Columns::ContractId => {
+        let id = self.0.contract_id.clone();
+
+        html!(<Link<AppRoute> to={AppRoute::ContractAgreement { id: id.clone() }}>{ id }</Link<AppRoute>>)
+      }
       Columns::TransferType => html!(self.0.transfer_type.to_string()),
       Columns::Kind => html!(self.0.kind.to_string()),
+      Columns::Actions => {
+        html!(<WatchButton kind={WatchedKind::TransferProcess} id={self.0.id.clone()} />)
+      }
     }
     .into()
   }
@@ -0,0 +1,110 @@
+use crate::services::plain_value;
+use edc_connector_client::types::policy::{
+  Action, Constraint, LeftOperand, MultiplicityConstraint, Operator, Policy,
+};
+use patternfly_yew::prelude::*;
+use yew::prelude::*;
+
+#[derive(Clone, Debug, PartialEq, Properties)]
+pub struct PolicyRulesProps {
+  pub policy: Policy,
+}
+
+/// Read-only rendering of a policy's permissions, prohibitions and obligations.
+#[component]
+pub fn PolicyRules(props: &PolicyRulesProps) -> Html {
+  let permissions = props
+    .policy
+    .permissions()
+    .iter()
+    .map(|permission| (permission.action(), permission.constraints()))
+    .collect::<Vec<_>>();
+  let prohibitions = props
+    .policy
+    .prohibitions()
+    .iter()
+    .map(|prohibition| (prohibition.action(), prohibition.constraints()))
+    .collect::<Vec<_>>();
+  let obligations = props
+    .policy
+    .obligations()
+    .iter()
+    .map(|obligation| (obligation.action(), obligation.constraints()))
+    .collect::<Vec<_>>();
+
+  html!(
+    <DescriptionList>
+      <DescriptionGroup term="Permissions">{ rules(&permissions) }</DescriptionGroup>
+      <DescriptionGroup term="Prohibitions">{ rules(&prohibitions) }</DescriptionGroup>
+      <DescriptionGroup term="Obligations">{ rules(&obligations) }</DescriptionGroup>
+    </DescriptionList>
+  )
+}
+
+fn rules(rules: &[(&Action, &[Constraint])]) -> Html {
+  if rules.is_empty() {
+    return html!("None");
+  }
+
+  html!(
+    <List>
+      { for rules.iter().map(|(action, constraints)| html_nested!(
+        <ListItem>
+          { action.id().clone() }
+          if !constraints.is_empty() {
+            { constraint_list(constraints) }
+          }
+        </ListItem>
+      )) }
+    </List>
+  )
+}
+
+fn constraint_list(constraints: &[Constraint]) -> Html {
+  html!(
+    <List>
+      { for constraints.iter().map(|constraint| html_nested!(
+        <ListItem>{ constraint_view(constraint) }</ListItem>
+      )) }
+    </List>
+  )
+}
+
+fn constraint_view(constraint: &Constraint) -> Html {
+  match constraint {
+    Constraint::Atomic(atomic_constraint) => html!(format!(
+      "{} {} {}",
+      left_operand_label(&atomic_constraint.left_operand),
+      operator_label(&atomic_constraint.operator),
+      plain_value(&atomic_constraint.right_operand.0)
+    )),
+    Constraint::MultiplicityConstraint(multiplicity_constraint) => {
+      let (label, constraints) = match multiplicity_constraint {
+        MultiplicityConstraint::And(constraints) => ("All of", constraints),
+        MultiplicityConstraint::Or(constraints) => ("Any of", constraints),
+        MultiplicityConstraint::Xone(constraints) => ("Exactly one of", constraints),
+      };
+
+      html!(
+        <>
+          { label }
+          { constraint_list(constraints) }
+        </>
+      )
+    }
+  }
+}
+
+fn left_operand_label(left_operand: &LeftOperand) -> &str {
+  match left_operand {
+    LeftOperand::Simple(left_operand) => left_operand,
+    LeftOperand::Id { id } => id,
+  }
+}
+
+fn operator_label(operator: &Operator) -> &str {
+  match operator {
+    Operator::Simple(operator) => operator,
+    Operator::Id { id } => id,
+  }
+}
@@ -1,5 +1,7 @@
 use crate::contexts::use_edc_connector_context;
+use crate::hooks::use_total_entries;
 use crate::models::PolicyDefinitionItem;
+use crate::services::page_total_entries;
 use edc_connector_client::types::query::Query;
 use patternfly_yew::prelude::*;
 use yew::prelude::*;
@@ -98,7 +100,12 @@ fn PolicySelectorInner(props: &PolicySelectorInnerProps) -> HtmlResult {
     limit_setter.set(limit)
   });
 
-  let total_entries = Option::<usize>::None;
+  let total_entries = use_total_entries(
+    *force_refresh,
+    Query::default(),
+    |client, query| async move { client.policies().query(query).await },
+  );
+  let total_entries = page_total_entries(*offset, *limit, policies.len(), total_entries);
 
   let nav_callback = use_callback(
     (offset.clone(), *limit, total_entries),
@@ -106,7 +113,7 @@ fn PolicySelectorInner(props: &PolicySelectorInnerProps) -> HtmlResult {
       let new_offset = match page {
         Navigation::First => 0,
         Navigation::Last => (total_entries.unwrap_or_default().saturating_sub(1) / limit) * limit,
-        Navigation::Previous => **offset - limit,
+        Navigation::Previous => offset.saturating_sub(*limit),
         Navigation::Next => **offset + limit,
         Navigation::Page(n) => n * limit,
       };
@@ -118,6 +125,7 @@ fn PolicySelectorInner(props: &PolicySelectorInnerProps) -> HtmlResult {
     <Stack gutter=true>
       <StackItem>
         <Pagination
+          {total_entries}
           offset={*offset}
           entries_per_page_choices={vec![5, 10, 25, 50, 100]}
           selected_choice={*limit}
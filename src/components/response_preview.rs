@@ -0,0 +1,52 @@
+use crate::services::BrowserResponse;
+use patternfly_yew::prelude::*;
+use yew::prelude::*;
+
+#[derive(Clone, Debug, PartialEq, Properties)]
+pub struct ResponsePreviewProps {
+  pub response: BrowserResponse,
+}
+
+/// Shows the body of a response made from the browser, noting when it was cut off.
+#[component]
+pub fn ResponsePreview(props: &ResponsePreviewProps) -> Html {
+  // Pretty-print JSON so nested payloads stay readable.
+  let body = serde_json::from_str::<serde_json::Value>(&props.response.body)
+    .ok()
+    .and_then(|body| serde_json::to_string_pretty(&body).ok())
+    .unwrap_or_else(|| props.response.body.clone());
+
+  html!(
+    <Stack gutter=true>
+      if props.response.truncated {
+        <StackItem>
+          <Alert inline=true plain=true r#type={AlertType::Info} title="Response truncated" />
+        </StackItem>
+      }
+      <StackItem>
+        <CodeBlock>
+          <CodeBlockCode>{ body }</CodeBlockCode>
+        </CodeBlock>
+      </StackItem>
+    </Stack>
+  )
+}
+
+#[derive(Clone, Debug, PartialEq, Properties)]
+pub struct RequestFailedProps {
+  pub error: String,
+}
+
+/// Explains a failed request made from the browser.
+#[component]
+pub fn RequestFailed(props: &RequestFailedProps) -> Html {
+  // Browsers hide most network failures behind a generic error, CORS being the usual cause.
+  html!(
+    <Alert inline=true r#type={AlertType::Danger} title="Request failed">
+      { html!(format!(
+        "{}. The server may not allow cross-origin requests from this UI.",
+        props.error
+      )) }
+    </Alert>
+  )
+}
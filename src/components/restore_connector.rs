@@ -0,0 +1,189 @@
+use crate::contexts::{record_restore_entry, use_activity_log_context, use_edc_connector_context};
+use crate::services::{ConflictStrategy, ConnectorBackup, RestoreEntry, RestoreOutcome, restore};
+use patternfly_yew::prelude::*;
+use std::rc::Rc;
+use yew::platform::spawn_local;
+use yew::prelude::*;
+
+#[derive(Clone, Debug, PartialEq, Properties)]
+pub struct RestoreConnectorProps {
+  #[prop_or_default]
+  pub on_restore: Callback<()>,
+}
+
+#[component]
+pub fn RestoreConnector(props: &RestoreConnectorProps) -> Html {
+  let edc_connector_context = use_edc_connector_context();
+  let activity_log_context = use_activity_log_context();
+
+  let input = use_state(String::new);
+  let strategy = use_state(|| ConflictStrategy::Skip);
+  let restoring = use_state(|| false);
+  let parse_error = use_state(|| Option::<String>::None);
+  let restore_entries = use_state(Vec::<RestoreEntry>::new);
+
+  let onchange_input = use_callback(input.setter(), |input, input_setter| {
+    input_setter.set(input);
+  });
+
+  let onselect_strategy = use_callback(strategy.setter(), |strategy, strategy_setter| {
+    strategy_setter.set(strategy);
+  });
+
+  let onrestore = use_callback(
+    (
+      edc_connector_context,
+      activity_log_context,
+      input.clone(),
+      strategy.clone(),
+      restoring.clone(),
+      parse_error.clone(),
+      restore_entries.clone(),
+      props.on_restore.clone(),
+    ),
+    |_,
+     (
+      edc_connector_context,
+      activity_log_context,
+      input,
+      strategy,
+      restoring,
+      parse_error,
+      restore_entries,
+      on_restore,
+    )| {
+      let backup = match ConnectorBackup::parse(input) {
+        Ok(backup) => backup,
+        Err(error) => {
+          parse_error.set(Some(error));
+          return;
+        }
+      };
+
+      let edc_connector_context = edc_connector_context.clone();
+      let activity_log_context = activity_log_context.clone();
+      let strategy = **strategy;
+      let restoring = restoring.clone();
+      let restore_entries = restore_entries.clone();
+      let on_restore = on_restore.clone();
+
+      parse_error.set(None);
+      restore_entries.set(vec![]);
+      restoring.set(true);
+
+      spawn_local(async move {
+        if let Some(client) = edc_connector_context.get_client() {
+          let progress = std::cell::RefCell::new(vec![]);
+
+          restore(&client, &backup, strategy, |restore_entry| {
+            record_restore_entry(&activity_log_context, &restore_entry, None);
+            progress.borrow_mut().push(restore_entry);
+            restore_entries.set(progress.borrow().clone());
+          })
+          .await;
+        }
+
+        restoring.set(false);
+        on_restore.emit(());
+      });
+    },
+  );
+
+  let header = html_nested! {
+    <TableHeader<Columns>>
+      <TableColumn<Columns> label="Kind" index={Columns::Kind} />
+      <TableColumn<Columns> label="ID" index={Columns::Id} />
+      <TableColumn<Columns> label="Outcome" index={Columns::Outcome} />
+    </TableHeader<Columns>>
+  };
+
+  let entries = (*restore_entries)
+    .iter()
+    .cloned()
+    .map(RestoreEntryRenderer)
+    .collect::<Vec<_>>();
+
+  let (entries, _) = use_table_data(MemoizedTableModel::new(Rc::new(entries)));
+
+  html!(
+    <Stack gutter=true>
+      <StackItem>
+        <Form>
+          <FormGroup label="Backup" required=true>
+            <TextArea
+              value={(*input).clone()}
+              onchange={onchange_input}
+              rows={10}
+              resize={ResizeOrientation::Vertical}
+            />
+          </FormGroup>
+          <FormGroup label="On Conflict" required=true>
+            <SimpleSelect<ConflictStrategy>
+              selected={*strategy}
+              onselect={onselect_strategy}
+              entries={vec![ConflictStrategy::Skip, ConflictStrategy::Overwrite, ConflictStrategy::Rename]}
+            />
+          </FormGroup>
+          <ActionGroup>
+            <Button
+              icon={Icon::Upload}
+              variant={ButtonVariant::Primary}
+              onclick={onrestore}
+              loading={*restoring}
+              disabled={(*input).is_empty() || *restoring}
+            >
+              { "Restore" }
+            </Button>
+          </ActionGroup>
+        </Form>
+      </StackItem>
+      if let Some(parse_error) = (*parse_error).clone() {
+        <StackItem>
+          <Alert inline=true r#type={AlertType::Danger} title="Invalid backup">
+            { html!(parse_error) }
+          </Alert>
+        </StackItem>
+      }
+      if !restore_entries.is_empty() {
+        <StackItem>
+          <Table<Columns, UseTableData<Columns, MemoizedTableModel<RestoreEntryRenderer>>>
+            mode={TableMode::Compact}
+            {header}
+            {entries}
+          />
+        </StackItem>
+      }
+    </Stack>
+  )
+}
+
+#[derive(Clone, Debug, Eq, PartialEq)]
+enum Columns {
+  Kind,
+  Id,
+  Outcome,
+}
+
+#[derive(Clone, Debug)]
+struct RestoreEntryRenderer(RestoreEntry);
+
+impl TableEntryRenderer<Columns> for RestoreEntryRenderer {
+  fn render_cell(&self, context: CellContext<'_, Columns>) -> Cell {
+    match context.column {
+      Columns::Kind => html!(self.0.kind.to_string()),
+      Columns::Id => html!(self.0.id.to_string()),
+      Columns::Outcome => match &self.0.outcome {
+        RestoreOutcome::Created => html!(<Label color={Color::Green} label="Created" />),
+        RestoreOutcome::Overwritten => html!(<Label color={Color::Blue} label="Overwritten" />),
+        RestoreOutcome::Renamed(new_id) => {
+          html!(<Label color={Color::Purple} label={format!("Renamed to {new_id}")} />)
+        }
+        RestoreOutcome::Skipped => html!(<Label label="Skipped" />),
+        RestoreOutcome::Failed(error) | RestoreOutcome::OverwriteFailed(error) => {
+          html!(<Label color={Color::Red} label={format!("Failed: {error}")} />)
+        }
+      },
+    }
+    .into()
+  }
+}
@@ -0,0 +1,133 @@
+use crate::contexts::{
+  ActivityOperation, record_activity, record_creation, use_activity_log_context,
+  use_edc_connector_context,
+};
+use crate::models::EntityKind;
+use crate::services::error_message;
+use edc_connector_client::types::secret::{NewSecret, Secret};
+use patternfly_yew::prelude::*;
+use yew::platform::spawn_local;
+use yew::prelude::*;
+
+#[derive(Clone, Debug, PartialEq, Properties)]
+pub struct SecretFormProps {
+  /// A known key whose value is set; a new key can be entered when absent.
+  #[prop_or_default]
+  pub secret_id: Option<String>,
+  /// Whether the vault already holds the key, so the value is replaced instead of created.
+  #[prop_or_default]
+  pub stored: bool,
+  #[prop_or_default]
+  pub on_save: Callback<String>,
+}
+
+/// Writes a secret value. Values are never read back, the field always starts empty.
+#[component]
+pub fn SecretForm(props: &SecretFormProps) -> Html {
+  let edc_connector_context = use_edc_connector_context();
+  let activity_log_context = use_activity_log_context();
+
+  let identifier = use_state(|| props.secret_id.clone().unwrap_or_default());
+  let value = use_state(String::new);
+  let error = use_state(|| Option::<String>::None);
+
+  let onsubmit = use_callback(
+    (
+      edc_connector_context,
+      activity_log_context,
+      identifier.clone(),
+      value.clone(),
+      error.clone(),
+      props.stored,
+      props.on_save.clone(),
+    ),
+    |event: SubmitEvent,
+     (edc_connector_context, activity_log_context, identifier, value, error, stored, on_save)| {
+      event.prevent_default();
+
+      let edc_connector_context = edc_connector_context.clone();
+      let activity_log_context = activity_log_context.clone();
+      let identifier = identifier.trim().to_string();
+      let value = (**value).clone();
+      let error = error.clone();
+      let stored = *stored;
+      let on_save = on_save.clone();
+
+      spawn_local(async move {
+        let Some(client) = edc_connector_context.get_client() else {
+          return;
+        };
+
+        let result = if stored {
+          let secret = Secret::builder().id(&identifier).value(value).build();
+          let result = client.secrets().update(&secret).await;
+          record_activity(
+            &activity_log_context,
+            ActivityOperation::Update,
+            EntityKind::Secret,
+            Some(identifier.clone()),
+            &result,
+          );
+          result.map_err(|error| error_message(&error))
+        } else {
+          let secret = NewSecret::builder().id(&identifier).value(value).build();
+          let result = client.secrets().create(&secret).await;
+          record_creation(
+            &activity_log_context,
+            EntityKind::Secret,
+            Some(identifier.clone()),
+            &result,
+          );
+          result.map(|_| ()).map_err(|error| error_message(&error))
+        };
+
+        match result {
+          Ok(()) => on_save.emit(identifier),
+          Err(message) => error.set(Some(message)),
+        }
+      });
+    },
+  );
+
+  let onchange_identifier = use_callback(identifier.setter(), |value, identifier_setter| {
+    identifier_setter.set(value);
+  });
+
+  let onchange_value = use_callback(value.setter(), |value, value_setter| {
+    value_setter.set(value);
+  });
+
+  html!(
+    <Form {onsubmit}>
+      if let Some(error) = (*error).clone() {
+        <Alert inline=true r#type={AlertType::Danger} title="Could not save the secret">
+          { html!(error) }
+        </Alert>
+      }
+      <FormGroup label="Key" required=true>
+        <TextInput
+          required=true
+          readonly={props.secret_id.is_some()}
+          value={(*identifier).clone()}
+          onchange={onchange_identifier}
+        />
+      </FormGroup>
+      <FormGroup
+        label="Value"
+        required=true
+        helper_text={FormHelperText::from("The current value is never shown")}
+      >
+        <TextInput
+          required=true
+          r#type={TextInputType::Password}
+          autocomplete="new-password"
+          value={(*value).clone()}
+          onchange={onchange_value}
+        />
+      </FormGroup>
+      <ActionGroup>
+        <Button variant={ButtonVariant::Primary} label="Save" r#type={ButtonType::Submit} />
+      </ActionGroup>
+    </Form>
+  )
+}
@@ -0,0 +1,163 @@
+use crate::contexts::use_edc_connector_context;
+use crate::services::{error_message, secret_exists, secret_references};
+use patternfly_yew::prelude::*;
+use std::time::Duration;
+use yew::platform::spawn_local;
+use yew::platform::time::sleep;
+use yew::prelude::*;
+
+/// Waits for the typed key to settle before asking the vault.
+const LOOKUP_DELAY: Duration = Duration::from_millis(500);
+
+#[derive(Clone, Debug, PartialEq, Eq)]
+enum SecretChoice {
+  Known(String),
+  Other,
+}
+
+impl std::fmt::Display for SecretChoice {
+  fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+    match self {
+      SecretChoice::Known(secret_name) => write!(f, "{secret_name}"),
+      SecretChoice::Other => write!(f, "Other…"),
+    }
+  }
+}
+
+#[derive(Clone, Debug, PartialEq, Properties)]
+pub struct SecretSelectorProps {
+  #[prop_or("Secret".into())]
+  pub label: AttrValue,
+  pub value: String,
+  pub onchange: Callback<String>,
+}
+
+/// Picks a vault secret by key, offering the ones other assets already reference and telling
+/// whether the chosen key holds a value.
+#[component]
+pub fn SecretSelector(props: &SecretSelectorProps) -> Html {
+  let edc_connector_context = use_edc_connector_context();
+  let known = use_state(Vec::<String>::new);
+  let stored = use_state(|| Option::<Result<bool, String>>::None);
+  let other = use_state(|| false);
+  let generation = use_mut_ref(|| 0usize);
+
+  {
+    let known = known.clone();
+
+    use_effect_with(
+      edc_connector_context.clone(),
+      move |edc_connector_context| {
+        if let Some(client) = edc_connector_context.get_client() {
+          spawn_local(async move {
+            if let Ok(references) = secret_references(&client).await {
+              known.set(references.into_keys().collect());
+            }
+          });
+        }
+      },
+    );
+  }
+
+  {
+    let stored = stored.clone();
+
+    use_effect_with(
+      (edc_connector_context, props.value.clone()),
+      move |(edc_connector_context, value)| {
+        *generation.borrow_mut() += 1;
+        let current_generation = *generation.borrow();
+        stored.set(None);
+
+        let value = value.trim().to_string();
+
+        if value.is_empty() {
+          return;
+        }
+
+        let Some(client) = edc_connector_context.get_client() else {
+          stored.set(Some(Err("No connector configured".to_string())));
+          return;
+        };
+
+        spawn_local(async move {
+          sleep(LOOKUP_DELAY).await;
+
+          if *generation.borrow() != current_generation {
+            return;
+          }
+
+          let exists = secret_exists(&client, &value).await;
+
+          if *generation.borrow() == current_generation {
+            stored.set(Some(exists.map_err(|error| error_message(&error))));
+          }
+        });
+      },
+    );
+  }
+
+  let selected = if *other || (!props.value.is_empty() && !known.contains(&props.value)) {
+    Some(SecretChoice::Other)
+  } else if props.value.is_empty() {
+    None
+  } else {
+    Some(SecretChoice::Known(props.value.clone()))
+  };
+
+  let onselect = use_callback(
+    (other.setter(), props.onchange.clone()),
+    |choice, (other_setter, onchange)| match choice {
+      SecretChoice::Known(secret_name) => {
+        other_setter.set(false);
+        onchange.emit(secret_name);
+      }
+      SecretChoice::Other => {
+        other_setter.set(true);
+        onchange.emit(String::new());
+      }
+    },
+  );
+
+  let entries = known
+    .iter()
+    .cloned()
+    .map(SecretChoice::Known)
+    .chain([SecretChoice::Other])
+    .collect::<Vec<_>>();
+
+  let helper_text = match (*stored).clone() {
+    _ if props.value.trim().is_empty() => {
+      FormHelperText::from("The value is read from the vault at transfer time")
+    }
+    Some(Ok(true)) => {
+      FormHelperText::from(("Stored in the vault".to_string(), InputState::Success))
+    }
+    Some(Ok(false)) => FormHelperText::from((
+      "No value stored under this key yet, add it on the Secrets page".to_string(),
+      InputState::Warning,
+    )),
+    Some(Err(error)) => FormHelperText::from((
+      format!("Unable to check the vault: {error}"),
+      InputState::Error,
+    )),
+    None => FormHelperText::from("Checking the vault…"),
+  };
+
+  let free_text = known.is_empty() || selected == Some(SecretChoice::Other);
+
+  html!(
+    <FormGroup label={props.label.to_string()} {helper_text}>
+      if !known.is_empty() {
+        <SimpleSelect<SecretChoice> placeholder="Select a secret" {entries} {selected} {onselect} />
+      }
+      if free_text {
+        <TextInput
+          placeholder="Vault key"
+          value={props.value.clone()}
+          onchange={props.onchange.clone()}
+        />
+      }
+    </FormGroup>
+  )
+}
@@ -0,0 +1,138 @@
+use crate::contexts::{
+  ActivityOperation, record_activity, use_activity_log_context, use_edc_connector_context,
+};
+use crate::models::EntityKind;
+use patternfly_yew::prelude::*;
+use yew::platform::spawn_local;
+use yew::prelude::*;
+
+#[derive(Copy, Clone, Debug, PartialEq, Eq, strum::Display)]
+pub enum TransferProcessAction {
+  Suspend,
+  Resume,
+  Terminate,
+}
+
+impl From<TransferProcessAction> for ActivityOperation {
+  fn from(action: TransferProcessAction) -> Self {
+    match action {
+      TransferProcessAction::Suspend => ActivityOperation::Suspend,
+      TransferProcessAction::Resume => ActivityOperation::Resume,
+      TransferProcessAction::Terminate => ActivityOperation::Terminate,
+    }
+  }
+}
+
+#[derive(Clone, Debug, PartialEq, Properties)]
+pub struct TransferProcessActionFormProps {
+  pub transfer_process_id: String,
+  pub action: TransferProcessAction,
+  #[prop_or_default]
+  pub on_done: Callback<()>,
+}
+
+#[component]
+pub fn TransferProcessActionForm(props: &TransferProcessActionFormProps) -> Html {
+  let edc_connector_context = use_edc_connector_context();
+  let activity_log_context = use_activity_log_context();
+
+  let reason = use_state(|| "".to_string());
+  let error = use_state(|| Option::<String>::None);
+
+  let onsubmit = use_callback(
+    (
+      edc_connector_context,
+      activity_log_context,
+      props.transfer_process_id.clone(),
+      props.action,
+      reason.clone(),
+      error.clone(),
+      props.on_done.clone(),
+    ),
+    |event: SubmitEvent,
+     (
+      edc_connector_context,
+      activity_log_context,
+      transfer_process_id,
+      action,
+      reason,
+      error,
+      on_done,
+    )| {
+      event.prevent_default();
+
+      let edc_connector_context = edc_connector_context.clone();
+      let activity_log_context = activity_log_context.clone();
+      let transfer_process_id = transfer_process_id.clone();
+      let action = *action;
+      let reason = (**reason).clone();
+      let error = error.clone();
+      let on_done = on_done.clone();
+
+      spawn_local(async move {
+        if let Some(client) = edc_connector_context.get_client() {
+          let transfer_processes = client.transfer_processes();
+
+          let result = match action {
+            TransferProcessAction::Suspend => {
+              transfer_processes
+                .suspend(&transfer_process_id, &reason)
+                .await
+            }
+            TransferProcessAction::Resume => transfer_processes.resume(&transfer_process_id).await,
+            TransferProcessAction::Terminate => {
+              transfer_processes
+                .terminate(&transfer_process_id, &reason)
+                .await
+            }
+          };
+
+          record_activity(
+            &activity_log_context,
+            action.into(),
+            EntityKind::TransferProcess,
+            Some(transfer_process_id),
+            &result,
+          );
+
+          match result {
+            Ok(()) => on_done.emit(()),
+            Err(action_error) => error.set(Some(action_error.to_string())),
+          }
+        }
+      })
+    },
+  );
+
+  let onchange_reason = use_callback(reason.setter(), move |reason, reason_setter| {
+    reason_setter.set(reason);
+  });
+
+  html!(
+    <Form {onsubmit}>
+      if let Some(error) = (*error).clone() {
+        <Alert inline=true r#type={AlertType::Danger} title={format!("{} failed", props.action)}>
+          { html!(error) }
+        </Alert>
+      }
+      if props.action == TransferProcessAction::Resume {
+        { format!("Resume transfer process {}?", props.transfer_process_id) }
+      } else {
+        <FormGroup label="Reason" required=true>
+          <TextInput required=true value={(*reason).to_string()} onchange={onchange_reason} />
+        </FormGroup>
+      }
+      <ActionGroup>
+        <Button
+          variant={if props.action == TransferProcessAction::Terminate {
+            ButtonVariant::Danger
+          } else {
+            ButtonVariant::Primary
+          }}
+          label={props.action.to_string()}
+          r#type={ButtonType::Submit}
+        />
+      </ActionGroup>
+    </Form>
+  )
+}
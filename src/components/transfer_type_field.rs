@@ -0,0 +1,140 @@
+use crate::contexts::use_edc_connector_context;
+use crate::services::{SupportedTransferTypes, supported_transfer_types};
+use patternfly_yew::prelude::*;
+use std::time::Duration;
+use yew::platform::spawn_local;
+use yew::platform::time::sleep;
+use yew::prelude::*;
+
+/// Waits for the contract ID to settle before asking the provider's catalog.
+const LOOKUP_DELAY: Duration = Duration::from_millis(500);
+
+#[derive(Clone, Debug, PartialEq, Eq)]
+enum TransferTypeChoice {
+  Supported(String),
+  Other,
+}
+
+impl std::fmt::Display for TransferTypeChoice {
+  fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+    match self {
+      TransferTypeChoice::Supported(transfer_type) => write!(f, "{transfer_type}"),
+      TransferTypeChoice::Other => write!(f, "Other…"),
+    }
+  }
+}
+
+#[derive(Clone, Debug, PartialEq, Properties)]
+pub struct TransferTypeFieldProps {
+  pub contract_id: String,
+  pub value: String,
+  pub onchange: Callback<String>,
+}
+
+/// Offers the transfer types the agreed asset can actually be transferred with, keeping free text
+/// for anything the connector doesn't advertise.
+#[component]
+pub fn TransferTypeField(props: &TransferTypeFieldProps) -> Html {
+  let edc_connector_context = use_edc_connector_context();
+  let supported = use_state(|| Option::<SupportedTransferTypes>::None);
+  let other = use_state(|| false);
+  let generation = use_mut_ref(|| 0usize);
+
+  {
+    let supported = supported.clone();
+
+    use_effect_with(
+      (edc_connector_context, props.contract_id.clone()),
+      move |(edc_connector_context, contract_id)| {
+        *generation.borrow_mut() += 1;
+        let current_generation = *generation.borrow();
+
+        if let Some(client) = edc_connector_context.get_client() {
+          let contract_id = contract_id.trim().to_string();
+
+          spawn_local(async move {
+            sleep(LOOKUP_DELAY).await;
+
+            if *generation.borrow() != current_generation {
+              return;
+            }
+
+            let transfer_types = supported_transfer_types(&client, &contract_id).await;
+
+            if *generation.borrow() == current_generation {
+              supported.set(Some(transfer_types));
+            }
+          });
+        }
+      },
+    );
+  }
+
+  let transfer_types = (*supported)
+    .as_ref()
+    .map(|supported| supported.transfer_types.clone())
+    .unwrap_or_default();
+
+  let selected = if *other || (!props.value.is_empty() && !transfer_types.contains(&props.value)) {
+    Some(TransferTypeChoice::Other)
+  } else if props.value.is_empty() {
+    None
+  } else {
+    Some(TransferTypeChoice::Supported(props.value.clone()))
+  };
+
+  let onselect = use_callback(
+    (other.setter(), props.onchange.clone()),
+    |choice, (other_setter, onchange)| match choice {
+      TransferTypeChoice::Supported(transfer_type) => {
+        other_setter.set(false);
+        onchange.emit(transfer_type);
+      }
+      TransferTypeChoice::Other => {
+        other_setter.set(true);
+        onchange.emit(String::new());
+      }
+    },
+  );
+
+  let entries = transfer_types
+    .iter()
+    .cloned()
+    .map(TransferTypeChoice::Supported)
+    .chain([TransferTypeChoice::Other])
+    .collect::<Vec<_>>();
+
+  let helper_text = match &*supported {
+    Some(supported) if !supported.transfer_types.is_empty() => {
+      FormHelperText::from(format!("Supported by {}", supported.source).as_str())
+    }
+    Some(_) => FormHelperText::from((
+      "Neither the catalog nor the data planes advertise any transfer type".to_string(),
+      InputState::Warning,
+    )),
+    None => FormHelperText::from("Looking up supported transfer types…"),
+  };
+
+  let free_text = transfer_types.is_empty() || selected == Some(TransferTypeChoice::Other);
+
+  html!(
+    <FormGroup label="Transfer Type" required=true {helper_text}>
+      if !transfer_types.is_empty() {
+        <SimpleSelect<TransferTypeChoice>
+          placeholder="Select a transfer type"
+          {entries}
+          {selected}
+          {onselect}
+        />
+      }
+      if free_text {
+        <TextInput
+          required=true
+          placeholder="e.g. HttpData-PULL"
+          value={props.value.clone()}
+          onchange={props.onchange.clone()}
+        />
+      }
+    </FormGroup>
+  )
+}
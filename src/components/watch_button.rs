@@ -0,0 +1,28 @@
+use crate::contexts::{WatchedKind, use_watcher_context, watch};
+use patternfly_yew::prelude::*;
+use yew::prelude::*;
+
+#[derive(Clone, Debug, PartialEq, Properties)]
+pub struct WatchButtonProps {
+  pub kind: WatchedKind,
+  pub id: String,
+}
+
+#[component]
+pub fn WatchButton(props: &WatchButtonProps) -> Html {
+  let watcher_context = use_watcher_context();
+  let watching = watcher_context.is_watching(props.kind, &props.id);
+
+  let onclick = use_callback(
+    (watcher_context, props.kind, props.id.clone()),
+    |_, (watcher_context, kind, id)| {
+      watch(watcher_context, *kind, id.clone());
+    },
+  );
+
+  html!(
+    <Button icon={Icon::Bell} variant={ButtonVariant::Secondary} disabled={watching} {onclick}>
+      { if watching { "Watching" } else { "Watch" } }
+    </Button>
+  )
+}
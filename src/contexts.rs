@@ -1,3 +1,7 @@
+mod activity_log_context;
 mod edc_connector_context;
+mod watcher_context;
 
+pub use activity_log_context::*;
 pub use edc_connector_context::*;
+pub use watcher_context::*;
@@ -0,0 +1,188 @@
+use crate::models::EntityKind;
+use crate::services::{RestoreEntry, RestoreOutcome, error_message};
+use edc_connector_client::EdcResult;
+use edc_connector_client::types::response::IdResponse;
+use std::rc::Rc;
+use yew::prelude::*;
+
+#[derive(Copy, Clone, Debug, PartialEq, Eq, strum::Display)]
+pub enum ActivityOperation {
+  Create,
+  Update,
+  Delete,
+  Suspend,
+  Resume,
+  Terminate,
+}
+
+#[derive(Clone, Debug, PartialEq)]
+pub struct ActivityEntry {
+  pub timestamp: String,
+  pub operation: ActivityOperation,
+  pub kind: EntityKind,
+  pub id: Option<String>,
+  pub error: Option<String>,
+  /// Management URL of the connector written to, if not the current one.
+  pub connector: Option<String>,
+}
+
+impl ActivityEntry {
+  pub fn new<T>(
+    operation: ActivityOperation,
+    kind: EntityKind,
+    id: Option<String>,
+    result: &EdcResult<T>,
+  ) -> Self {
+    Self::with_error(
+      operation,
+      kind,
+      id,
+      result.as_ref().err().map(error_message),
+    )
+  }
+
+  pub fn with_error(
+    operation: ActivityOperation,
+    kind: EntityKind,
+    id: Option<String>,
+    error: Option<String>,
+  ) -> Self {
+    Self {
+      timestamp: chrono::Utc::now()
+        .format("%Y-%m-%d %H:%M:%S UTC")
+        .to_string(),
+      operation,
+      kind,
+      id: id.filter(|id| !id.is_empty()),
+      error,
+      connector: None,
+    }
+  }
+
+  /// Deleted entities, entities that failed to be created and entities on other connectors have
+  /// nothing to link to.
+  pub fn links_to_entity(&self) -> bool {
+    self.error.is_none() && self.operation != ActivityOperation::Delete && self.connector.is_none()
+  }
+}
+
+#[derive(Debug, PartialEq, Clone)]
+pub enum ActivityLogAction {
+  Record(ActivityEntry),
+  MarkRead,
+  Clear,
+}
+
+#[derive(Clone, Debug, Default, PartialEq)]
+pub struct ActivityLogState {
+  /// Newest entries first.
+  pub entries: Vec<ActivityEntry>,
+  pub unread: usize,
+}
+
+impl Reducible for ActivityLogState {
+  type Action = ActivityLogAction;
+
+  fn reduce(self: Rc<Self>, action: Self::Action) -> Rc<Self> {
+    match action {
+      ActivityLogAction::Record(entry) => {
+        let mut entries = self.entries.clone();
+        entries.insert(0, entry);
+
+        Self {
+          entries,
+          unread: self.unread + 1,
+        }
+      }
+      ActivityLogAction::MarkRead => Self {
+        entries: self.entries.clone(),
+        unread: 0,
+      },
+      ActivityLogAction::Clear => Self::default(),
+    }
+    .into()
+  }
+}
+
+#[derive(Properties, PartialEq)]
+pub struct ActivityLogContextProviderProps {
+  #[prop_or_default]
+  pub children: Html,
+}
+
+/// Keeps a session log of the operations performed through the UI.
+#[component]
+pub fn ActivityLogContextProvider(props: &ActivityLogContextProviderProps) -> Html {
+  let activity_log_context = use_reducer(ActivityLogState::default);
+
+  html! {
+    <ContextProvider<ActivityLogContext> context={activity_log_context}>
+      { props.children.clone() }
+    </ContextProvider<ActivityLogContext>>
+  }
+}
+
+pub type ActivityLogContext = UseReducerHandle<ActivityLogState>;
+
+#[hook]
+pub fn use_activity_log_context() -> ActivityLogContext {
+  use_context::<ActivityLogContext>().expect("no activity log context found")
+}
+
+pub fn record_activity<T>(
+  activity_log_context: &ActivityLogContext,
+  operation: ActivityOperation,
+  kind: EntityKind,
+  id: Option<String>,
+  result: &EdcResult<T>,
+) {
+  activity_log_context.dispatch(ActivityLogAction::Record(ActivityEntry::new(
+    operation, kind, id, result,
+  )));
+}
+
+/// Records a create operation, preferring the ID assigned by the connector over the requested one.
+pub fn record_creation(
+  activity_log_context: &ActivityLogContext,
+  kind: EntityKind,
+  requested_id: Option<String>,
+  result: &EdcResult<IdResponse<String>>,
+) {
+  let id = match result {
+    Ok(id_response) => Some(id_response.id().clone()),
+    Err(_) => requested_id,
+  };
+
+  record_activity(
+    activity_log_context,
+    ActivityOperation::Create,
+    kind,
+    id,
+    result,
+  );
+}
+
+/// Records what a restore, or a copy between connectors, wrote for one entry, `connector` naming
+/// the target when it is not the current connector. Skipped entries were left alone and are not
+/// recorded.
+pub fn record_restore_entry(
+  activity_log_context: &ActivityLogContext,
+  restore_entry: &RestoreEntry,
+  connector: Option<String>,
+) {
+  let id = restore_entry.id.clone();
+
+  let (operation, id, error) = match &restore_entry.outcome {
+    RestoreOutcome::Created => (ActivityOperation::Create, id, None),
+    RestoreOutcome::Renamed(new_id) => (ActivityOperation::Create, new_id.clone(), None),
+    RestoreOutcome::Overwritten => (ActivityOperation::Update, id, None),
+    RestoreOutcome::Skipped => return,
+    RestoreOutcome::Failed(error) => (ActivityOperation::Create, id, Some(error.clone())),
+    RestoreOutcome::OverwriteFailed(error) => (ActivityOperation::Update, id, Some(error.clone())),
+  };
+
+  activity_log_context.dispatch(ActivityLogAction::Record(ActivityEntry {
+    connector,
+    ..ActivityEntry::with_error(operation, restore_entry.kind, Some(id), error)
+  }));
+}
@@ -12,6 +12,21 @@ pub struct EdcConnectorState {
 }
 
 impl EdcConnectorState {
+  pub fn new(management_url: String, api_key: Option<String>) -> Self {
+    Self {
+      management_url,
+      api_key,
+    }
+  }
+
+  pub fn management_url(&self) -> &str {
+    &self.management_url
+  }
+
+  pub fn api_key(&self) -> Option<&str> {
+    self.api_key.as_deref()
+  }
+
   pub fn get_client(&self) -> Option<EdcConnectorClient> {
     let builder = EdcConnectorClient::builder().management_url(self.management_url.clone());
 
@@ -45,9 +60,8 @@ pub struct Props {
 
 #[component]
 pub fn EdcConnectorContextProvider(props: &Props) -> Html {
-  let edc_connector_context = use_reducer(move || EdcConnectorState {
-    management_url: props.management_url.clone(),
-    api_key: props.api_key.clone(),
+  let edc_connector_context = use_reducer(move || {
+    EdcConnectorState::new(props.management_url.clone(), props.api_key.clone())
   });
 
   html! {
@@ -0,0 +1,224 @@
+use crate::contexts::use_edc_connector_context;
+use crate::models::{ContractNegotiationState, TransferProcessState};
+use crate::services::{request_browser_notification_permission, show_browser_notification};
+use edc_connector_client::types::contract_negotiation::ContractNegotiationState as EdcContractNegotiationState;
+use edc_connector_client::types::transfer_process::TransferProcessState as EdcTransferProcessState;
+use edc_connector_client::{EdcConnectorClient, EdcResult, Error};
+use patternfly_yew::prelude::*;
+use reqwest::StatusCode;
+use std::rc::Rc;
+use std::time::Duration;
+use yew::platform::spawn_local;
+use yew::platform::time::sleep;
+use yew::prelude::*;
+
+const WATCH_POLL_INTERVAL: Duration = Duration::from_secs(5);
+
+#[derive(Copy, Clone, Debug, PartialEq, Eq, strum::Display)]
+pub enum WatchedKind {
+  #[strum(to_string = "Contract Negotiation")]
+  ContractNegotiation,
+  #[strum(to_string = "Transfer Process")]
+  TransferProcess,
+}
+
+#[derive(Clone, Debug, PartialEq)]
+pub struct Watch {
+  pub kind: WatchedKind,
+  pub id: String,
+  pub state: Option<String>,
+}
+
+#[derive(Debug, PartialEq, Clone)]
+pub enum WatcherAction {
+  Watch(WatchedKind, String),
+  Unwatch(WatchedKind, String),
+  Observed(WatchedKind, String, String),
+}
+
+#[derive(Clone, Debug, Default, PartialEq)]
+pub struct WatcherState {
+  pub watches: Vec<Watch>,
+}
+
+impl WatcherState {
+  pub fn is_watching(&self, kind: WatchedKind, id: &str) -> bool {
+    self
+      .watches
+      .iter()
+      .any(|watch| watch.kind == kind && watch.id == id)
+  }
+}
+
+impl Reducible for WatcherState {
+  type Action = WatcherAction;
+
+  fn reduce(self: Rc<Self>, action: Self::Action) -> Rc<Self> {
+    let mut watches = self.watches.clone();
+
+    match action {
+      WatcherAction::Watch(kind, id) => {
+        if self.is_watching(kind, &id) {
+          return self;
+        }
+
+        watches.push(Watch {
+          kind,
+          id,
+          state: None,
+        });
+      }
+      WatcherAction::Unwatch(kind, id) => {
+        watches.retain(|watch| watch.kind != kind || watch.id != id);
+      }
+      WatcherAction::Observed(kind, id, state) => {
+        for watch in watches
+          .iter_mut()
+          .filter(|watch| watch.kind == kind && watch.id == id)
+        {
+          watch.state = Some(state.clone());
+        }
+      }
+    }
+
+    Self { watches }.into()
+  }
+}
+
+#[derive(Properties, PartialEq)]
+pub struct WatcherContextProviderProps {
+  #[prop_or_default]
+  pub children: Html,
+}
+
+/// Polls watched negotiations and transfers in the background, independent of the current page,
+/// and notifies once each reaches a terminal state.
+#[component]
+pub fn WatcherContextProvider(props: &WatcherContextProviderProps) -> Html {
+  let edc_connector_context = use_edc_connector_context();
+  let toaster = use_toaster();
+  let watcher_context = use_reducer(WatcherState::default);
+  let watches = use_mut_ref(Vec::<Watch>::new);
+  let generation = use_mut_ref(|| 0usize);
+
+  *watches.borrow_mut() = watcher_context.watches.clone();
+
+  {
+    let dispatcher = watcher_context.dispatcher();
+
+    use_effect_with(edc_connector_context, move |edc_connector_context| {
+      *generation.borrow_mut() += 1;
+      let current_generation = *generation.borrow();
+
+      if let Some(client) = edc_connector_context.get_client() {
+        let generation = generation.clone();
+
+        spawn_local(async move {
+          loop {
+            sleep(WATCH_POLL_INTERVAL).await;
+
+            if *generation.borrow() != current_generation {
+              break;
+            }
+
+            let current_watches = watches.borrow().clone();
+
+            for watch in current_watches {
+              let (state, terminal) = match poll_state(&client, watch.kind, &watch.id).await {
+                Ok(polled) => polled,
+                // Deleted, or never existed on this connector, it will not change anymore.
+                Err(Error::ManagementApi(error)) if error.status_code == StatusCode::NOT_FOUND => {
+                  dispatcher.dispatch(WatcherAction::Unwatch(watch.kind, watch.id));
+                  continue;
+                }
+                // Others may be transient, so keep watching.
+                Err(error) => {
+                  log::warn!("Error polling {} {}: {}", watch.kind, watch.id, error);
+                  continue;
+                }
+              };
+
+              if terminal {
+                dispatcher.dispatch(WatcherAction::Unwatch(watch.kind, watch.id.clone()));
+                notify(toaster.as_ref(), &watch, &state);
+              } else if watch.state.as_ref() != Some(&state) {
+                dispatcher.dispatch(WatcherAction::Observed(watch.kind, watch.id, state));
+              }
+            }
+          }
+        });
+      }
+
+      move || *generation.borrow_mut() += 1
+    });
+  }
+
+  html! {
+    <ContextProvider<WatcherContext> context={watcher_context}>
+      { props.children.clone() }
+    </ContextProvider<WatcherContext>>
+  }
+}
+
+async fn poll_state(
+  client: &EdcConnectorClient,
+  kind: WatchedKind,
+  id: &str,
+) -> EdcResult<(String, bool)> {
+  match kind {
+    WatchedKind::ContractNegotiation => {
+      let state = client.contract_negotiations().get_state(id).await?;
+      let terminal = matches!(
+        state,
+        EdcContractNegotiationState::Finalized | EdcContractNegotiationState::Terminated
+      );
+
+      Ok((ContractNegotiationState::from(&state).to_string(), terminal))
+    }
+    WatchedKind::TransferProcess => {
+      let state = client.transfer_processes().get_state(id).await?;
+      let terminal = matches!(
+        state,
+        EdcTransferProcessState::Started
+          | EdcTransferProcessState::Completed
+          | EdcTransferProcessState::Terminated
+      );
+
+      Ok((TransferProcessState::from(&state).to_string(), terminal))
+    }
+  }
+}
+
+fn notify(toaster: Option<&Toaster>, watch: &Watch, state: &str) {
+  let title = format!("{} {state}", watch.kind);
+  let body = format!("{} {} reached {state}", watch.kind, watch.id);
+
+  if let Some(toaster) = toaster {
+    toaster.toast(Toast {
+      title: title.clone(),
+      r#type: if state == "Terminated" {
+        AlertType::Warning
+      } else {
+        AlertType::Success
+      },
+      timeout: Some(Duration::from_secs(10)),
+      body: html!(body.clone()),
+      ..Default::default()
+    });
+  }
+
+  show_browser_notification(&title, &body);
+}
+
+pub type WatcherContext = UseReducerHandle<WatcherState>;
+
+#[hook]
+pub fn use_watcher_context() -> WatcherContext {
+  use_context::<WatcherContext>().expect("no watcher context found")
+}
+
+/// Starts watching an entity, asking for browser notification permission along the way.
+pub fn watch(watcher_context: &WatcherContext, kind: WatchedKind, id: String) {
+  request_browser_notification_permission();
+  watcher_context.dispatch(WatcherAction::Watch(kind, id));
+}
@@ -0,0 +1,9 @@
+mod use_auto_refresh;
+mod use_changed_states;
+mod use_list_state;
+mod use_total_entries;
+
+pub use use_auto_refresh::*;
+pub use use_changed_states::*;
+pub use use_list_state::*;
+pub use use_total_entries::*;
@@ -0,0 +1,38 @@
+use std::time::Duration;
+use yew::platform::spawn_local;
+use yew::platform::time::sleep;
+use yew::prelude::*;
+
+/// Emits `onrefresh` every `interval` while it is set, skipping ticks while the tab is hidden.
+#[hook]
+pub fn use_auto_refresh(interval: Option<Duration>, onrefresh: Callback<()>) {
+  let generation = use_mut_ref(|| 0usize);
+  let latest_onrefresh = use_mut_ref(|| onrefresh.clone());
+
+  *latest_onrefresh.borrow_mut() = onrefresh;
+
+  use_effect_with(interval, move |interval| {
+    *generation.borrow_mut() += 1;
+    let current_generation = *generation.borrow();
+
+    if let Some(interval) = *interval {
+      let generation = generation.clone();
+
+      spawn_local(async move {
+        loop {
+          sleep(interval).await;
+
+          if *generation.borrow() != current_generation {
+            break;
+          }
+
+          if !gloo_utils::document().hidden() {
+            latest_onrefresh.borrow().emit(());
+          }
+        }
+      });
+    }
+
+    move || *generation.borrow_mut() += 1
+  });
+}
@@ -0,0 +1,27 @@
+use std::collections::{HashMap, HashSet};
+use std::rc::Rc;
+use yew::prelude::*;
+
+/// Returns the IDs whose state differs from the last `(id, state)` list this component saw.
+#[hook]
+pub fn use_changed_states(states: Vec<(String, String)>) -> Rc<HashSet<String>> {
+  let previous_states = use_mut_ref(HashMap::<String, String>::new);
+
+  use_memo(states, move |states| {
+    let mut previous_states = previous_states.borrow_mut();
+
+    let changed = states
+      .iter()
+      .filter(|(id, state)| {
+        previous_states
+          .get(id)
+          .is_some_and(|previous_state| previous_state != state)
+      })
+      .map(|(id, _)| id.clone())
+      .collect::<HashSet<_>>();
+
+    previous_states.extend(states.iter().cloned());
+
+    changed
+  })
+}
@@ -0,0 +1,51 @@
+use crate::models::ListState;
+use web_sys::wasm_bindgen::JsValue;
+use yew::prelude::*;
+use yew_nested_router::History;
+
+/// Keeps a list page's pagination, sort, filters and extra columns in the query string, so the
+/// page can be shared and survives a reload.
+#[hook]
+pub fn use_list_state() -> UseStateHandle<ListState> {
+  let list_state = use_state_eq(current_list_state);
+
+  {
+    let list_state = list_state.clone();
+
+    // Follows the URL when it changes under the page, e.g. going back or forward.
+    use_effect_with((), move |_| {
+      let listener = History::listener(move || list_state.set(current_list_state()));
+
+      move || drop(listener)
+    });
+  }
+
+  use_effect_with((*list_state).clone(), |list_state| {
+    let location = gloo_utils::window().location();
+    let pathname = location.pathname().unwrap_or_default();
+    let search = list_state.to_search();
+
+    if location
+      .search()
+      .unwrap_or_default()
+      .trim_start_matches('?')
+      != search
+    {
+      let url = if search.is_empty() {
+        pathname
+      } else {
+        format!("{pathname}?{search}")
+      };
+
+      let _ = History::replace_state(JsValue::NULL, &url);
+    }
+  });
+
+  list_state
+}
+
+fn current_list_state() -> ListState {
+  let search = gloo_utils::window().location().search().unwrap_or_default();
+
+  ListState::from_search(&search)
+}
@@ -0,0 +1,49 @@
+use crate::contexts::use_edc_connector_context;
+use crate::services::count_all;
+use edc_connector_client::types::query::Query;
+use edc_connector_client::{EdcConnectorClient, EdcResult};
+use std::future::Future;
+use yew::platform::spawn_local;
+use yew::prelude::*;
+
+/// Counts the entries matching `query` in the background, recounting whenever `deps` change.
+///
+/// This pages through every entry, so pages with auto-refresh keep its ticks out of `deps`.
+#[hook]
+pub fn use_total_entries<D, T, F, Fut>(deps: D, query: Query, fetch: F) -> Option<usize>
+where
+  D: PartialEq + 'static,
+  T: 'static,
+  F: Fn(EdcConnectorClient, Query) -> Fut + 'static,
+  Fut: Future<Output = EdcResult<Vec<T>>> + 'static,
+{
+  let edc_connector_context = use_edc_connector_context();
+  let total_entries = use_state(|| Option::<usize>::None);
+  let generation = use_mut_ref(|| 0usize);
+
+  {
+    let total_entries = total_entries.clone();
+
+    use_effect_with(
+      (edc_connector_context, deps),
+      move |(edc_connector_context, _)| {
+        *generation.borrow_mut() += 1;
+        let current_generation = *generation.borrow();
+        // The previous total no longer applies, e.g. after a filter change.
+        total_entries.set(None);
+
+        if let Some(client) = edc_connector_context.get_client() {
+          spawn_local(async move {
+            let count = count_all(query, |query| fetch(client.clone(), query)).await;
+
+            if *generation.borrow() == current_generation {
+              total_entries.set(count.ok());
+            }
+          });
+        }
+      },
+    );
+  }
+
+  *total_entries
+}
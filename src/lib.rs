@@ -1,5 +1,8 @@
 pub mod components;
 pub mod contexts;
+pub mod hooks;
 
 pub mod models;
 pub mod pages;
+pub mod routes;
+pub mod services;
@@ -20,30 +20,19 @@ fn main() {
 
 #[cfg(target_arch = "wasm32")]
 mod main_application {
-  use edc_web_ui::{contexts::EdcConnectorContextProvider, pages::*};
+  use edc_web_ui::{
+    components::{ActivityLogPanel, ActivityLogToggle, GlobalSearch},
+    contexts::{ActivityLogContextProvider, EdcConnectorContextProvider, WatcherContextProvider},
+    pages::*,
+    routes::AppRoute,
+  };
   use patternfly_yew::prelude::*;
   use serde::Deserialize;
   use wasm_cookies::CookieOptions;
   use yew::platform::spawn_local;
   use yew::prelude::*;
+  use yew_nested_router::Router;
   use yew_nested_router::prelude::Switch as RouterSwitch;
-  use yew_nested_router::{Router, Target};
-
-  #[derive(Debug, Clone, Copy, PartialEq, Target, Eq)]
-  enum AppRoute {
-    #[target(rename = "assets")]
-    Assets,
-    #[target(rename = "policies")]
-    Policies,
-    #[target(rename = "contract-definitions")]
-    ContractDefinitions,
-    #[target(rename = "contract-negotiations")]
-    ContractNegotiations,
-    #[target(rename = "contract-agreements")]
-    ContractAgreements,
-    #[target(rename = "transfer-processes")]
-    TransferProcesses,
-  }
 
   #[derive(Clone, Debug, Deserialize, PartialEq)]
   struct Configuration {
@@ -145,11 +134,17 @@ mod main_application {
       };
 
       html!(
-        <Router<AppRoute> default={AppRoute::Assets}>
+        <Router<AppRoute> default={AppRoute::Dashboard}>
           <EdcConnectorContextProvider {management_url} {api_key}>
-            <BackdropViewer>
-              <MainView {onlogout} />
-            </BackdropViewer>
+            <ToastViewer>
+              <WatcherContextProvider>
+                <ActivityLogContextProvider>
+                  <BackdropViewer>
+                    <MainView {onlogout} />
+                  </BackdropViewer>
+                </ActivityLogContextProvider>
+              </WatcherContextProvider>
+            </ToastViewer>
           </EdcConnectorContextProvider>
         </Router<AppRoute>>
       )
@@ -225,22 +220,64 @@ mod main_application {
       <PageSidebar>
         <Nav>
           <NavList>
-            <NavRouterItem<AppRoute> to={AppRoute::Assets}>{ "Assets" }</NavRouterItem<AppRoute>>
-            <NavRouterItem<AppRoute> to={AppRoute::Policies}>
+            <NavRouterItem<AppRoute> to={AppRoute::Dashboard}>{ "Dashboard" }</NavRouterItem<AppRoute>>
+            <NavRouterItem<AppRoute>
+              to={AppRoute::Assets}
+              predicate={Callback::from(|route| {
+                matches!(route, AppRoute::Assets | AppRoute::Asset { .. })
+              })}
+            >
+              { "Assets" }
+            </NavRouterItem<AppRoute>>
+            <NavRouterItem<AppRoute>
+              to={AppRoute::Policies}
+              predicate={Callback::from(|route| {
+                matches!(route, AppRoute::Policies | AppRoute::PolicyDefinition { .. })
+              })}
+            >
               { "Policies" }
             </NavRouterItem<AppRoute>>
-            <NavRouterItem<AppRoute> to={AppRoute::ContractDefinitions}>
+            <NavRouterItem<AppRoute>
+              to={AppRoute::ContractDefinitions}
+              predicate={Callback::from(|route| {
+                matches!(route, AppRoute::ContractDefinitions | AppRoute::ContractDefinition { .. })
+              })}
+            >
               { "Contract Definitions" }
             </NavRouterItem<AppRoute>>
-            <NavRouterItem<AppRoute> to={AppRoute::ContractNegotiations}>
+            <NavRouterItem<AppRoute>
+              to={AppRoute::ContractNegotiations}
+              predicate={Callback::from(|route| {
+                matches!(route, AppRoute::ContractNegotiations | AppRoute::ContractNegotiation { .. })
+              })}
+            >
               { "Contract Negotiations" }
             </NavRouterItem<AppRoute>>
-            <NavRouterItem<AppRoute> to={AppRoute::ContractAgreements}>
+            <NavRouterItem<AppRoute>
+              to={AppRoute::ContractAgreements}
+              predicate={Callback::from(|route| {
+                matches!(route, AppRoute::ContractAgreements | AppRoute::ContractAgreement { .. })
+              })}
+            >
               { "Contract Agreements" }
             </NavRouterItem<AppRoute>>
-            <NavRouterItem<AppRoute> to={AppRoute::TransferProcesses}>
+            <NavRouterItem<AppRoute>
+              to={AppRoute::TransferProcesses}
+              predicate={Callback::from(|route| {
+                matches!(route, AppRoute::TransferProcesses | AppRoute::TransferProcess { .. })
+              })}
+            >
               { "Transfer Processes" }
             </NavRouterItem<AppRoute>>
+            <NavRouterItem<AppRoute> to={AppRoute::Edrs}>{ "EDRs" }</NavRouterItem<AppRoute>>
+            <NavRouterItem<AppRoute> to={AppRoute::Secrets}>{ "Secrets" }</NavRouterItem<AppRoute>>
+            <NavRouterItem<AppRoute> to={AppRoute::DataPlanes}>{ "Data Planes" }</NavRouterItem<AppRoute>>
+            <NavRouterItem<AppRoute> to={AppRoute::Backup}>
+              { "Backup & Restore" }
+            </NavRouterItem<AppRoute>>
+            <NavRouterItem<AppRoute> to={AppRoute::Compare}>
+              { "Compare Connectors" }
+            </NavRouterItem<AppRoute>>
           </NavList>
         </Nav>
       </PageSidebar>
@@ -257,13 +294,33 @@ mod main_application {
       onlogout.emit(());
     });
 
+    let activity_log_expanded = use_state(|| false);
+
+    let ontoggle_activity_log =
+      use_callback(activity_log_expanded.clone(), |_, activity_log_expanded| {
+        activity_log_expanded.set(!**activity_log_expanded);
+      });
+
+    let onclose_activity_log = use_callback(
+      activity_log_expanded.setter(),
+      |_, activity_log_expanded_setter| {
+        activity_log_expanded_setter.set(false);
+      },
+    );
+
     let tools = html!(
       <Toolbar>
         <ToolbarContent>
+          <ToolbarItem>
+            <GlobalSearch />
+          </ToolbarItem>
           <ToolbarGroup
             modifiers={ToolbarElementModifier::End.all()}
             variant={GroupVariant::IconButton}
           >
+            <ToolbarItem>
+              <ActivityLogToggle ontoggle={ontoggle_activity_log} />
+            </ToolbarItem>
             <ToolbarItem>
               <ToggleTheme />
             </ToolbarItem>
@@ -277,21 +334,42 @@ mod main_application {
 
     html!(
       <Page {brand} {sidebar} {tools} full_height=true>
-        <PageSection>
-          <RouterSwitch<AppRoute> render={switch_app_route} />
-        </PageSection>
+        <Drawer expanded={*activity_log_expanded}>
+          <DrawerContent
+            panel_content={html!(<ActivityLogPanel onclose={onclose_activity_log} />)}
+          >
+            <DrawerContentBody>
+              <PageSection>
+                <RouterSwitch<AppRoute> render={switch_app_route} />
+              </PageSection>
+            </DrawerContentBody>
+          </DrawerContent>
+        </Drawer>
       </Page>
     )
   }
 
   fn switch_app_route(target: AppRoute) -> Html {
     match target {
+      AppRoute::Dashboard => html! { <DashboardPage /> },
       AppRoute::Assets => html! { <AssetPage /> },
+      AppRoute::Asset { id } => html! { <AssetDetailsPage {id} /> },
       AppRoute::Policies => html! { <PolicyPage /> },
+      AppRoute::PolicyDefinition { id } => html! { <PolicyDefinitionDetailsPage {id} /> },
       AppRoute::ContractDefinitions => html! { <ContractDefinitionPage /> },
+      AppRoute::ContractDefinition { id } => html! { <ContractDefinitionDetailsPage {id} /> },
       AppRoute::ContractNegotiations => html! { <ContractNegotiationPage /> },
+      AppRoute::ContractNegotiation { id } => html! { <ContractNegotiationDetailsPage {id} /> },
       AppRoute::ContractAgreements => html! { <ContractAgreementPage /> },
+      AppRoute::ContractAgreement { id } => html! { <ContractAgreementDetailsPage {id} /> },
       AppRoute::TransferProcesses => html! { <TransferProcessPage /> },
+      AppRoute::TransferProcess { id } => html! { <TransferProcessDetailsPage {id} /> },
+      AppRoute::Secrets => html! { <SecretPage /> },
+      AppRoute::Edrs => html! { <EdrPage /> },
+      AppRoute::DataPlanes => html! { <DataPlanePage /> },
+      AppRoute::Backup => html! { <BackupPage /> },
+      AppRoute::Compare => html! { <ComparePage /> },
+      AppRoute::Search { term } => html! { <SearchPage {term} /> },
     }
   }
 
@@ -1,23 +1,57 @@
+mod age_threshold;
+mod asset_draft;
 mod asset_item;
+mod asset_property_draft;
+mod auto_refresh;
+mod callback_address_item;
 mod contract_agreement_item;
+mod contract_definition_draft;
 mod contract_definition_item;
+mod contract_negotiation_details;
 mod contract_negotiation_item;
 mod contract_negotiation_kind;
 mod contract_negotiation_state;
+mod data_destination_draft;
+mod data_plane_instance_item;
+mod data_plane_instance_state;
+mod edr_entry_item;
+mod endpoint_data_reference;
+mod entity_kind;
+mod list_query;
 mod policy_definition;
 mod policy_kind;
+mod secret_item;
+mod secret_property;
+mod transfer_process_details;
 mod transfer_process_item;
 mod transfer_process_kind;
 mod transfer_process_state;
 
+pub use age_threshold::*;
+pub use asset_draft::*;
 pub use asset_item::*;
+pub use asset_property_draft::*;
+pub use auto_refresh::*;
+pub use callback_address_item::*;
 pub use contract_agreement_item::*;
+pub use contract_definition_draft::*;
 pub use contract_definition_item::*;
+pub use contract_negotiation_details::*;
 pub use contract_negotiation_item::*;
 pub use contract_negotiation_kind::*;
 pub use contract_negotiation_state::*;
+pub use data_destination_draft::*;
+pub use data_plane_instance_item::*;
+pub use data_plane_instance_state::*;
+pub use edr_entry_item::*;
+pub use endpoint_data_reference::*;
+pub use entity_kind::*;
+pub use list_query::*;
 pub use policy_definition::*;
 pub use policy_kind::*;
+pub use secret_item::*;
+pub use secret_property::*;
+pub use transfer_process_details::*;
 pub use transfer_process_item::*;
 pub use transfer_process_kind::*;
 pub use transfer_process_state::*;
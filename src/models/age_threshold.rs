@@ -0,0 +1,36 @@
+use std::time::Duration;
+
+/// How old a negotiation or transfer in a non-terminal state must be to be listed on the dashboard.
+/// Negotiations only report their creation, so their age is counted from there, not from their
+/// last state change.
+#[derive(Copy, Clone, Debug, PartialEq, Eq)]
+pub struct AgeThreshold(pub u64);
+
+impl AgeThreshold {
+  pub const ALL: [AgeThreshold; 4] = [
+    AgeThreshold(5),
+    AgeThreshold(15),
+    AgeThreshold(60),
+    AgeThreshold(1440),
+  ];
+
+  pub fn duration(self) -> Duration {
+    Duration::from_secs(self.0 * 60)
+  }
+}
+
+impl Default for AgeThreshold {
+  fn default() -> Self {
+    AgeThreshold(15)
+  }
+}
+
+impl std::fmt::Display for AgeThreshold {
+  fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+    match self.0 {
+      minutes if minutes % 1440 == 0 => write!(f, "Older than {}d", minutes / 1440),
+      minutes if minutes % 60 == 0 => write!(f, "Older than {}h", minutes / 60),
+      minutes => write!(f, "Older than {minutes}min"),
+    }
+  }
+}
@@ -0,0 +1,686 @@
+use super::{AssetPropertyDraft, expand_property_name};
+use crate::services::{compact_property_name, plain_value};
+use edc_connector_client::types::asset::{Asset, NewAsset};
+use edc_connector_client::types::data_address::{DataAddress, DataAddressBuilder};
+use edc_connector_client::types::properties::ToValue;
+use serde::{Deserialize, Deserializer};
+use serde_json::Value;
+use std::collections::HashMap;
+
+#[derive(Copy, Clone, Debug, Default, PartialEq, Eq, strum::Display)]
+pub enum AssetDataAddressKind {
+  #[default]
+  #[strum(to_string = "HTTP")]
+  HttpData,
+  #[strum(to_string = "Amazon S3")]
+  AmazonS3,
+  #[strum(to_string = "Azure Storage")]
+  AzureStorage,
+  #[strum(to_string = "Custom")]
+  Custom,
+}
+
+impl AssetDataAddressKind {
+  pub const ALL: [AssetDataAddressKind; 4] = [
+    AssetDataAddressKind::HttpData,
+    AssetDataAddressKind::AmazonS3,
+    AssetDataAddressKind::AzureStorage,
+    AssetDataAddressKind::Custom,
+  ];
+
+  /// Whether the form of this type edits the given data address property.
+  fn has_field_for(&self, property: &str) -> bool {
+    match self {
+      AssetDataAddressKind::HttpData => {
+        property.starts_with("header:")
+          || [
+            "baseUrl",
+            "contentType",
+            "proxyPath",
+            "proxyQueryParams",
+            "proxyMethod",
+            "proxyBody",
+            "method",
+            "path",
+            "queryParams",
+            "authKey",
+            "authCode",
+            "secretName",
+            "oauth2:tokenUrl",
+            "oauth2:clientId",
+            "oauth2:clientSecretKey",
+            "oauth2:scope",
+          ]
+          .contains(&property)
+      }
+      AssetDataAddressKind::AmazonS3 => {
+        ["bucketName", "region", "objectName", "keyName"].contains(&property)
+      }
+      AssetDataAddressKind::AzureStorage => {
+        ["account", "container", "blobName", "keyName"].contains(&property)
+      }
+      AssetDataAddressKind::Custom => true,
+    }
+  }
+}
+
+/// How the data plane authenticates against an HTTP data source.
+#[derive(Copy, Clone, Debug, Default, PartialEq, Eq, strum::Display)]
+pub enum AssetAuthKind {
+  #[default]
+  #[strum(to_string = "None")]
+  None,
+  #[strum(to_string = "Header value")]
+  AuthCode,
+  #[strum(to_string = "Header from vault secret")]
+  Secret,
+  #[strum(to_string = "OAuth2 client credentials")]
+  OAuth2,
+}
+
+impl AssetAuthKind {
+  pub const ALL: [AssetAuthKind; 4] = [
+    AssetAuthKind::None,
+    AssetAuthKind::AuthCode,
+    AssetAuthKind::Secret,
+    AssetAuthKind::OAuth2,
+  ];
+}
+
+/// Form and import state of an asset. Imports always describe HTTP assets, the other data address
+/// types are only set through the form.
+#[derive(Clone, Debug, Default, Deserialize, PartialEq)]
+#[serde(default, rename_all = "camelCase", deny_unknown_fields)]
+pub struct AssetDraft {
+  #[serde(alias = "@id")]
+  pub id: String,
+  pub name: String,
+  #[serde(skip)]
+  pub kind: AssetDataAddressKind,
+  pub base_url: String,
+  pub content_type: String,
+  pub proxy_path: bool,
+  pub proxy_query_params: bool,
+  pub proxy_method: bool,
+  pub proxy_body: bool,
+  pub method: String,
+  pub path: String,
+  pub query_params: String,
+  /// Content type of the request body sent to the source, unrelated to the asset's own.
+  pub request_content_type: String,
+  #[serde(deserialize_with = "deserialize_headers")]
+  pub headers: Vec<(String, String)>,
+  /// Header carrying `auth_code` or the `secret_name` vault secret, `Authorization` if empty.
+  pub auth_key: String,
+  pub auth_code: String,
+  pub secret_name: String,
+  #[serde(rename = "oauth2:tokenUrl")]
+  pub oauth2_token_url: String,
+  #[serde(rename = "oauth2:clientId")]
+  pub oauth2_client_id: String,
+  /// Vault key of the client secret, the data plane never takes it inline.
+  #[serde(rename = "oauth2:clientSecretKey")]
+  pub oauth2_client_secret_key: String,
+  #[serde(rename = "oauth2:scope")]
+  pub oauth2_scope: String,
+  #[serde(skip)]
+  pub bucket_name: String,
+  #[serde(skip)]
+  pub region: String,
+  #[serde(skip)]
+  pub object_name: String,
+  #[serde(skip)]
+  pub account_name: String,
+  #[serde(skip)]
+  pub container_name: String,
+  #[serde(skip)]
+  pub blob_name: String,
+  /// Vault key of the storage credentials of S3 and Azure data addresses.
+  #[serde(skip)]
+  pub key_name: String,
+  #[serde(skip)]
+  pub custom_type: String,
+  #[serde(skip)]
+  pub custom_properties: Vec<(String, String)>,
+  /// Prefix to namespace IRI pairs used to expand `properties` names.
+  #[serde(skip)]
+  pub namespaces: Vec<(String, String)>,
+  #[serde(skip)]
+  pub properties: Vec<AssetPropertyDraft>,
+}
+
+impl AssetDraft {
+  pub fn validate(&self) -> Result<(), String> {
+    if self.id.trim().is_empty() {
+      return Err("missing id".to_string());
+    }
+
+    if self.name.trim().is_empty() {
+      return Err("missing name".to_string());
+    }
+
+    for property in self
+      .properties
+      .iter()
+      .filter(|property| !property.is_blank())
+    {
+      if property.name.trim().is_empty() {
+        return Err("empty property name".to_string());
+      }
+
+      property.to_value()?;
+    }
+
+    match self.kind {
+      AssetDataAddressKind::HttpData => self.validate_http_data(),
+      AssetDataAddressKind::AmazonS3 => {
+        required(&self.bucket_name, "bucketName")?;
+        required(&self.region, "region")
+      }
+      AssetDataAddressKind::AzureStorage => {
+        required(&self.account_name, "account")?;
+        required(&self.container_name, "container")
+      }
+      AssetDataAddressKind::Custom => {
+        required(&self.custom_type, "type")?;
+
+        if filled_in(&self.custom_properties).any(|(name, _)| name.trim().is_empty()) {
+          return Err("empty property name".to_string());
+        }
+
+        Ok(())
+      }
+    }
+  }
+
+  fn validate_http_data(&self) -> Result<(), String> {
+    if !(self.base_url.starts_with("http://") || self.base_url.starts_with("https://")) {
+      return Err(format!("invalid baseUrl '{}'", self.base_url));
+    }
+
+    if filled_in(&self.headers).any(|(name, _)| name.trim().is_empty()) {
+      return Err("empty header name".to_string());
+    }
+
+    if !self.auth_code.trim().is_empty() && !self.secret_name.trim().is_empty() {
+      return Err("authCode and secretName can't be combined".to_string());
+    }
+
+    if !self.oauth2_token_url.trim().is_empty()
+      && (self.oauth2_client_id.trim().is_empty()
+        || self.oauth2_client_secret_key.trim().is_empty())
+    {
+      return Err("OAuth2 needs a clientId and a clientSecretKey".to_string());
+    }
+
+    Ok(())
+  }
+
+  pub fn auth_kind(&self) -> AssetAuthKind {
+    if !self.oauth2_token_url.is_empty() {
+      AssetAuthKind::OAuth2
+    } else if !self.secret_name.is_empty() {
+      AssetAuthKind::Secret
+    } else if !self.auth_code.is_empty() {
+      AssetAuthKind::AuthCode
+    } else {
+      AssetAuthKind::None
+    }
+  }
+
+  /// Drops the settings of every other kind of authentication.
+  pub fn with_auth_kind(&self, auth_kind: AssetAuthKind) -> AssetDraft {
+    let mut draft = self.clone();
+
+    if !matches!(auth_kind, AssetAuthKind::AuthCode | AssetAuthKind::Secret) {
+      draft.auth_key.clear();
+    }
+    if auth_kind != AssetAuthKind::AuthCode {
+      draft.auth_code.clear();
+    }
+    if auth_kind != AssetAuthKind::Secret {
+      draft.secret_name.clear();
+    }
+    if auth_kind != AssetAuthKind::OAuth2 {
+      draft.oauth2_token_url.clear();
+      draft.oauth2_client_id.clear();
+      draft.oauth2_client_secret_key.clear();
+      draft.oauth2_scope.clear();
+    }
+
+    draft
+  }
+
+  pub fn to_new_asset(&self) -> NewAsset {
+    let data_address = match self.kind {
+      AssetDataAddressKind::HttpData => self.http_data_address(),
+      AssetDataAddressKind::AmazonS3 => with_optional_properties(
+        DataAddress::builder()
+          .kind("AmazonS3")
+          .property("bucketName", self.bucket_name.trim())
+          .property("region", self.region.trim()),
+        [
+          ("objectName", &self.object_name),
+          ("keyName", &self.key_name),
+        ],
+      ),
+      AssetDataAddressKind::AzureStorage => with_optional_properties(
+        DataAddress::builder()
+          .kind("AzureStorage")
+          .property("account", self.account_name.trim())
+          .property("container", self.container_name.trim()),
+        [("blobName", &self.blob_name), ("keyName", &self.key_name)],
+      ),
+      AssetDataAddressKind::Custom => {
+        let mut data_address_builder = DataAddress::builder().kind(self.custom_type.trim());
+
+        for (name, value) in filled_in(&self.custom_properties) {
+          data_address_builder = data_address_builder.property(name.trim(), value.clone());
+        }
+
+        data_address_builder
+      }
+    }
+    .build()
+    .expect("data address kind is always set");
+
+    let mut new_asset_builder = NewAsset::builder()
+      .id(&self.id)
+      .data_address(data_address)
+      .property("name", self.name.clone())
+      .property("contenttype", self.content_type.clone());
+
+    // Invalid values are rejected by `validate` before getting here.
+    for property in self
+      .properties
+      .iter()
+      .filter(|property| !property.is_blank())
+    {
+      let Ok(value) = property.to_value() else {
+        continue;
+      };
+      let name = expand_property_name(&property.name, &self.namespaces);
+
+      new_asset_builder = if property.private {
+        new_asset_builder.private_property(&name, JsonValue(value))
+      } else {
+        new_asset_builder.property(&name, JsonValue(value))
+      };
+    }
+
+    new_asset_builder.build()
+  }
+
+  fn http_data_address(&self) -> DataAddressBuilder {
+    let mut data_address_builder = with_optional_properties(
+      DataAddress::builder()
+        .kind("HttpData")
+        .property("baseUrl", self.base_url.clone())
+        .property("proxyPath", bool_to_string(self.proxy_path))
+        .property("proxyQueryParams", bool_to_string(self.proxy_query_params))
+        .property("proxyMethod", bool_to_string(self.proxy_method))
+        .property("proxyBody", bool_to_string(self.proxy_body)),
+      [
+        ("method", &self.method),
+        ("path", &self.path),
+        ("queryParams", &self.query_params),
+        ("contentType", &self.request_content_type),
+        ("oauth2:tokenUrl", &self.oauth2_token_url),
+        ("oauth2:clientId", &self.oauth2_client_id),
+        ("oauth2:clientSecretKey", &self.oauth2_client_secret_key),
+        ("oauth2:scope", &self.oauth2_scope),
+      ],
+    );
+
+    for (key, value) in filled_in(&self.headers) {
+      data_address_builder =
+        data_address_builder.property(&format!("header:{}", key.trim()), value.clone());
+    }
+
+    let auth_value = [
+      ("authCode", &self.auth_code),
+      ("secretName", &self.secret_name),
+    ]
+    .into_iter()
+    .find(|(_, value)| !value.trim().is_empty());
+
+    if let Some((property, value)) = auth_value {
+      let auth_key = match self.auth_key.trim() {
+        "" => "Authorization",
+        auth_key => auth_key,
+      };
+
+      data_address_builder = data_address_builder
+        .property("authKey", auth_key)
+        .property(property, value.trim());
+    }
+
+    data_address_builder
+  }
+
+  pub fn parse_json(input: &str) -> Result<Vec<Result<AssetDraft, String>>, String> {
+    let values = serde_json::from_str::<Vec<Value>>(input).map_err(|error| error.to_string())?;
+
+    Ok(
+      values
+        .into_iter()
+        .map(|value| {
+          serde_json::from_value::<AssetDraft>(value).map_err(|error| {
+            let error = error.to_string();
+
+            // Misspelled keys are rejected, the list of expected ones is too long to show though.
+            match error.split_once(", expected") {
+              Some((unknown_field, _)) if error.starts_with("unknown field") => {
+                unknown_field.to_string()
+              }
+              _ => error,
+            }
+          })
+        })
+        .collect(),
+    )
+  }
+
+  /// Parses a CSV document whose first line names the columns.
+  ///
+  /// Recognised columns are `id`, `name`, `baseUrl`, `contentType`, the four proxy flags,
+  /// `method`, `path`, `queryParams`, `requestContentType`, `headers` (as `Name=Value;Name=Value`),
+  /// one `header:<Name>` column per header, `authKey`, `authCode`, `secretName` and the `oauth2:*`
+  /// properties.
+  pub fn parse_csv(input: &str) -> Result<Vec<Result<AssetDraft, String>>, String> {
+    let mut records = parse_csv_records(input).into_iter();
+
+    let columns = records.next().ok_or("missing header line")??;
+
+    Ok(
+      records
+        .filter(|record| {
+          record.as_ref().map_or(true, |record| {
+            record.iter().any(|field| !field.trim().is_empty())
+          })
+        })
+        .map(|record| {
+          let record = record?;
+
+          if record.len() != columns.len() {
+            return Err(format!(
+              "{} fields for {} columns",
+              record.len(),
+              columns.len()
+            ));
+          }
+
+          let mut draft = AssetDraft::default();
+
+          for (column, field) in columns.iter().zip(record) {
+            let field = field.trim().to_string();
+
+            if let Some(header) = column.trim().strip_prefix("header:") {
+              if !field.is_empty() {
+                draft.headers.push((header.to_string(), field));
+              }
+
+              continue;
+            }
+
+            match normalize_column(column).as_str() {
+              "id" => draft.id = field,
+              "name" => draft.name = field,
+              "baseurl" => draft.base_url = field,
+              "contenttype" => draft.content_type = field,
+              "proxypath" => draft.proxy_path = parse_bool(&field)?,
+              "proxyqueryparams" => draft.proxy_query_params = parse_bool(&field)?,
+              "proxymethod" => draft.proxy_method = parse_bool(&field)?,
+              "proxybody" => draft.proxy_body = parse_bool(&field)?,
+              "method" => draft.method = field,
+              "path" => draft.path = field,
+              "queryparams" => draft.query_params = field,
+              "requestcontenttype" => draft.request_content_type = field,
+              "authkey" => draft.auth_key = field,
+              "authcode" => draft.auth_code = field,
+              "secretname" => draft.secret_name = field,
+              "oauth2tokenurl" => draft.oauth2_token_url = field,
+              "oauth2clientid" => draft.oauth2_client_id = field,
+              "oauth2clientsecretkey" => draft.oauth2_client_secret_key = field,
+              "oauth2scope" => draft.oauth2_scope = field,
+              "headers" => {
+                for pair in field.split(';').filter(|pair| !pair.trim().is_empty()) {
+                  let (key, value) = pair
+                    .split_once('=')
+                    .ok_or_else(|| format!("invalid header '{pair}'"))?;
+                  draft
+                    .headers
+                    .push((key.trim().to_string(), value.trim().to_string()));
+                }
+              }
+              other => return Err(format!("unknown column '{other}'")),
+            }
+          }
+
+          Ok(draft)
+        })
+        .collect(),
+    )
+  }
+}
+
+/// Asset properties the form has dedicated fields for, or which the connector sets itself.
+const FORM_ASSET_PROPERTIES: [&str; 3] = ["id", "name", "contenttype"];
+
+/// Fills a draft from an existing asset, e.g. to create a similar one.
+impl From<&Asset> for AssetDraft {
+  fn from(asset: &Asset) -> Self {
+    let mut draft = AssetDraft {
+      id: asset.id().to_string(),
+      ..AssetDraft::default()
+    };
+
+    let data_address = serde_json::to_value(asset.data_address()).unwrap_or_default();
+    let data_address_properties = data_address
+      .as_object()
+      .into_iter()
+      .flatten()
+      .filter(|(name, _)| !name.starts_with('@'))
+      .map(|(name, value)| (compact_property_name(name).to_string(), plain_value(value)))
+      .collect::<Vec<_>>();
+
+    let data_address_type = data_address_properties
+      .iter()
+      .find(|(name, _)| name == "type")
+      .map(|(_, value)| value.clone())
+      .unwrap_or_default();
+
+    draft.kind = match data_address_type.as_str() {
+      "HttpData" => AssetDataAddressKind::HttpData,
+      "AmazonS3" => AssetDataAddressKind::AmazonS3,
+      "AzureStorage" => AssetDataAddressKind::AzureStorage,
+      _ => AssetDataAddressKind::Custom,
+    };
+
+    // Properties the type's form has no field for would get lost, so such data addresses are
+    // edited as custom ones instead.
+    if data_address_properties
+      .iter()
+      .any(|(name, _)| name != "type" && !draft.kind.has_field_for(name))
+    {
+      draft.kind = AssetDataAddressKind::Custom;
+    }
+
+    for (name, value) in data_address_properties {
+      if draft.kind == AssetDataAddressKind::Custom {
+        if name == "type" {
+          draft.custom_type = value;
+        } else {
+          draft.custom_properties.push((name, value));
+        }
+
+        continue;
+      }
+
+      if let Some(header) = name.strip_prefix("header:") {
+        draft.headers.push((header.to_string(), value));
+        continue;
+      }
+
+      match name.as_str() {
+        "baseUrl" => draft.base_url = value,
+        "contentType" => draft.request_content_type = value,
+        "proxyPath" => draft.proxy_path = value == "true",
+        "proxyQueryParams" => draft.proxy_query_params = value == "true",
+        "proxyMethod" => draft.proxy_method = value == "true",
+        "proxyBody" => draft.proxy_body = value == "true",
+        "method" => draft.method = value,
+        "path" => draft.path = value,
+        "queryParams" => draft.query_params = value,
+        "authKey" => draft.auth_key = value,
+        "authCode" => draft.auth_code = value,
+        "secretName" => draft.secret_name = value,
+        "oauth2:tokenUrl" => draft.oauth2_token_url = value,
+        "oauth2:clientId" => draft.oauth2_client_id = value,
+        "oauth2:clientSecretKey" => draft.oauth2_client_secret_key = value,
+        "oauth2:scope" => draft.oauth2_scope = value,
+        "bucketName" => draft.bucket_name = value,
+        "region" => draft.region = value,
+        "objectName" => draft.object_name = value,
+        "account" => draft.account_name = value,
+        "container" => draft.container_name = value,
+        "blobName" => draft.blob_name = value,
+        "keyName" => draft.key_name = value,
+        _ => {}
+      }
+    }
+    draft.headers.sort();
+
+    // The default header is implied, so only keep one that differs.
+    if draft.auth_key == "Authorization" {
+      draft.auth_key.clear();
+    }
+
+    for (name, value) in asset.properties().iter() {
+      match compact_property_name(name) {
+        "name" => draft.name = plain_value(&value.0),
+        "contenttype" => draft.content_type = plain_value(&value.0),
+        name if FORM_ASSET_PROPERTIES.contains(&name) => {}
+        name => draft
+          .properties
+          .push(AssetPropertyDraft::from_value(name, &value.0, false)),
+      }
+    }
+
+    for (name, value) in asset.private_properties().iter() {
+      draft.properties.push(AssetPropertyDraft::from_value(
+        compact_property_name(name),
+        &value.0,
+        true,
+      ));
+    }
+    draft
+      .properties
+      .sort_by(|left, right| (left.private, &left.name).cmp(&(right.private, &right.name)));
+
+    draft
+  }
+}
+
+/// Lets typed property values through the client's string-centric property builders.
+struct JsonValue(Value);
+
+impl ToValue for JsonValue {
+  fn into_value(self) -> Value {
+    self.0
+  }
+}
+
+fn deserialize_headers<'de, D>(deserializer: D) -> Result<Vec<(String, String)>, D::Error>
+where
+  D: Deserializer<'de>,
+{
+  let mut headers = HashMap::<String, String>::deserialize(deserializer)?
+    .into_iter()
+    .collect::<Vec<_>>();
+  headers.sort();
+
+  Ok(headers)
+}
+
+fn required(value: &str, property: &str) -> Result<(), String> {
+  match value.trim() {
+    "" => Err(format!("missing {property}")),
+    _ => Ok(()),
+  }
+}
+
+/// Skips the blank rows an editor leaves behind.
+fn filled_in(entries: &[(String, String)]) -> impl Iterator<Item = &(String, String)> {
+  entries
+    .iter()
+    .filter(|(name, value)| !(name.trim().is_empty() && value.trim().is_empty()))
+}
+
+fn with_optional_properties<const N: usize>(
+  mut data_address_builder: DataAddressBuilder,
+  properties: [(&str, &String); N],
+) -> DataAddressBuilder {
+  for (property, value) in properties {
+    if !value.trim().is_empty() {
+      data_address_builder = data_address_builder.property(property, value.trim());
+    }
+  }
+
+  data_address_builder
+}
+
+fn bool_to_string(value: bool) -> &'static str {
+  if value { "true" } else { "false" }
+}
+
+fn normalize_column(column: &str) -> String {
+  column
+    .chars()
+    .filter(|character| character.is_ascii_alphanumeric())
+    .collect::<String>()
+    .to_ascii_lowercase()
+}
+
+fn parse_bool(field: &str) -> Result<bool, String> {
+  match field.to_ascii_lowercase().as_str() {
+    "" | "false" | "0" | "no" => Ok(false),
+    "true" | "1" | "yes" => Ok(true),
+    other => Err(format!("invalid boolean '{other}'")),
+  }
+}
+
+/// Splits CSV into records, a quote left open swallowing the rest of the input into a failed one.
+fn parse_csv_records(input: &str) -> Vec<Result<Vec<String>, String>> {
+  let mut records = vec![];
+  let mut record = vec![];
+  let mut field = String::new();
+  let mut in_quotes = false;
+  let mut characters = input.chars().peekable();
+
+  while let Some(character) = characters.next() {
+    match character {
+      '"' if in_quotes && characters.peek() == Some(&'"') => {
+        characters.next();
+        field.push('"');
+      }
+      '"' => in_quotes = !in_quotes,
+      ',' if !in_quotes => record.push(std::mem::take(&mut field)),
+      '\r' if !in_quotes => {}
+      '\n' if !in_quotes => {
+        record.push(std::mem::take(&mut field));
+        records.push(Ok(std::mem::take(&mut record)));
+      }
+      _ => field.push(character),
+    }
+  }
+
+  if in_quotes {
+    records.push(Err("unterminated quoted field".to_string()));
+  } else if !field.is_empty() || !record.is_empty() {
+    record.push(field);
+    records.push(Ok(record));
+  }
+
+  records
+}
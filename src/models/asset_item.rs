@@ -1,34 +1,77 @@
+use super::{MASKED_VALUE, expand_property_name, is_secret_property};
+use crate::services::{compact_property_name, plain_value};
 use edc_connector_client::types::asset::Asset;
+use edc_connector_client::types::data_address::DataAddress;
 
 #[derive(Clone, Debug, PartialEq)]
 pub struct AssetItem {
   pub id: String,
   pub name: String,
-  pub base_url: String,
-  pub proxy_path: bool,
-  pub proxy_query_params: bool,
-  pub proxy_method: bool,
-  pub proxy_body: bool,
+  pub data_address_type: String,
+  /// Where the data lives, e.g. the base URL or the bucket and object.
+  pub location: String,
+  /// Every data address property, secrets masked.
+  pub data_address_properties: Vec<(String, String)>,
+  /// The public asset properties, as text.
+  pub properties: Vec<(String, String)>,
+}
+
+impl AssetItem {
+  /// Looks up a public property by its full, compacted or `prefix:name` form.
+  pub fn property(&self, name: &str) -> Option<&str> {
+    let expanded_name = expand_property_name(name, &[]);
+
+    self
+      .properties
+      .iter()
+      .find(|(property, _)| {
+        property == name.trim()
+          || property == &expanded_name
+          || compact_property_name(property) == compact_property_name(&expanded_name)
+      })
+      .map(|(_, value)| value.as_str())
+  }
 }
 
 impl From<Asset> for AssetItem {
   fn from(asset: Asset) -> Self {
     let id = asset.id().to_string();
     let name = get_property(&asset, "name");
-    let base_url = get_property(&asset, "baseUrl");
-    let proxy_path = get_boolean_property(&asset, "proxyPath");
-    let proxy_query_params = get_boolean_property(&asset, "proxyQueryParams");
-    let proxy_method = get_boolean_property(&asset, "proxyMethod");
-    let proxy_body = get_boolean_property(&asset, "proxyBody");
+    let data_address_properties = data_address_properties(asset.data_address());
+    let properties = asset
+      .properties()
+      .iter()
+      .map(|(name, value)| (name.clone(), plain_value(&value.0)))
+      .collect();
+
+    let property = |name: &str| {
+      data_address_properties
+        .iter()
+        .find(|(property, _)| property == name)
+        .map(|(_, value)| value.as_str())
+        .unwrap_or_default()
+    };
+
+    let data_address_type = property("type").to_string();
+    let location = match data_address_type.as_str() {
+      "HttpData" => property("baseUrl").to_string(),
+      "AmazonS3" => format!("s3://{}/{}", property("bucketName"), property("objectName")),
+      "AzureStorage" => format!(
+        "{}/{}/{}",
+        property("account"),
+        property("container"),
+        property("blobName")
+      ),
+      _ => String::new(),
+    };
 
     AssetItem {
       id,
       name,
-      base_url,
-      proxy_path,
-      proxy_query_params,
-      proxy_method,
-      proxy_body,
+      data_address_type,
+      location: location.trim_end_matches('/').to_string(),
+      data_address_properties,
+      properties,
     }
   }
 }
@@ -41,11 +84,26 @@ fn get_property(asset: &Asset, name: &str) -> String {
     .unwrap_or_default()
 }
 
-fn get_boolean_property(asset: &Asset, name: &str) -> bool {
-  asset
-    .properties()
-    .get::<String>(name)
-    .unwrap_or_default()
-    .unwrap_or_default()
-    == "true"
+fn data_address_properties(data_address: &DataAddress) -> Vec<(String, String)> {
+  let data_address = serde_json::to_value(data_address).unwrap_or_default();
+
+  let mut properties = data_address
+    .as_object()
+    .into_iter()
+    .flatten()
+    .filter(|(name, _)| !name.starts_with('@'))
+    .map(|(name, value)| {
+      let name = compact_property_name(name);
+      let value = if is_secret_property(name) {
+        MASKED_VALUE.to_string()
+      } else {
+        plain_value(value)
+      };
+
+      (name.to_string(), value)
+    })
+    .collect::<Vec<_>>();
+  properties.sort();
+
+  properties
 }
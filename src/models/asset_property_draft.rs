@@ -0,0 +1,135 @@
+use serde_json::Value;
+
+/// Prefixes that expand without being declared, `edc` being the default vocabulary anyway.
+pub const WELL_KNOWN_NAMESPACES: [(&str, &str); 6] = [
+  ("edc", "https://w3id.org/edc/v0.0.1/ns/"),
+  ("dct", "http://purl.org/dc/terms/"),
+  ("dcat", "http://www.w3.org/ns/dcat#"),
+  ("odrl", "http://www.w3.org/ns/odrl/2/"),
+  ("foaf", "http://xmlns.com/foaf/0.1/"),
+  ("dspace", "https://w3id.org/dspace/v0.8/"),
+];
+
+/// Expands a `prefix:name` property name, trying the declared namespaces before the well-known
+/// ones. Plain names, full IRIs and unknown prefixes are kept as they are.
+pub fn expand_property_name(name: &str, namespaces: &[(String, String)]) -> String {
+  let name = name.trim();
+
+  let Some((prefix, local_name)) = name.split_once(':') else {
+    return name.to_string();
+  };
+
+  if local_name.starts_with("//") {
+    return name.to_string();
+  }
+
+  namespaces
+    .iter()
+    .map(|(prefix, namespace)| (prefix.trim(), namespace.trim()))
+    .chain(WELL_KNOWN_NAMESPACES)
+    .find(|(namespace_prefix, _)| *namespace_prefix == prefix)
+    .map(|(_, namespace)| format!("{namespace}{local_name}"))
+    .unwrap_or_else(|| name.to_string())
+}
+
+/// Shortens a full IRI to `prefix:name` where a well-known namespace allows it.
+pub fn prefix_property_name(name: &str) -> String {
+  WELL_KNOWN_NAMESPACES
+    .iter()
+    .find_map(|(prefix, namespace)| {
+      name
+        .strip_prefix(namespace)
+        .map(|local_name| format!("{prefix}:{local_name}"))
+    })
+    .unwrap_or_else(|| name.to_string())
+}
+
+#[derive(Copy, Clone, Debug, Default, PartialEq, Eq, strum::Display)]
+pub enum AssetPropertyValueKind {
+  #[default]
+  #[strum(to_string = "Text")]
+  String,
+  Number,
+  Boolean,
+  #[strum(to_string = "JSON")]
+  Json,
+  List,
+}
+
+impl AssetPropertyValueKind {
+  pub const ALL: [AssetPropertyValueKind; 5] = [
+    AssetPropertyValueKind::String,
+    AssetPropertyValueKind::Number,
+    AssetPropertyValueKind::Boolean,
+    AssetPropertyValueKind::Json,
+    AssetPropertyValueKind::List,
+  ];
+}
+
+#[derive(Clone, Debug, Default, PartialEq)]
+pub struct AssetPropertyDraft {
+  pub name: String,
+  pub kind: AssetPropertyValueKind,
+  pub value: String,
+  /// Private properties stay with the provider and never show up in the catalog.
+  pub private: bool,
+}
+
+impl AssetPropertyDraft {
+  /// Recreates the editor row of an existing property value.
+  pub fn from_value(name: &str, value: &Value, private: bool) -> Self {
+    let (kind, value) = match value {
+      Value::String(string) => (AssetPropertyValueKind::String, string.clone()),
+      Value::Number(number) => (AssetPropertyValueKind::Number, number.to_string()),
+      Value::Bool(bool) => (AssetPropertyValueKind::Boolean, bool.to_string()),
+      Value::Array(_) => (AssetPropertyValueKind::List, value.to_string()),
+      Value::Object(_) | Value::Null => (AssetPropertyValueKind::Json, value.to_string()),
+    };
+
+    AssetPropertyDraft {
+      name: prefix_property_name(name),
+      kind,
+      value,
+      private,
+    }
+  }
+
+  pub fn is_blank(&self) -> bool {
+    self.name.trim().is_empty() && self.value.trim().is_empty()
+  }
+
+  pub fn to_value(&self) -> Result<Value, String> {
+    let value = self.value.trim();
+    let invalid = |expected: &str| format!("'{}' is not {expected}", self.name.trim());
+
+    match self.kind {
+      AssetPropertyValueKind::String => Ok(Value::String(self.value.clone())),
+      AssetPropertyValueKind::Number => serde_json::from_str::<serde_json::Number>(value)
+        .map(Value::Number)
+        .map_err(|_| invalid("a number")),
+      AssetPropertyValueKind::Boolean => match value.to_ascii_lowercase().as_str() {
+        "true" => Ok(Value::Bool(true)),
+        "false" => Ok(Value::Bool(false)),
+        _ => Err(invalid("true or false")),
+      },
+      AssetPropertyValueKind::Json => match serde_json::from_str::<Value>(value) {
+        Ok(Value::Object(object)) => Ok(Value::Object(object)),
+        // Existing properties may hold null, which duplicates have to keep.
+        Ok(Value::Null) => Ok(Value::Null),
+        _ => Err(invalid("a JSON object")),
+      },
+      // A JSON array is taken as is, anything else is a comma separated list of strings.
+      AssetPropertyValueKind::List => match serde_json::from_str::<Value>(value) {
+        Ok(Value::Array(items)) => Ok(Value::Array(items)),
+        _ => Ok(Value::Array(
+          value
+            .split(',')
+            .map(str::trim)
+            .filter(|item| !item.is_empty())
+            .map(|item| Value::String(item.to_string()))
+            .collect(),
+        )),
+      },
+    }
+  }
+}
@@ -0,0 +1,44 @@
+use std::time::Duration;
+
+#[derive(Copy, Clone, Debug, PartialEq, Eq)]
+pub struct RefreshInterval(pub u64);
+
+impl RefreshInterval {
+  pub const ALL: [RefreshInterval; 4] = [
+    RefreshInterval(5),
+    RefreshInterval(10),
+    RefreshInterval(30),
+    RefreshInterval(60),
+  ];
+
+  pub fn duration(self) -> Duration {
+    Duration::from_secs(self.0)
+  }
+}
+
+impl std::fmt::Display for RefreshInterval {
+  fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+    write!(f, "Every {}s", self.0)
+  }
+}
+
+#[derive(Copy, Clone, Debug, PartialEq, Eq)]
+pub struct AutoRefresh {
+  pub enabled: bool,
+  pub interval: RefreshInterval,
+}
+
+impl Default for AutoRefresh {
+  fn default() -> Self {
+    Self {
+      enabled: false,
+      interval: RefreshInterval(10),
+    }
+  }
+}
+
+impl AutoRefresh {
+  pub fn active_interval(&self) -> Option<Duration> {
+    self.enabled.then(|| self.interval.duration())
+  }
+}
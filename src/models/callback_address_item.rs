@@ -0,0 +1,55 @@
+use edc_connector_client::types::callback_address::CallbackAddress;
+use serde_json::Value;
+
+#[derive(Clone, Debug, PartialEq)]
+pub struct CallbackAddressItem {
+  pub uri: String,
+  pub events: Vec<String>,
+  pub transactional: bool,
+}
+
+impl From<&CallbackAddress> for CallbackAddressItem {
+  fn from(callback_address: &CallbackAddress) -> Self {
+    // The client keeps these fields private, so read them back from the serialized form.
+    let value = serde_json::to_value(callback_address).unwrap_or_default();
+
+    let uri = value
+      .get("uri")
+      .and_then(Value::as_str)
+      .unwrap_or_default()
+      .to_string();
+    let events = value
+      .get("events")
+      .and_then(Value::as_array)
+      .map(|events| {
+        events
+          .iter()
+          .filter_map(Value::as_str)
+          .map(str::to_string)
+          .collect()
+      })
+      .unwrap_or_default();
+    let transactional = value
+      .get("transactional")
+      .and_then(Value::as_bool)
+      .unwrap_or_default();
+
+    Self {
+      uri,
+      events,
+      transactional,
+    }
+  }
+}
+
+impl std::fmt::Display for CallbackAddressItem {
+  fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+    write!(f, "{} ({})", self.uri, self.events.join(", "))?;
+
+    if self.transactional {
+      write!(f, ", transactional")?;
+    }
+
+    Ok(())
+  }
+}
@@ -0,0 +1,50 @@
+use crate::models::PolicyDefinitionItem;
+use edc_connector_client::types::contract_definition::ContractDefinition;
+use serde_json::Value;
+
+/// Form state of a contract definition, for forms that don't start out empty.
+#[derive(Clone, Debug, Default, PartialEq)]
+pub struct ContractDefinitionDraft {
+  pub access_policy_definition_item: Option<PolicyDefinitionItem>,
+  pub contract_policy_definition_item: Option<PolicyDefinitionItem>,
+  /// Left operand, operator and right operand of the asset selector.
+  pub asset_selector: (String, String, String),
+}
+
+impl ContractDefinitionDraft {
+  /// Takes the policies as loaded items, since the contract definition only references them. Fails
+  /// for asset selectors the form can't show, as a duplicate would select other assets then.
+  pub fn new(
+    contract_definition: &ContractDefinition,
+    access_policy_definition_item: Option<PolicyDefinitionItem>,
+    contract_policy_definition_item: Option<PolicyDefinitionItem>,
+  ) -> Result<Self, String> {
+    let asset_selector = match contract_definition.assets_selector() {
+      [] => Default::default(),
+      [criterion] => match &criterion.operand_right().0 {
+        Value::String(operand_right) => (
+          criterion.operand_left().to_string(),
+          criterion.operator().to_string(),
+          operand_right.clone(),
+        ),
+        _ => {
+          return Err(format!(
+            "The asset selector compares '{}' with a value other than text, which the form can't edit",
+            criterion.operand_left()
+          ));
+        }
+      },
+      _ => {
+        return Err(
+          "The asset selector has several criteria, but the form edits a single one".to_string(),
+        );
+      }
+    };
+
+    Ok(ContractDefinitionDraft {
+      access_policy_definition_item,
+      contract_policy_definition_item,
+      asset_selector,
+    })
+  }
+}
@@ -0,0 +1,34 @@
+use super::{CallbackAddressItem, ContractNegotiationItem};
+use crate::services::raw_string_property;
+use edc_connector_client::types::contract_negotiation::ContractNegotiation;
+use serde_json::Value;
+
+#[derive(Clone, Debug, PartialEq)]
+pub struct ContractNegotiationDetails {
+  pub item: ContractNegotiationItem,
+  pub created_at: String,
+  pub callback_addresses: Vec<CallbackAddressItem>,
+  pub error_detail: Option<String>,
+}
+
+impl ContractNegotiationDetails {
+  /// `raw` is the untyped management API response, which still carries the error detail.
+  pub fn new(contract_negotiation: ContractNegotiation, raw: &Value) -> Self {
+    let created_at = chrono::DateTime::from_timestamp_millis(contract_negotiation.created_at())
+      .unwrap_or_default()
+      .to_string();
+    let callback_addresses = contract_negotiation
+      .callback_addresses()
+      .iter()
+      .map(CallbackAddressItem::from)
+      .collect();
+    let error_detail = raw_string_property(raw, "errorDetail");
+
+    Self {
+      item: ContractNegotiationItem::from(contract_negotiation),
+      created_at,
+      callback_addresses,
+      error_detail,
+    }
+  }
+}
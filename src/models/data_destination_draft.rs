@@ -0,0 +1,135 @@
+use edc_connector_client::types::data_address::{DataAddress, DataAddressBuilder};
+
+#[derive(Copy, Clone, Debug, Default, PartialEq, Eq, strum::Display)]
+pub enum DataDestinationKind {
+  #[default]
+  #[strum(to_string = "None")]
+  None,
+  #[strum(to_string = "HTTP")]
+  HttpData,
+  #[strum(to_string = "Amazon S3")]
+  AmazonS3,
+  #[strum(to_string = "Azure Storage")]
+  AzureStorage,
+  #[strum(to_string = "Custom")]
+  Custom,
+}
+
+impl DataDestinationKind {
+  pub const ALL: [DataDestinationKind; 5] = [
+    DataDestinationKind::None,
+    DataDestinationKind::HttpData,
+    DataDestinationKind::AmazonS3,
+    DataDestinationKind::AzureStorage,
+    DataDestinationKind::Custom,
+  ];
+
+  /// Guesses the destination a push transfer type writes to, e.g. `AmazonS3-PUSH`.
+  pub fn for_transfer_type(transfer_type: &str) -> Option<DataDestinationKind> {
+    let (destination_type, flow) = transfer_type.rsplit_once('-')?;
+
+    if !flow.eq_ignore_ascii_case("push") {
+      return None;
+    }
+
+    Some(match destination_type.to_ascii_lowercase().as_str() {
+      "httpdata" => DataDestinationKind::HttpData,
+      "amazons3" => DataDestinationKind::AmazonS3,
+      "azurestorage" => DataDestinationKind::AzureStorage,
+      _ => DataDestinationKind::Custom,
+    })
+  }
+}
+
+/// Form state for a transfer's data destination, keeping every type's fields so switching back
+/// and forth doesn't lose input.
+#[derive(Clone, Debug, Default, PartialEq)]
+pub struct DataDestinationDraft {
+  pub kind: DataDestinationKind,
+  pub base_url: String,
+  pub method: String,
+  pub headers: Vec<(String, String)>,
+  pub bucket_name: String,
+  pub region: String,
+  pub object_name: String,
+  pub account_name: String,
+  pub container_name: String,
+  pub blob_name: String,
+  pub custom_type: String,
+  pub properties: Vec<(String, String)>,
+}
+
+impl DataDestinationDraft {
+  pub fn to_data_address(&self) -> Result<Option<DataAddress>, String> {
+    let builder = match self.kind {
+      DataDestinationKind::None => return Ok(None),
+      DataDestinationKind::HttpData => {
+        let builder = DataAddress::builder()
+          .kind("HttpData")
+          .property("baseUrl", required(&self.base_url, "base URL")?);
+        let builder = optional(builder, "method", &self.method);
+
+        with_properties(builder, "header:", &self.headers)?
+      }
+      DataDestinationKind::AmazonS3 => {
+        let builder = DataAddress::builder()
+          .kind("AmazonS3")
+          .property("bucketName", required(&self.bucket_name, "bucket name")?)
+          .property("region", required(&self.region, "region")?);
+
+        optional(builder, "objectName", &self.object_name)
+      }
+      DataDestinationKind::AzureStorage => {
+        let builder = DataAddress::builder()
+          .kind("AzureStorage")
+          .property("account", required(&self.account_name, "account name")?)
+          .property("container", required(&self.container_name, "container")?);
+
+        optional(builder, "blobName", &self.blob_name)
+      }
+      DataDestinationKind::Custom => {
+        let builder = DataAddress::builder().kind(required(&self.custom_type, "type")?);
+
+        with_properties(builder, "", &self.properties)?
+      }
+    };
+
+    builder.build().map(Some).map_err(|error| error.to_string())
+  }
+}
+
+fn required<'a>(value: &'a str, label: &str) -> Result<&'a str, String> {
+  match value.trim() {
+    "" => Err(format!("The data destination is missing its {label}")),
+    value => Ok(value),
+  }
+}
+
+fn optional(builder: DataAddressBuilder, property: &str, value: &str) -> DataAddressBuilder {
+  match value.trim() {
+    "" => builder,
+    value => builder.property(property, value),
+  }
+}
+
+fn with_properties(
+  mut builder: DataAddressBuilder,
+  prefix: &str,
+  properties: &[(String, String)],
+) -> Result<DataAddressBuilder, String> {
+  for (name, value) in properties {
+    let name = name.trim();
+
+    if name.is_empty() {
+      if value.trim().is_empty() {
+        continue;
+      }
+
+      return Err("Every data destination property needs a name".to_string());
+    }
+
+    builder = builder.property(&format!("{prefix}{name}"), value.clone());
+  }
+
+  Ok(builder)
+}
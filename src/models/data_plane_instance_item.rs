@@ -0,0 +1,32 @@
+use super::DataPlaneInstanceState;
+use edc_connector_client::types::dataplane::DataPlaneInstance;
+
+#[derive(Clone, Debug, PartialEq)]
+pub struct DataPlaneInstanceItem {
+  pub id: String,
+  pub url: String,
+  pub state: DataPlaneInstanceState,
+  pub allowed_source_types: Vec<String>,
+  pub allowed_destination_types: Vec<String>,
+  pub allowed_transfer_types: Vec<String>,
+}
+
+impl From<DataPlaneInstance> for DataPlaneInstanceItem {
+  fn from(data_plane_instance: DataPlaneInstance) -> Self {
+    let id = data_plane_instance.id().to_string();
+    let url = data_plane_instance.url().to_string();
+    let state = DataPlaneInstanceState::from(data_plane_instance.state());
+    let allowed_source_types = data_plane_instance.allowed_source_types().clone();
+    let allowed_destination_types = data_plane_instance.allowed_dest_types().clone();
+    let allowed_transfer_types = data_plane_instance.allowed_transfer_types().clone();
+
+    Self {
+      id,
+      url,
+      state,
+      allowed_source_types,
+      allowed_destination_types,
+      allowed_transfer_types,
+    }
+  }
+}
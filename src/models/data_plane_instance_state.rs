@@ -0,0 +1,23 @@
+use edc_connector_client::types::dataplane::DataPlaneInstanceState as EdcDataPlaneInstanceState;
+
+#[derive(Debug, Clone, PartialEq, strum::Display)]
+pub enum DataPlaneInstanceState {
+  Available,
+  Registered,
+  Unavailable,
+  Unregistered,
+  #[strum(to_string = "{0}")]
+  Other(String),
+}
+
+impl From<&EdcDataPlaneInstanceState> for DataPlaneInstanceState {
+  fn from(state: &EdcDataPlaneInstanceState) -> Self {
+    match state {
+      EdcDataPlaneInstanceState::Available => DataPlaneInstanceState::Available,
+      EdcDataPlaneInstanceState::Registered => DataPlaneInstanceState::Registered,
+      EdcDataPlaneInstanceState::Unavailable => DataPlaneInstanceState::Unavailable,
+      EdcDataPlaneInstanceState::Unregistered => DataPlaneInstanceState::Unregistered,
+      EdcDataPlaneInstanceState::Other(other) => DataPlaneInstanceState::Other(other.to_string()),
+    }
+  }
+}
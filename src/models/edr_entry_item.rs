@@ -0,0 +1,29 @@
+use edc_connector_client::types::edr::EndpointDataReferenceEntry;
+
+#[derive(Clone, Debug, PartialEq)]
+pub struct EdrEntryItem {
+  pub transfer_process_id: String,
+  pub asset_id: String,
+  pub agreement_id: String,
+  pub provider_id: String,
+  pub created_at: String,
+  /// Unix seconds, only known once the cached data address has been read.
+  pub expires_at: Option<i64>,
+}
+
+impl From<EndpointDataReferenceEntry> for EdrEntryItem {
+  fn from(entry: EndpointDataReferenceEntry) -> Self {
+    let created_at = chrono::DateTime::from_timestamp_millis(entry.created_at())
+      .unwrap_or_default()
+      .to_string();
+
+    Self {
+      transfer_process_id: entry.transfer_process_id().to_string(),
+      asset_id: entry.asset_id().to_string(),
+      agreement_id: entry.agreement_id().to_string(),
+      provider_id: entry.provider_id().to_string(),
+      created_at,
+      expires_at: None,
+    }
+  }
+}
@@ -0,0 +1,83 @@
+use super::{MASKED_VALUE, is_secret_property};
+use crate::services::{compact_property_name, plain_value};
+use base64::Engine;
+use base64::engine::general_purpose::URL_SAFE_NO_PAD;
+use edc_connector_client::types::data_address::DataAddress;
+use serde_json::Value;
+
+/// The data address of an EDR, with the well-known fields pulled out of the property bag.
+#[derive(Clone, Debug, PartialEq)]
+pub struct EndpointDataReference {
+  pub endpoint: Option<String>,
+  pub endpoint_type: Option<String>,
+  pub auth_type: Option<String>,
+  pub authorization: Option<String>,
+  /// Unix seconds, read from the `exp` claim when the token is a JWT.
+  pub expires_at: Option<i64>,
+  pub properties: Vec<(String, String)>,
+}
+
+impl EndpointDataReference {
+  pub fn is_expired(&self, now: i64) -> bool {
+    self.expires_at.is_some_and(|expires_at| expires_at <= now)
+  }
+}
+
+impl From<&DataAddress> for EndpointDataReference {
+  fn from(data_address: &DataAddress) -> Self {
+    EndpointDataReference::from(&serde_json::to_value(data_address).unwrap_or_default())
+  }
+}
+
+/// Accepts the data address as returned by the management API, JSON-LD keywords included.
+impl From<&Value> for EndpointDataReference {
+  fn from(data_address: &Value) -> Self {
+    let mut endpoint = None;
+    let mut endpoint_type = None;
+    let mut auth_type = None;
+    let mut authorization = None;
+    let mut properties = vec![];
+
+    let fields = data_address.as_object().cloned().unwrap_or_default();
+
+    for (name, value) in &fields {
+      let name = compact_property_name(name);
+      let value = plain_value(value);
+
+      match name {
+        "endpoint" => endpoint = Some(value),
+        "endpointType" => endpoint_type = Some(value),
+        "authType" => auth_type = Some(value),
+        "authorization" => authorization = Some(value),
+        name if name.starts_with('@') => {}
+        name if is_secret_property(name) => {
+          properties.push((name.to_string(), MASKED_VALUE.to_string()))
+        }
+        name => properties.push((name.to_string(), value)),
+      }
+    }
+
+    properties.sort();
+    let expires_at = authorization.as_deref().and_then(jwt_expiry);
+
+    Self {
+      endpoint,
+      endpoint_type,
+      auth_type,
+      authorization,
+      expires_at,
+      properties,
+    }
+  }
+}
+
+fn jwt_expiry(token: &str) -> Option<i64> {
+  let token = token.strip_prefix("Bearer ").unwrap_or(token);
+  let payload = token.split('.').nth(1)?;
+  let claims = URL_SAFE_NO_PAD.decode(payload.trim_end_matches('=')).ok()?;
+
+  serde_json::from_slice::<Value>(&claims)
+    .ok()?
+    .get("exp")?
+    .as_i64()
+}
@@ -0,0 +1,36 @@
+use crate::routes::AppRoute;
+
+#[derive(Copy, Clone, Debug, PartialEq, Eq, PartialOrd, Ord, strum::Display)]
+pub enum EntityKind {
+  Asset,
+  #[strum(to_string = "Policy Definition")]
+  PolicyDefinition,
+  #[strum(to_string = "Contract Definition")]
+  ContractDefinition,
+  #[strum(to_string = "Contract Negotiation")]
+  ContractNegotiation,
+  #[strum(to_string = "Contract Agreement")]
+  ContractAgreement,
+  #[strum(to_string = "Transfer Process")]
+  TransferProcess,
+  #[strum(to_string = "EDR")]
+  EndpointDataReference,
+  Secret,
+}
+
+impl EntityKind {
+  pub fn route(&self, id: String) -> AppRoute {
+    match self {
+      EntityKind::Asset => AppRoute::Asset { id },
+      EntityKind::PolicyDefinition => AppRoute::PolicyDefinition { id },
+      EntityKind::ContractDefinition => AppRoute::ContractDefinition { id },
+      EntityKind::ContractNegotiation => AppRoute::ContractNegotiation { id },
+      EntityKind::ContractAgreement => AppRoute::ContractAgreement { id },
+      EntityKind::TransferProcess => AppRoute::TransferProcess { id },
+      // EDRs are cached per transfer process and shown on its details page.
+      EntityKind::EndpointDataReference => AppRoute::TransferProcess { id },
+      // Secret values are write-only, so there is no details page to link to.
+      EntityKind::Secret => AppRoute::Secrets,
+    }
+  }
+}
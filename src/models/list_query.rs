@@ -0,0 +1,236 @@
+use edc_connector_client::types::query::{Query, SortOrder};
+use std::str::FromStr;
+
+pub const DEFAULT_LIMIT: usize = 10;
+
+#[derive(Copy, Clone, Debug, PartialEq, Eq)]
+pub struct QueryProperty {
+  pub label: &'static str,
+  pub path: &'static str,
+}
+
+impl QueryProperty {
+  pub const fn new(label: &'static str, path: &'static str) -> Self {
+    Self { label, path }
+  }
+}
+
+impl std::fmt::Display for QueryProperty {
+  fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+    f.write_str(self.label)
+  }
+}
+
+#[derive(Copy, Clone, Debug, PartialEq, Eq, strum::Display, strum::EnumString)]
+pub enum FilterOperator {
+  #[strum(to_string = "=")]
+  Equal,
+  #[strum(to_string = "!=")]
+  NotEqual,
+  #[strum(to_string = "<")]
+  LessThan,
+  #[strum(to_string = "<=")]
+  LessThanOrEqual,
+  #[strum(to_string = ">")]
+  GreaterThan,
+  #[strum(to_string = ">=")]
+  GreaterThanOrEqual,
+  #[strum(to_string = "like")]
+  Like,
+  #[strum(to_string = "in")]
+  In,
+}
+
+impl FilterOperator {
+  pub const ALL: [FilterOperator; 8] = [
+    FilterOperator::Equal,
+    FilterOperator::NotEqual,
+    FilterOperator::LessThan,
+    FilterOperator::LessThanOrEqual,
+    FilterOperator::GreaterThan,
+    FilterOperator::GreaterThanOrEqual,
+    FilterOperator::Like,
+    FilterOperator::In,
+  ];
+}
+
+#[derive(Clone, Debug, PartialEq)]
+pub struct QueryFilter {
+  pub property: String,
+  pub operator: FilterOperator,
+  pub value: String,
+}
+
+#[derive(Clone, Debug, PartialEq)]
+pub struct QuerySort {
+  pub property: String,
+  pub ascending: bool,
+}
+
+#[derive(Clone, Debug, Default, PartialEq)]
+pub struct ListQuery {
+  pub filters: Vec<QueryFilter>,
+  pub sort: Option<QuerySort>,
+}
+
+impl ListQuery {
+  pub fn to_query(&self, offset: usize, limit: usize) -> Query {
+    let builder = self
+      .filters
+      .iter()
+      .fold(Query::builder(), |builder, filter| {
+        let operator = filter.operator.to_string();
+
+        match filter.operator {
+          FilterOperator::In => builder.filter(
+            &filter.property,
+            &operator,
+            filter
+              .value
+              .split(',')
+              .map(|value| value.trim().to_string())
+              .collect::<Vec<_>>(),
+          ),
+          _ => builder.filter(&filter.property, &operator, filter.value.clone()),
+        }
+      });
+
+    let builder = match &self.sort {
+      Some(sort) => builder.sort(
+        &sort.property,
+        if sort.ascending {
+          SortOrder::Asc
+        } else {
+          SortOrder::Desc
+        },
+      ),
+      None => builder,
+    };
+
+    builder.offset(offset as u32).limit(limit as u32).build()
+  }
+}
+
+/// The pagination, sort, filter and column state of a list page, mirrored in the URL query string.
+#[derive(Clone, Debug, PartialEq)]
+pub struct ListState {
+  pub offset: usize,
+  pub limit: usize,
+  pub query: ListQuery,
+  /// Property paths shown as extra columns, on lists supporting them.
+  pub columns: Vec<String>,
+}
+
+impl Default for ListState {
+  fn default() -> Self {
+    Self {
+      offset: 0,
+      limit: DEFAULT_LIMIT,
+      query: ListQuery::default(),
+      columns: vec![],
+    }
+  }
+}
+
+impl ListState {
+  pub fn from_search(search: &str) -> Self {
+    let mut list_state = Self::default();
+
+    for parameter in search.trim_start_matches('?').split('&') {
+      let Some((key, value)) = parameter.split_once('=') else {
+        continue;
+      };
+      let value = decode(value);
+
+      match key {
+        "offset" => list_state.offset = value.parse().unwrap_or_default(),
+        "limit" => {
+          list_state.limit = value
+            .parse()
+            .ok()
+            .filter(|limit| *limit > 0)
+            .unwrap_or(DEFAULT_LIMIT)
+        }
+        "sort" => {
+          let (property, ascending) = match value.strip_prefix('-') {
+            Some(property) => (property.to_string(), false),
+            None => (value, true),
+          };
+
+          list_state.query.sort = Some(QuerySort {
+            property,
+            ascending,
+          });
+        }
+        "filter" => {
+          let mut parts = value.splitn(3, ' ');
+
+          if let (Some(property), Some(operator), Some(value)) =
+            (parts.next(), parts.next(), parts.next())
+            && let Ok(operator) = FilterOperator::from_str(operator)
+          {
+            list_state.query.filters.push(QueryFilter {
+              property: property.to_string(),
+              operator,
+              value: value.to_string(),
+            });
+          }
+        }
+        "column" if !value.is_empty() => list_state.columns.push(value),
+        _ => {}
+      }
+    }
+
+    // Hand-edited URLs may point between pages, which pagination can't step back from.
+    list_state.offset -= list_state.offset % list_state.limit;
+
+    list_state
+  }
+
+  /// Renders the non-default parts of the state, without the leading `?`.
+  pub fn to_search(&self) -> String {
+    let mut parameters = vec![];
+
+    if self.offset != 0 {
+      parameters.push(format!("offset={}", self.offset));
+    }
+
+    if self.limit != DEFAULT_LIMIT {
+      parameters.push(format!("limit={}", self.limit));
+    }
+
+    if let Some(sort) = &self.query.sort {
+      let direction = if sort.ascending { "" } else { "-" };
+      parameters.push(format!(
+        "sort={}",
+        encode(&format!("{direction}{}", sort.property))
+      ));
+    }
+
+    for filter in &self.query.filters {
+      parameters.push(format!(
+        "filter={}",
+        encode(&format!(
+          "{} {} {}",
+          filter.property, filter.operator, filter.value
+        ))
+      ));
+    }
+
+    for column in &self.columns {
+      parameters.push(format!("column={}", encode(column)));
+    }
+
+    parameters.join("&")
+  }
+}
+
+fn encode(value: &str) -> String {
+  urlencoding::encode(value).into_owned()
+}
+
+fn decode(value: &str) -> String {
+  urlencoding::decode(&value.replace('+', " "))
+    .map(|value| value.into_owned())
+    .unwrap_or_default()
+}
@@ -0,0 +1,7 @@
+#[derive(Clone, Debug, PartialEq)]
+pub struct SecretItem {
+  pub id: String,
+  /// `None` when the vault couldn't be asked.
+  pub stored: Option<bool>,
+  pub referenced_by: Vec<String>,
+}
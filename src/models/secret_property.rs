@@ -0,0 +1,26 @@
+use crate::services::compact_property_name;
+
+pub const MASKED_VALUE: &str = "••••••••";
+
+const SECRET_PROPERTY_MARKERS: [&str; 7] = [
+  "secret",
+  "authcode",
+  "password",
+  "token",
+  "authorization",
+  "credential",
+  "apikey",
+];
+
+pub fn is_secret_property(name: &str) -> bool {
+  let name = compact_property_name(name)
+    .to_lowercase()
+    .replace(['-', '_'], "");
+
+  SECRET_PROPERTY_MARKERS
+    .iter()
+    .any(|marker| name.contains(marker))
+    // Names of vault entries are references, not secrets themselves.
+    && !name.ends_with("name")
+    && !name.ends_with("alias")
+}
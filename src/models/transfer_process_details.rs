@@ -0,0 +1,72 @@
+use super::{CallbackAddressItem, MASKED_VALUE, TransferProcessItem, is_secret_property};
+use crate::services::{compact_property_name, plain_value, raw_string_property};
+use edc_connector_client::types::transfer_process::TransferProcess;
+use serde_json::Value;
+
+#[derive(Clone, Debug, PartialEq)]
+pub struct TransferProcessDetails {
+  pub item: TransferProcessItem,
+  pub state_timestamp: String,
+  pub correlation_id: Option<String>,
+  pub data_destination: Vec<(String, String)>,
+  pub private_properties: Vec<(String, String)>,
+  pub callback_addresses: Vec<CallbackAddressItem>,
+  pub error_detail: Option<String>,
+}
+
+impl TransferProcessDetails {
+  /// `raw` is the untyped management API response, which still carries the error detail.
+  pub fn new(transfer_process: TransferProcess, raw: &Value) -> Self {
+    let state_timestamp =
+      chrono::DateTime::from_timestamp_millis(transfer_process.state_timestamp())
+        .unwrap_or_default()
+        .to_string();
+    let correlation_id = transfer_process.correlation_id().cloned();
+    let data_destination = transfer_process
+      .data_destination()
+      .and_then(|data_destination| serde_json::to_value(data_destination).ok())
+      .and_then(|data_destination| data_destination.as_object().cloned())
+      .map(|data_destination| {
+        data_destination
+          .iter()
+          .map(|(name, value)| {
+            let value = if is_secret_property(name) {
+              MASKED_VALUE.to_string()
+            } else {
+              plain_value(value)
+            };
+
+            (compact_property_name(name).to_string(), value)
+          })
+          .collect::<Vec<_>>()
+      })
+      .unwrap_or_default();
+    let mut private_properties = transfer_process
+      .private_properties()
+      .iter()
+      .map(|(name, value)| {
+        (
+          compact_property_name(name).to_string(),
+          plain_value(&value.0),
+        )
+      })
+      .collect::<Vec<_>>();
+    private_properties.sort();
+    let callback_addresses = transfer_process
+      .callback_addresses()
+      .iter()
+      .map(CallbackAddressItem::from)
+      .collect();
+    let error_detail = raw_string_property(raw, "errorDetail");
+
+    Self {
+      item: TransferProcessItem::from(transfer_process),
+      state_timestamp,
+      correlation_id,
+      data_destination,
+      private_properties,
+      callback_addresses,
+      error_detail,
+    }
+  }
+}
@@ -1,13 +1,39 @@
+mod asset_details_page;
 mod asset_page;
+mod backup_page;
+mod compare_page;
+mod contract_agreement_details_page;
 mod contract_agreement_page;
+mod contract_definition_details_page;
 mod contract_definition_page;
+mod contract_negotiation_details_page;
 mod contract_negotiation_page;
+mod dashboard_page;
+mod data_plane_page;
+mod edr_page;
+mod policy_definition_details_page;
 mod policy_page;
+mod search_page;
+mod secret_page;
+mod transfer_process_details_page;
 mod transfer_process_page;
 
+pub use asset_details_page::*;
 pub use asset_page::*;
+pub use backup_page::*;
+pub use compare_page::*;
+pub use contract_agreement_details_page::*;
 pub use contract_agreement_page::*;
+pub use contract_definition_details_page::*;
 pub use contract_definition_page::*;
+pub use contract_negotiation_details_page::*;
 pub use contract_negotiation_page::*;
+pub use dashboard_page::*;
+pub use data_plane_page::*;
+pub use edr_page::*;
+pub use policy_definition_details_page::*;
 pub use policy_page::*;
+pub use search_page::*;
+pub use secret_page::*;
+pub use transfer_process_details_page::*;
 pub use transfer_process_page::*;
@@ -0,0 +1,95 @@
+use crate::components::AssetSourceTest;
+use crate::contexts::use_edc_connector_context;
+use crate::models::AssetItem;
+use crate::services::SourceTestRequest;
+use patternfly_yew::prelude::*;
+use yew::prelude::*;
+use yew::suspense::use_future_with;
+
+#[derive(Clone, Debug, PartialEq, Properties)]
+pub struct AssetDetailsPageProps {
+  pub id: String,
+}
+
+#[component]
+pub fn AssetDetailsPage(props: &AssetDetailsPageProps) -> Html {
+  html!(
+    <Stack gutter=true>
+      <StackItem>
+        <Title level={Level::H3} size={Size::XXLarge}>{ format!("Asset {}", props.id) }</Title>
+      </StackItem>
+      <StackItem>
+        <Card>
+          <CardBody>
+            <Suspense>
+              <AssetDetailsPageInner id={props.id.clone()} />
+            </Suspense>
+          </CardBody>
+        </Card>
+      </StackItem>
+    </Stack>
+  )
+}
+
+#[component]
+fn AssetDetailsPageInner(props: &AssetDetailsPageProps) -> HtmlResult {
+  let edc_connector_context = use_edc_connector_context();
+
+  let asset_item = use_future_with(
+    (edc_connector_context, props.id.clone()),
+    |parameters| async move {
+      let (edc_connector_context, id) = (*parameters).clone();
+
+      match edc_connector_context.get_client() {
+        Some(client) => client
+          .assets()
+          .get(&id)
+          .await
+          .map(|asset| {
+            let source_test_request = SourceTestRequest::from(asset.data_address());
+
+            (AssetItem::from(asset), source_test_request)
+          })
+          .map_err(|error| error.to_string()),
+        None => Err("No connector configured".to_string()),
+      }
+    },
+  )?;
+
+  Ok(match (*asset_item).clone() {
+    Ok((asset_item, source_test_request)) => html!(
+      <Stack gutter=true>
+        <StackItem>
+          <DescriptionList>
+            <DescriptionGroup term="ID">{ asset_item.id }</DescriptionGroup>
+            <DescriptionGroup term="Name">{ asset_item.name }</DescriptionGroup>
+            <DescriptionGroup term="Data Address Type">
+              { asset_item.data_address_type.clone() }
+            </DescriptionGroup>
+            <DescriptionGroup term="Location">{ asset_item.location }</DescriptionGroup>
+            <DescriptionGroup term="Data Address">
+              <List>
+                { for asset_item.data_address_properties.iter().map(|(name, value)| html_nested!(
+                  <ListItem>{ format!("{name}: {value}") }</ListItem>
+                )) }
+              </List>
+            </DescriptionGroup>
+          </DescriptionList>
+        </StackItem>
+        if asset_item.data_address_type == "HttpData" {
+          <StackItem>
+            <Title level={Level::H4}>{ "Test Source" }</Title>
+          </StackItem>
+          <StackItem>
+            <AssetSourceTest request={source_test_request} />
+          </StackItem>
+        }
+      </Stack>
+    ),
+    Err(error) => html!(
+      <Alert inline=true r#type={AlertType::Danger} title="Unable to load asset">
+        { html!(error) }
+      </Alert>
+    ),
+  })
+}
@@ -1,7 +1,10 @@
-use crate::components::{CreateAsset, ListAssets};
-use crate::contexts::use_edc_connector_context;
-use crate::models::AssetItem;
-use edc_connector_client::types::query::Query;
+use crate::components::{CreateAsset, ImportAssets, ListAssets};
+use crate::contexts::{
+  ActivityOperation, record_activity, use_activity_log_context, use_edc_connector_context,
+};
+use crate::hooks::{use_list_state, use_total_entries};
+use crate::models::{AssetDraft, AssetItem, EntityKind, ListQuery, ListState};
+use crate::services::error_message;
 use patternfly_yew::prelude::*;
 use yew::platform::spawn_local;
 use yew::prelude::*;
@@ -11,37 +14,68 @@ use yew::suspense::use_future_with;
 pub fn AssetPage() -> Html {
   let refresh = use_state(|| 0usize);
   let backdropper = use_backdrop();
-  let offset = use_state(|| 0usize);
-  let limit = use_state(|| 10usize);
+  let list_state = use_list_state();
 
-  let onoffset = use_callback(
-    (refresh.clone(), offset.setter()),
-    |offset, (refresh, offset_setter)| {
-      offset_setter.set(offset);
-      refresh.set(**refresh + 1);
-    },
-  );
+  let onoffset = use_callback(list_state.clone(), |offset, list_state| {
+    list_state.set(ListState {
+      offset,
+      ..(**list_state).clone()
+    });
+  });
 
-  let onlimit = use_callback(
-    (refresh.clone(), limit.setter()),
-    |limit, (refresh, limit_setter)| {
-      limit_setter.set(limit);
-      refresh.set(**refresh + 1);
-    },
+  let onlimit = use_callback(list_state.clone(), |limit, list_state| {
+    list_state.set(ListState {
+      limit,
+      ..(**list_state).clone()
+    });
+  });
+
+  let onquery = use_callback(list_state.clone(), |query, list_state| {
+    list_state.set(ListState {
+      offset: 0,
+      query,
+      ..(**list_state).clone()
+    });
+  });
+
+  let onproperty_columns = use_callback(list_state.clone(), |columns, list_state| {
+    list_state.set(ListState {
+      columns,
+      ..(**list_state).clone()
+    });
+  });
+
+  let total_entries = use_total_entries(
+    (*refresh, list_state.query.clone()),
+    list_state.query.to_query(0, 0),
+    |client, query| async move { client.assets().query(query).await },
   );
 
   let edc_connector_context = use_edc_connector_context();
+  let activity_log_context = use_activity_log_context();
 
   let ondelete = use_callback(
-    (refresh.clone(), edc_connector_context),
-    |asset_id: String, (refresh, edc_connector_context)| {
+    (
+      refresh.clone(),
+      edc_connector_context.clone(),
+      activity_log_context,
+    ),
+    |asset_id: String, (refresh, edc_connector_context, activity_log_context)| {
       let refresh = refresh.clone();
       let edc_connector_context = edc_connector_context.clone();
+      let activity_log_context = activity_log_context.clone();
       let asset_id = asset_id.clone();
 
       spawn_local(async move {
         if let Some(client) = edc_connector_context.get_client() {
-          let _ = client.assets().delete(&asset_id).await;
+          let result = client.assets().delete(&asset_id).await;
+          record_activity(
+            &activity_log_context,
+            ActivityOperation::Delete,
+            EntityKind::Asset,
+            Some(asset_id),
+            &result,
+          );
         }
         refresh.set(*refresh + 1);
       });
@@ -59,12 +93,77 @@ pub fn AssetPage() -> Html {
     },
   );
 
-  let onclick = use_callback((backdropper, on_create), |_, (backdropper, on_create)| {
+  let duplicate_error = use_state(|| Option::<String>::None);
+
+  let onduplicate = use_callback(
+    (
+      backdropper.clone(),
+      edc_connector_context,
+      on_create.clone(),
+      duplicate_error.clone(),
+    ),
+    |asset_id: String, (backdropper, edc_connector_context, on_create, duplicate_error)| {
+      let backdropper = backdropper.clone();
+      let edc_connector_context = edc_connector_context.clone();
+      let on_create = on_create.clone();
+      let duplicate_error = duplicate_error.clone();
+
+      spawn_local(async move {
+        let Some(client) = edc_connector_context.get_client() else {
+          return;
+        };
+
+        let asset = match client.assets().get(&asset_id).await {
+          Ok(asset) => asset,
+          Err(error) => {
+            duplicate_error.set(Some(error_message(&error)));
+            return;
+          }
+        };
+        let initial = AssetDraft {
+          id: String::new(),
+          ..AssetDraft::from(&asset)
+        };
+
+        duplicate_error.set(None);
+        if let Some(backdropper) = backdropper {
+          backdropper.open(html!(
+            <Bullseye>
+              <Modal variant={ModalVariant::Medium} title="Duplicate an Asset">
+                <CreateAsset {on_create} {initial} />
+              </Modal>
+            </Bullseye>
+          ))
+        }
+      });
+    },
+  );
+
+  let onclick = use_callback(
+    (backdropper.clone(), on_create),
+    |_, (backdropper, on_create)| {
+      if let Some(backdropper) = backdropper {
+        backdropper.open(html!(
+          <Bullseye>
+            <Modal variant={ModalVariant::Medium} title="Create an Asset">
+              <CreateAsset {on_create} />
+            </Modal>
+          </Bullseye>
+        ))
+      }
+    },
+  );
+
+  let on_import = use_callback(refresh.clone(), |_, refresh| {
+    refresh.set(**refresh + 1);
+  });
+
+  let onclick_import = use_callback((backdropper, on_import), |_, (backdropper, on_import)| {
     if let Some(backdropper) = backdropper {
       backdropper.open(html!(
         <Bullseye>
-          <Modal variant={ModalVariant::Medium} title="Create an Asset">
-            <CreateAsset {on_create} />
+          <Modal variant={ModalVariant::Large} title="Import Assets">
+            <ImportAssets {on_import} />
           </Modal>
         </Bullseye>
       ))
@@ -78,21 +177,43 @@ pub fn AssetPage() -> Html {
           <SplitItem fill=true>
             <Title level={Level::H3} size={Size::XXLarge}>{ "List Assets" }</Title>
           </SplitItem>
+          <SplitItem>
+            <Button
+              icon={Icon::Upload}
+              onclick={onclick_import}
+              variant={ButtonVariant::Secondary}
+            >
+              { "Import" }
+            </Button>
+          </SplitItem>
           <SplitItem>
             <Button icon={Icon::Plus} {onclick} variant={ButtonVariant::Primary}>{ "Add" }</Button>
           </SplitItem>
         </Split>
       </StackItem>
+      if let Some(error) = (*duplicate_error).clone() {
+        <StackItem>
+          <Alert inline=true r#type={AlertType::Danger} title="Could not duplicate">
+            { html!(error) }
+          </Alert>
+        </StackItem>
+      }
       <StackItem>
         <Card>
           <CardBody>
             <Suspense>
               <AssetPageInner
-                offset={*offset}
-                limit={*limit}
+                offset={list_state.offset}
+                limit={list_state.limit}
                 {onoffset}
                 {onlimit}
+                list_query={list_state.query.clone()}
+                {onquery}
                 {ondelete}
+                {onduplicate}
+                property_columns={list_state.columns.clone()}
+                {onproperty_columns}
+                {total_entries}
                 force_refresh={*refresh}
               />
             </Suspense>
@@ -109,7 +230,13 @@ pub struct AssetPageInnerProps {
   pub limit: usize,
   pub onoffset: Callback<usize>,
   pub onlimit: Callback<usize>,
+  pub list_query: ListQuery,
+  pub onquery: Callback<ListQuery>,
   pub ondelete: Callback<String>,
+  pub onduplicate: Callback<String>,
+  pub property_columns: Vec<String>,
+  pub onproperty_columns: Callback<Vec<String>>,
+  pub total_entries: Option<usize>,
   pub force_refresh: usize,
 }
 
@@ -122,42 +249,58 @@ pub fn AssetPageInner(props: &AssetPageInnerProps) -> HtmlResult {
       edc_connector_context,
       props.limit,
       props.offset,
+      props.list_query.clone(),
       props.force_refresh,
     ),
     |parameters| async move {
-      let (edc_connector_context, limit, offset, _) = (*parameters).clone();
-
-      let query = Query::builder()
-        .limit(limit as u32)
-        .offset(offset as u32)
-        // .filter("https://w3id.org/edc/v0.0.1/ns/master-catalog-company-id", "=", "424F9F7A-BBC8-4BAD-B128-C3D0A693ABBA")
-        .build();
-
-      if let Some(client) = edc_connector_context.get_client() {
-        client
-          .assets()
-          .query(query)
-          .await
-          .unwrap_or_default()
-          .into_iter()
-          .map(AssetItem::from)
-          .collect::<Vec<_>>()
-      } else {
-        vec![]
-      }
+      let (edc_connector_context, limit, offset, list_query, _) = (*parameters).clone();
+
+      let query = list_query.to_query(offset, limit);
+
+      let Some(client) = edc_connector_context.get_client() else {
+        return Ok(vec![]);
+      };
+
+      client
+        .assets()
+        .query(query)
+        .await
+        .map(|entities| {
+          entities
+            .into_iter()
+            .map(AssetItem::from)
+            .collect::<Vec<_>>()
+        })
+        .map_err(|error| error_message(&error))
     },
   )?;
 
-  let asset_items = (*asset_items).clone();
+  let (asset_items, error) = match (*asset_items).clone() {
+    Ok(asset_items) => (asset_items, None),
+    Err(error) => (vec![], Some(error)),
+  };
 
   Ok(html!(
-    <ListAssets
-      asset_items={asset_items}
-      offset={props.offset}
-      limit={props.limit}
-      onoffset={props.onoffset.clone()}
-      onlimit={props.onlimit.clone()}
-      ondelete={props.ondelete.clone()}
-    />
+    <>
+      if let Some(error) = error {
+        <Alert inline=true r#type={AlertType::Danger} title="Unable to load assets">
+          { html!(error) }
+        </Alert>
+      }
+      <ListAssets
+        asset_items={asset_items}
+        offset={props.offset}
+        total_entries={props.total_entries}
+        limit={props.limit}
+        onoffset={props.onoffset.clone()}
+        onlimit={props.onlimit.clone()}
+        list_query={props.list_query.clone()}
+        onquery={props.onquery.clone()}
+        ondelete={props.ondelete.clone()}
+        onduplicate={props.onduplicate.clone()}
+        property_columns={props.property_columns.clone()}
+        onproperty_columns={props.onproperty_columns.clone()}
+      />
+    </>
   ))
 }
@@ -0,0 +1,30 @@
+use crate::components::{ExportConnector, RestoreConnector};
+use patternfly_yew::prelude::*;
+use yew::prelude::*;
+
+#[component]
+pub fn BackupPage() -> Html {
+  html!(
+    <Stack gutter=true>
+      <StackItem>
+        <Title level={Level::H3} size={Size::XXLarge}>{ "Backup & Restore" }</Title>
+      </StackItem>
+      <StackItem>
+        <Card>
+          <CardTitle>{ "Export" }</CardTitle>
+          <CardBody>
+            <ExportConnector />
+          </CardBody>
+        </Card>
+      </StackItem>
+      <StackItem>
+        <Card>
+          <CardTitle>{ "Restore" }</CardTitle>
+          <CardBody>
+            <RestoreConnector />
+          </CardBody>
+        </Card>
+      </StackItem>
+    </Stack>
+  )
+}
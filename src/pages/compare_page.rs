@@ -0,0 +1,339 @@
+use crate::components::ListConnectorDiff;
+use crate::contexts::{
+  EdcConnectorState, record_restore_entry, use_activity_log_context, use_edc_connector_context,
+};
+use crate::models::EntityKind;
+use crate::services::{
+  ConflictStrategy, ConnectorBackup, DiffEntry, DiffStatus, RestoreEntry, RestoreOutcome,
+  diff_connectors, restore, select_entries,
+};
+use edc_connector_client::EdcConnectorClient;
+use patternfly_yew::prelude::*;
+use std::collections::BTreeSet;
+use std::rc::Rc;
+use yew::platform::spawn_local;
+use yew::prelude::*;
+
+#[derive(Clone)]
+struct Comparison {
+  current: Rc<ConnectorBackup>,
+  other: Rc<ConnectorBackup>,
+  entries: Vec<DiffEntry>,
+}
+
+impl PartialEq for Comparison {
+  fn eq(&self, other: &Self) -> bool {
+    Rc::ptr_eq(&self.current, &other.current) && Rc::ptr_eq(&self.other, &other.other)
+  }
+}
+
+#[derive(Copy, Clone, PartialEq)]
+enum CopyDirection {
+  ToCurrent,
+  ToOther,
+}
+
+#[component]
+pub fn ComparePage() -> Html {
+  let edc_connector_context = use_edc_connector_context();
+  let activity_log_context = use_activity_log_context();
+
+  let other_management_url = use_state(String::new);
+  let other_api_key = use_state(String::new);
+  let loading = use_state(|| false);
+  let comparison = use_state(|| Option::<Result<Comparison, String>>::None);
+  let selection = use_state(BTreeSet::<(EntityKind, String)>::new);
+  let copy_entries = use_state(Vec::<RestoreEntry>::new);
+
+  let onchange_other_management_url = use_callback(
+    other_management_url.setter(),
+    |other_management_url, other_management_url_setter| {
+      other_management_url_setter.set(other_management_url);
+    },
+  );
+
+  let onchange_other_api_key = use_callback(
+    other_api_key.setter(),
+    |other_api_key, other_api_key_setter| {
+      other_api_key_setter.set(other_api_key);
+    },
+  );
+
+  let other_connector = EdcConnectorState::new(
+    (*other_management_url).clone(),
+    Some((*other_api_key).clone()).filter(|api_key| !api_key.is_empty()),
+  );
+
+  let oncompare = use_callback(
+    (
+      edc_connector_context.clone(),
+      other_connector.clone(),
+      loading.clone(),
+      comparison.clone(),
+      selection.clone(),
+      copy_entries.clone(),
+    ),
+    |_, (edc_connector_context, other_connector, loading, comparison, selection, copy_entries)| {
+      let (Some(current_client), Some(other_client)) = (
+        edc_connector_context.get_client(),
+        other_connector.get_client(),
+      ) else {
+        return;
+      };
+
+      let loading = loading.clone();
+      let comparison = comparison.clone();
+
+      selection.set(BTreeSet::new());
+      copy_entries.set(vec![]);
+      loading.set(true);
+
+      spawn_local(async move {
+        comparison.set(Some(compare(&current_client, &other_client).await));
+        loading.set(false);
+      });
+    },
+  );
+
+  let oncopy = use_callback(
+    (
+      edc_connector_context.clone(),
+      activity_log_context,
+      other_connector.clone(),
+      loading.clone(),
+      comparison.clone(),
+      selection.clone(),
+      copy_entries.clone(),
+    ),
+    |direction: CopyDirection,
+     (
+      edc_connector_context,
+      activity_log_context,
+      other_connector,
+      loading,
+      comparison,
+      selection,
+      copy_entries,
+    )| {
+      let (Some(current_client), Some(other_client), Some(Ok(current_comparison))) = (
+        edc_connector_context.get_client(),
+        other_connector.get_client(),
+        (**comparison).clone(),
+      ) else {
+        return;
+      };
+
+      let (source, target_client, target_connector) = match direction {
+        CopyDirection::ToCurrent => (current_comparison.other, current_client.clone(), None),
+        CopyDirection::ToOther => (
+          current_comparison.current,
+          other_client.clone(),
+          Some(other_connector.management_url().to_string()),
+        ),
+      };
+      let backup = select_entries(&source, selection);
+
+      let activity_log_context = activity_log_context.clone();
+      let loading = loading.clone();
+      let comparison = comparison.clone();
+      let selection = selection.clone();
+      let copy_entries = copy_entries.clone();
+
+      loading.set(true);
+
+      spawn_local(async move {
+        let entries = restore(
+          &target_client,
+          &backup,
+          ConflictStrategy::Overwrite,
+          |restore_entry| {
+            record_restore_entry(
+              &activity_log_context,
+              &restore_entry,
+              target_connector.clone(),
+            )
+          },
+        )
+        .await;
+
+        copy_entries.set(entries);
+        selection.set(BTreeSet::new());
+        comparison.set(Some(compare(&current_client, &other_client).await));
+        loading.set(false);
+      });
+    },
+  );
+
+  let onselect = use_callback(selection.clone(), |(key, selected), selection| {
+    let mut new_selection = (**selection).clone();
+
+    if selected {
+      new_selection.insert(key);
+    } else {
+      new_selection.remove(&key);
+    }
+
+    selection.set(new_selection);
+  });
+
+  let oncopy_to_current = oncopy.reform(|_| CopyDirection::ToCurrent);
+  let oncopy_to_other = oncopy.reform(|_| CopyDirection::ToOther);
+
+  let result = match (*comparison).clone() {
+    Some(Ok(comparison)) if comparison.entries.is_empty() => html!(
+      <Alert inline=true r#type={AlertType::Success} title="Both connectors have the same configuration" />
+    ),
+    Some(Ok(comparison)) => {
+      // An entry only exists on one side, so it can only be copied from there.
+      let selected_status = |status: DiffStatus| {
+        comparison.entries.iter().any(|diff_entry| {
+          diff_entry.status == status
+            && selection.contains(&(diff_entry.kind, diff_entry.id.clone()))
+        })
+      };
+      let only_in_current_selected = selected_status(DiffStatus::Removed);
+      let only_in_other_selected = selected_status(DiffStatus::Added);
+
+      html!(
+        <Stack gutter=true>
+          <StackItem>
+            <Toolbar>
+              <ToolbarContent>
+                <ToolbarItem>
+                  <Button
+                    variant={ButtonVariant::Secondary}
+                    label="Copy selected to current"
+                    onclick={oncopy_to_current}
+                    disabled={selection.is_empty() || only_in_current_selected || *loading}
+                  />
+                </ToolbarItem>
+                <ToolbarItem>
+                  <Button
+                    variant={ButtonVariant::Secondary}
+                    label="Copy selected to other"
+                    onclick={oncopy_to_other}
+                    disabled={selection.is_empty() || only_in_other_selected || *loading}
+                  />
+                </ToolbarItem>
+              </ToolbarContent>
+            </Toolbar>
+          </StackItem>
+          if only_in_current_selected || only_in_other_selected {
+            <StackItem>
+              <HelperText>
+                <HelperTextItem>
+                  { "Entries that only exist in one connector can only be copied from that connector to the other." }
+                </HelperTextItem>
+              </HelperText>
+            </StackItem>
+          }
+          <StackItem>
+            <ListConnectorDiff
+              entries={comparison.entries}
+              selection={(*selection).clone()}
+              {onselect}
+            />
+          </StackItem>
+        </Stack>
+      )
+    }
+    Some(Err(error)) => html!(
+      <Alert inline=true r#type={AlertType::Danger} title="Comparison failed">{ html!(error) }</Alert>
+    ),
+    None => html!(),
+  };
+
+  let failed = copy_entries
+    .iter()
+    .filter_map(|restore_entry| match &restore_entry.outcome {
+      RestoreOutcome::Failed(error) | RestoreOutcome::OverwriteFailed(error) => Some(format!(
+        "{} {}: {error}",
+        restore_entry.kind, restore_entry.id
+      )),
+      _ => None,
+    })
+    .collect::<Vec<_>>();
+
+  html!(
+    <Stack gutter=true>
+      <StackItem>
+        <Title level={Level::H3} size={Size::XXLarge}>{ "Compare Connectors" }</Title>
+      </StackItem>
+      <StackItem>
+        <Card>
+          <CardBody>
+            <Form>
+              <FormGroup label="Current Management URL">
+                <TextInput value={edc_connector_context.management_url().to_string()} readonly=true />
+              </FormGroup>
+              <FormGroup label="Other Management URL" required=true>
+                <TextInput
+                  required=true
+                  value={(*other_management_url).clone()}
+                  onchange={onchange_other_management_url}
+                />
+              </FormGroup>
+              <FormGroup label="Other API Key">
+                <TextInput
+                  r#type={TextInputType::Password}
+                  value={(*other_api_key).clone()}
+                  onchange={onchange_other_api_key}
+                />
+              </FormGroup>
+              <ActionGroup>
+                <Button
+                  variant={ButtonVariant::Primary}
+                  label="Compare"
+                  onclick={oncompare}
+                  loading={*loading}
+                  disabled={other_management_url.is_empty() || *loading}
+                />
+              </ActionGroup>
+            </Form>
+          </CardBody>
+        </Card>
+      </StackItem>
+      if !copy_entries.is_empty() {
+        <StackItem>
+          if failed.is_empty() {
+            <Alert
+              inline=true
+              r#type={AlertType::Success}
+              title={format!("Copied {} entries", copy_entries.len())}
+            />
+          } else {
+            <Alert
+              inline=true
+              r#type={AlertType::Danger}
+              title={format!("{} of {} entries failed to copy", failed.len(), copy_entries.len())}
+            >
+              <List>
+                { for failed.into_iter().map(|failure| html_nested!(<ListItem>{ failure }</ListItem>)) }
+              </List>
+            </Alert>
+          }
+        </StackItem>
+      }
+      <StackItem>{ result }</StackItem>
+    </Stack>
+  )
+}
+
+async fn compare(
+  current_client: &EdcConnectorClient,
+  other_client: &EdcConnectorClient,
+) -> Result<Comparison, String> {
+  let (current, other) = futures::join!(
+    ConnectorBackup::export(current_client),
+    ConnectorBackup::export(other_client)
+  );
+
+  let current = current.map_err(|error| format!("current connector: {error}"))?;
+  let other = other.map_err(|error| format!("other connector: {error}"))?;
+
+  Ok(Comparison {
+    entries: diff_connectors(&current, &other),
+    current: Rc::new(current),
+    other: Rc::new(other),
+  })
+}
@@ -0,0 +1,176 @@
+use crate::components::PolicyRules;
+use crate::contexts::use_edc_connector_context;
+use crate::models::{ContractAgreementItem, TransferProcessItem};
+use crate::routes::AppRoute;
+use crate::services::query_all;
+use edc_connector_client::types::policy::Policy;
+use edc_connector_client::types::query::Query;
+use patternfly_yew::prelude::*;
+use yew::prelude::*;
+use yew::suspense::use_future_with;
+use yew_nested_router::components::Link;
+
+#[derive(Clone, Debug, PartialEq, Properties)]
+pub struct ContractAgreementDetailsPageProps {
+  pub id: String,
+}
+
+#[component]
+pub fn ContractAgreementDetailsPage(props: &ContractAgreementDetailsPageProps) -> Html {
+  html!(
+    <Stack gutter=true>
+      <StackItem>
+        <Title level={Level::H3} size={Size::XXLarge}>
+          { format!("Contract Agreement {}", props.id) }
+        </Title>
+      </StackItem>
+      <StackItem>
+        <Card>
+          <CardBody>
+            <Suspense>
+              <ContractAgreementDetailsPageInner id={props.id.clone()} />
+            </Suspense>
+          </CardBody>
+        </Card>
+      </StackItem>
+    </Stack>
+  )
+}
+
+#[derive(Clone, Debug, PartialEq)]
+struct ContractAgreementDetails {
+  item: ContractAgreementItem,
+  policy: Policy,
+  local_asset: bool,
+  contract_negotiation_ids: Vec<String>,
+  transfer_process_items: Vec<TransferProcessItem>,
+}
+
+#[component]
+fn ContractAgreementDetailsPageInner(props: &ContractAgreementDetailsPageProps) -> HtmlResult {
+  let edc_connector_context = use_edc_connector_context();
+
+  let contract_agreement_details = use_future_with(
+    (edc_connector_context, props.id.clone()),
+    |parameters| async move {
+      let (edc_connector_context, id) = (*parameters).clone();
+
+      let Some(client) = edc_connector_context.get_client() else {
+        return Err("No connector configured".to_string());
+      };
+
+      let contract_agreement = client
+        .contract_agreements()
+        .get(&id)
+        .await
+        .map_err(|error| error.to_string())?;
+
+      let assets = client.assets();
+      let contract_negotiations = client.contract_negotiations();
+      let (asset, contract_negotiations, transfer_processes) = futures::join!(
+        assets.get(contract_agreement.asset_id()),
+        contract_negotiations.query(
+          Query::builder()
+            .filter("contractAgreement.id", "=", id.clone())
+            .build()
+        ),
+        query_all(
+          Query::builder()
+            .filter("contractId", "=", id.clone())
+            .build(),
+          |query| {
+            let client = client.clone();
+            async move { client.transfer_processes().query(query).await }
+          }
+        )
+      );
+
+      Ok(ContractAgreementDetails {
+        policy: contract_agreement.policy().clone(),
+        item: ContractAgreementItem::from(contract_agreement),
+        local_asset: asset.is_ok(),
+        contract_negotiation_ids: contract_negotiations
+          .unwrap_or_default()
+          .iter()
+          .map(|contract_negotiation| contract_negotiation.id().to_string())
+          .collect(),
+        transfer_process_items: transfer_processes
+          .unwrap_or_default()
+          .into_iter()
+          .map(TransferProcessItem::from)
+          .collect(),
+      })
+    },
+  )?;
+
+  Ok(match (*contract_agreement_details).clone() {
+    Ok(contract_agreement_details) => {
+      let contract_agreement_item = contract_agreement_details.item;
+      let asset_id = contract_agreement_item.asset_id.clone();
+
+      let asset = if contract_agreement_details.local_asset {
+        html!(<Link<AppRoute> to={AppRoute::Asset { id: asset_id.clone() }}>{ asset_id }</Link<AppRoute>>)
+      } else {
+        html!(asset_id)
+      };
+
+      html!(
+        <Stack gutter=true>
+          <StackItem>
+            <DescriptionList>
+              <DescriptionGroup term="ID">{ contract_agreement_item.id }</DescriptionGroup>
+              <DescriptionGroup term="Contract Signing Date">
+                { contract_agreement_item.signing_date }
+              </DescriptionGroup>
+              <DescriptionGroup term="Consumer ID">
+                { contract_agreement_item.consumer_id }
+              </DescriptionGroup>
+              <DescriptionGroup term="Provider ID">
+                { contract_agreement_item.provider_id }
+              </DescriptionGroup>
+              <DescriptionGroup term="Asset ID">{ asset }</DescriptionGroup>
+              <DescriptionGroup term="Policy ID">{ contract_agreement_item.policy_id }</DescriptionGroup>
+              <DescriptionGroup term="Contract Negotiation">
+                { for contract_agreement_details.contract_negotiation_ids.into_iter().map(|id| html!(
+                  <Link<AppRoute> to={AppRoute::ContractNegotiation { id: id.clone() }}>
+                    { id }
+                  </Link<AppRoute>>
+                )) }
+              </DescriptionGroup>
+              <DescriptionGroup term="Transfer Processes">
+                <List>
+                  { for contract_agreement_details.transfer_process_items.into_iter().map(|transfer_process_item| {
+                    let id = transfer_process_item.id.clone();
+
+                    html_nested!(
+                      <ListItem>
+                        <Link<AppRoute> to={AppRoute::TransferProcess { id: id.clone() }}>
+                          { id }
+                        </Link<AppRoute>>
+                        { format!(
+                          " — {} ({})",
+                          transfer_process_item.state, transfer_process_item.transfer_type
+                        ) }
+                      </ListItem>
+                    )
+                  }) }
+                </List>
+              </DescriptionGroup>
+            </DescriptionList>
+          </StackItem>
+          <StackItem>
+            <Title level={Level::H4}>{ "Agreed Policy" }</Title>
+          </StackItem>
+          <StackItem>
+            <PolicyRules policy={contract_agreement_details.policy} />
+          </StackItem>
+        </Stack>
+      )
+    }
+    Err(error) => html!(
+      <Alert inline=true r#type={AlertType::Danger} title="Unable to load contract agreement">
+        { html!(error) }
+      </Alert>
+    ),
+  })
+}
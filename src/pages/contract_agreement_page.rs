@@ -1,7 +1,8 @@
 use crate::components::ListContractAgreements;
 use crate::contexts::use_edc_connector_context;
-use crate::models::ContractAgreementItem;
-use edc_connector_client::types::query::Query;
+use crate::hooks::{use_list_state, use_total_entries};
+use crate::models::{ContractAgreementItem, ListQuery, ListState};
+use crate::services::error_message;
 use patternfly_yew::prelude::*;
 use yew::prelude::*;
 use yew::suspense::use_future_with;
@@ -9,23 +10,34 @@ use yew::suspense::use_future_with;
 #[component]
 pub fn ContractAgreementPage() -> Html {
   let refresh = use_state(|| 0usize);
-  let offset = use_state(|| 0usize);
-  let limit = use_state(|| 10usize);
+  let list_state = use_list_state();
 
-  let onoffset = use_callback(
-    (refresh.clone(), offset.setter()),
-    |offset, (refresh, offset_setter)| {
-      offset_setter.set(offset);
-      refresh.set(**refresh + 1);
-    },
-  );
+  let onoffset = use_callback(list_state.clone(), |offset, list_state| {
+    list_state.set(ListState {
+      offset,
+      ..(**list_state).clone()
+    });
+  });
 
-  let onlimit = use_callback(
-    (refresh.clone(), limit.setter()),
-    |limit, (refresh, limit_setter)| {
-      limit_setter.set(limit);
-      refresh.set(**refresh + 1);
-    },
+  let onlimit = use_callback(list_state.clone(), |limit, list_state| {
+    list_state.set(ListState {
+      limit,
+      ..(**list_state).clone()
+    });
+  });
+
+  let onquery = use_callback(list_state.clone(), |query, list_state| {
+    list_state.set(ListState {
+      offset: 0,
+      query,
+      ..(**list_state).clone()
+    });
+  });
+
+  let total_entries = use_total_entries(
+    (*refresh, list_state.query.clone()),
+    list_state.query.to_query(0, 0),
+    |client, query| async move { client.contract_agreements().query(query).await },
   );
 
   html!(
@@ -38,10 +50,13 @@ pub fn ContractAgreementPage() -> Html {
           <CardBody>
             <Suspense>
               <ContractAgreementPageInner
-                offset={*offset}
-                limit={*limit}
+                offset={list_state.offset}
+                limit={list_state.limit}
                 {onoffset}
                 {onlimit}
+                list_query={list_state.query.clone()}
+                {onquery}
+                {total_entries}
                 force_refresh={*refresh}
               />
             </Suspense>
@@ -58,6 +73,9 @@ pub struct ContractAgreementPageInnerProps {
   pub limit: usize,
   pub onoffset: Callback<usize>,
   pub onlimit: Callback<usize>,
+  pub list_query: ListQuery,
+  pub onquery: Callback<ListQuery>,
+  pub total_entries: Option<usize>,
   pub force_refresh: usize,
 }
 
@@ -70,40 +88,54 @@ pub fn ContractAgreementPageInner(props: &ContractAgreementPageInnerProps) -> Ht
       edc_connector_context,
       props.limit,
       props.offset,
+      props.list_query.clone(),
       props.force_refresh,
     ),
     |parameters| async move {
-      let (edc_connector_context, limit, offset, _) = (*parameters).clone();
+      let (edc_connector_context, limit, offset, list_query, _) = (*parameters).clone();
 
-      let query = Query::builder()
-        .limit(limit as u32)
-        .offset(offset as u32)
-        .build();
+      let query = list_query.to_query(offset, limit);
 
-      if let Some(client) = edc_connector_context.get_client() {
-        client
-          .contract_agreements()
-          .query(query)
-          .await
-          .unwrap_or_default()
-          .into_iter()
-          .map(ContractAgreementItem::from)
-          .collect::<Vec<_>>()
-      } else {
-        vec![]
-      }
+      let Some(client) = edc_connector_context.get_client() else {
+        return Ok(vec![]);
+      };
+
+      client
+        .contract_agreements()
+        .query(query)
+        .await
+        .map(|entities| {
+          entities
+            .into_iter()
+            .map(ContractAgreementItem::from)
+            .collect::<Vec<_>>()
+        })
+        .map_err(|error| error_message(&error))
     },
   )?;
 
-  let contract_agreement_items = (*contract_agreement_items).clone();
+  let (contract_agreement_items, error) = match (*contract_agreement_items).clone() {
+    Ok(contract_agreement_items) => (contract_agreement_items, None),
+    Err(error) => (vec![], Some(error)),
+  };
 
   Ok(html!(
-    <ListContractAgreements
-      contract_agreement_items={contract_agreement_items}
-      offset={props.offset}
-      limit={props.limit}
-      onoffset={props.onoffset.clone()}
-      onlimit={props.onlimit.clone()}
-    />
+    <>
+      if let Some(error) = error {
+        <Alert inline=true r#type={AlertType::Danger} title="Unable to load contract agreements">
+          { html!(error) }
+        </Alert>
+      }
+      <ListContractAgreements
+        contract_agreement_items={contract_agreement_items}
+        offset={props.offset}
+        total_entries={props.total_entries}
+        limit={props.limit}
+        onoffset={props.onoffset.clone()}
+        onlimit={props.onlimit.clone()}
+        list_query={props.list_query.clone()}
+        onquery={props.onquery.clone()}
+      />
+    </>
   ))
 }
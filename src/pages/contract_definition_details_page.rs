@@ -0,0 +1,84 @@
+use crate::contexts::use_edc_connector_context;
+use crate::models::ContractDefinitionItem;
+use crate::routes::AppRoute;
+use patternfly_yew::prelude::*;
+use yew::prelude::*;
+use yew::suspense::use_future_with;
+use yew_nested_router::components::Link;
+
+#[derive(Clone, Debug, PartialEq, Properties)]
+pub struct ContractDefinitionDetailsPageProps {
+  pub id: String,
+}
+
+#[component]
+pub fn ContractDefinitionDetailsPage(props: &ContractDefinitionDetailsPageProps) -> Html {
+  html!(
+    <Stack gutter=true>
+      <StackItem>
+        <Title level={Level::H3} size={Size::XXLarge}>
+          { format!("Contract Definition {}", props.id) }
+        </Title>
+      </StackItem>
+      <StackItem>
+        <Card>
+          <CardBody>
+            <Suspense>
+              <ContractDefinitionDetailsPageInner id={props.id.clone()} />
+            </Suspense>
+          </CardBody>
+        </Card>
+      </StackItem>
+    </Stack>
+  )
+}
+
+#[component]
+fn ContractDefinitionDetailsPageInner(props: &ContractDefinitionDetailsPageProps) -> HtmlResult {
+  let edc_connector_context = use_edc_connector_context();
+
+  let contract_definition_item = use_future_with(
+    (edc_connector_context, props.id.clone()),
+    |parameters| async move {
+      let (edc_connector_context, id) = (*parameters).clone();
+
+      match edc_connector_context.get_client() {
+        Some(client) => client
+          .contract_definitions()
+          .get(&id)
+          .await
+          .map(ContractDefinitionItem::from)
+          .map_err(|error| error.to_string()),
+        None => Err("No connector configured".to_string()),
+      }
+    },
+  )?;
+
+  Ok(match (*contract_definition_item).clone() {
+    Ok(contract_definition_item) => {
+      let access_policy_id = contract_definition_item.access_policy_id.clone();
+      let contract_policy_id = contract_definition_item.contract_policy_id.clone();
+
+      html!(
+        <DescriptionList>
+          <DescriptionGroup term="ID">{ contract_definition_item.id }</DescriptionGroup>
+          <DescriptionGroup term="Access Policy">
+            <Link<AppRoute> to={AppRoute::PolicyDefinition { id: access_policy_id.clone() }}>
+              { access_policy_id }
+            </Link<AppRoute>>
+          </DescriptionGroup>
+          <DescriptionGroup term="Contract Policy">
+            <Link<AppRoute> to={AppRoute::PolicyDefinition { id: contract_policy_id.clone() }}>
+              { contract_policy_id }
+            </Link<AppRoute>>
+          </DescriptionGroup>
+        </DescriptionList>
+      )
+    }
+    Err(error) => html!(
+      <Alert inline=true r#type={AlertType::Danger} title="Unable to load contract definition">
+        { html!(error) }
+      </Alert>
+    ),
+  })
+}
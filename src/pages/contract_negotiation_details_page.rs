@@ -0,0 +1,236 @@
+use crate::components::{AutoRefreshToggle, WatchButton};
+use crate::contexts::{WatchedKind, use_edc_connector_context};
+use crate::hooks::use_auto_refresh;
+use crate::models::{AutoRefresh, ContractNegotiationDetails};
+use crate::routes::AppRoute;
+use crate::services::get_raw_entity;
+use patternfly_yew::prelude::*;
+use serde_json::Value;
+use yew::prelude::*;
+use yew::suspense::use_future_with;
+use yew_nested_router::components::Link;
+
+#[derive(Clone, Debug, PartialEq)]
+struct StateObservation {
+  state: String,
+  observed_at: String,
+}
+
+#[derive(Clone, Debug, PartialEq, Properties)]
+pub struct ContractNegotiationDetailsPageProps {
+  pub id: String,
+}
+
+#[component]
+pub fn ContractNegotiationDetailsPage(props: &ContractNegotiationDetailsPageProps) -> Html {
+  let refresh = use_state(|| 0usize);
+  let timeline = use_state(Vec::<StateObservation>::new);
+  let auto_refresh = use_state(AutoRefresh::default);
+
+  let onrefresh = use_callback(refresh.clone(), |_, refresh| {
+    refresh.set(**refresh + 1);
+  });
+
+  use_auto_refresh(auto_refresh.active_interval(), onrefresh);
+
+  let onchange_auto_refresh = use_callback(
+    auto_refresh.setter(),
+    |auto_refresh, auto_refresh_setter| {
+      auto_refresh_setter.set(auto_refresh);
+    },
+  );
+
+  {
+    let timeline = timeline.clone();
+
+    use_effect_with(props.id.clone(), move |_| timeline.set(vec![]));
+  }
+
+  // Records every transition seen from here, the page being reloaded by auto-refresh.
+  let onstate = use_callback(timeline.clone(), |state: String, timeline| {
+    if timeline
+      .last()
+      .is_none_or(|observation| observation.state != state)
+    {
+      let mut observations = (**timeline).clone();
+      observations.push(StateObservation {
+        state,
+        observed_at: chrono::Utc::now()
+          .format("%Y-%m-%d %H:%M:%S UTC")
+          .to_string(),
+      });
+
+      timeline.set(observations);
+    }
+  });
+
+  html!(
+    <Stack gutter=true>
+      <StackItem>
+        <Split gutter=true>
+          <SplitItem fill=true>
+            <Title level={Level::H3} size={Size::XXLarge}>
+              { format!("Contract Negotiation {}", props.id) }
+            </Title>
+          </SplitItem>
+          <SplitItem>
+            <AutoRefreshToggle value={*auto_refresh} onchange={onchange_auto_refresh} />
+          </SplitItem>
+          <SplitItem>
+            <WatchButton kind={WatchedKind::ContractNegotiation} id={props.id.clone()} />
+          </SplitItem>
+        </Split>
+      </StackItem>
+      <StackItem>
+        <Card>
+          <CardBody>
+            <Suspense>
+              <ContractNegotiationDetailsPageInner
+                id={props.id.clone()}
+                force_refresh={*refresh}
+                {onstate}
+              />
+            </Suspense>
+          </CardBody>
+        </Card>
+      </StackItem>
+      <StackItem>
+        <Card>
+          <CardHeader>
+            <CardTitle>{ "State Timeline" }</CardTitle>
+          </CardHeader>
+          <CardBody>
+            <List>
+              { for timeline.iter().map(|observation| html_nested!(
+                <ListItem>
+                  { format!("{} — {}", observation.state, observation.observed_at) }
+                </ListItem>
+              )) }
+            </List>
+          </CardBody>
+        </Card>
+      </StackItem>
+    </Stack>
+  )
+}
+
+#[derive(Clone, Debug, PartialEq, Properties)]
+struct ContractNegotiationDetailsPageInnerProps {
+  id: String,
+  force_refresh: usize,
+  onstate: Callback<String>,
+}
+
+#[component]
+fn ContractNegotiationDetailsPageInner(
+  props: &ContractNegotiationDetailsPageInnerProps,
+) -> HtmlResult {
+  let edc_connector_context = use_edc_connector_context();
+
+  let contract_negotiation = use_future_with(
+    (edc_connector_context, props.id.clone(), props.force_refresh),
+    |parameters| async move {
+      let (edc_connector_context, id, _) = (*parameters).clone();
+
+      let Some(client) = edc_connector_context.get_client() else {
+        return Err("No connector configured".to_string());
+      };
+
+      let contract_negotiations = client.contract_negotiations();
+      let (contract_negotiation, raw) = futures::join!(
+        contract_negotiations.get(&id),
+        get_raw_entity(&edc_connector_context, "contractnegotiations", &id)
+      );
+
+      let contract_negotiation = contract_negotiation.map_err(|error| error.to_string())?;
+      let details =
+        ContractNegotiationDetails::new(contract_negotiation, &raw.unwrap_or(Value::Null));
+
+      // The management API does not return the offer itself, only the agreement's policy.
+      let agreed_policy = match details.item.contract_agreement_id.as_str() {
+        "" => None,
+        contract_agreement_id => client
+          .contract_agreements()
+          .get(contract_agreement_id)
+          .await
+          .ok()
+          .and_then(|contract_agreement| {
+            serde_json::to_string_pretty(contract_agreement.policy()).ok()
+          }),
+      };
+
+      Ok((details, agreed_policy))
+    },
+  )?;
+
+  let state = contract_negotiation
+    .as_ref()
+    .ok()
+    .map(|(details, _)| details.item.state.clone());
+
+  use_effect_with((state, props.onstate.clone()), |(state, onstate)| {
+    if let Some(state) = state {
+      onstate.emit(state.clone());
+    }
+  });
+
+  Ok(match (*contract_negotiation).clone() {
+    Ok((details, agreed_policy)) => {
+      let contract_negotiation_item = details.item;
+
+      let contract_agreement = if contract_negotiation_item.contract_agreement_id.is_empty() {
+        html!()
+      } else {
+        let id = contract_negotiation_item.contract_agreement_id.clone();
+
+        html!(
+          <Link<AppRoute> to={AppRoute::ContractAgreement { id: id.clone() }}>{ id }</Link<AppRoute>>
+        )
+      };
+
+      let agreed_policy = match agreed_policy {
+        Some(agreed_policy) => html!(
+          <CodeBlock>
+            <CodeBlockCode>{ agreed_policy }</CodeBlockCode>
+          </CodeBlock>
+        ),
+        None => html!("Available once an agreement has been reached"),
+      };
+
+      html!(
+        <DescriptionList>
+          <DescriptionGroup term="ID">{ contract_negotiation_item.id }</DescriptionGroup>
+          <DescriptionGroup term="State">{ contract_negotiation_item.state }</DescriptionGroup>
+          if let Some(error_detail) = details.error_detail {
+            <DescriptionGroup term="Error Detail">
+              <Alert inline=true plain=true r#type={AlertType::Danger} title={error_detail} />
+            </DescriptionGroup>
+          }
+          <DescriptionGroup term="Kind">{ contract_negotiation_item.kind }</DescriptionGroup>
+          <DescriptionGroup term="Created At">{ details.created_at }</DescriptionGroup>
+          <DescriptionGroup term="Contract Agreement">{ contract_agreement }</DescriptionGroup>
+          <DescriptionGroup term="Counter Party ID">
+            { contract_negotiation_item.counter_party_id }
+          </DescriptionGroup>
+          <DescriptionGroup term="Counter Party Address">
+            { contract_negotiation_item.counter_party_address }
+          </DescriptionGroup>
+          <DescriptionGroup term="Protocol">{ contract_negotiation_item.protocol }</DescriptionGroup>
+          <DescriptionGroup term="Callback Addresses">
+            <List>
+              { for details.callback_addresses.iter().map(|callback_address| html_nested!(
+                <ListItem>{ callback_address.to_string() }</ListItem>
+              )) }
+            </List>
+          </DescriptionGroup>
+          <DescriptionGroup term="Agreed Policy">{ agreed_policy }</DescriptionGroup>
+        </DescriptionList>
+      )
+    }
+    Err(error) => html!(
+      <Alert inline=true r#type={AlertType::Danger} title="Unable to load contract negotiation">
+        { html!(error) }
+      </Alert>
+    ),
+  })
+}
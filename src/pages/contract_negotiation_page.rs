@@ -1,7 +1,8 @@
-use crate::components::{CreateContractNegotiation, ListContractNegotiations};
+use crate::components::{AutoRefreshToggle, CreateContractNegotiation, ListContractNegotiations};
 use crate::contexts::use_edc_connector_context;
-use crate::models::ContractNegotiationItem;
-use edc_connector_client::types::query::Query;
+use crate::hooks::{use_auto_refresh, use_list_state, use_total_entries};
+use crate::models::{AutoRefresh, ContractNegotiationItem, ListQuery, ListState};
+use crate::services::error_message;
 use patternfly_yew::prelude::*;
 use yew::prelude::*;
 use yew::suspense::use_future_with;
@@ -9,34 +10,62 @@ use yew::suspense::use_future_with;
 #[component]
 pub fn ContractNegotiationPage() -> Html {
   let refresh = use_state(|| 0usize);
+  let created = use_state(|| 0usize);
   let backdropper = use_backdrop();
-  let offset = use_state(|| 0usize);
-  let limit = use_state(|| 10usize);
+  let list_state = use_list_state();
+  let auto_refresh = use_state(AutoRefresh::default);
 
-  let onoffset = use_callback(
-    (refresh.clone(), offset.setter()),
-    |offset, (refresh, offset_setter)| {
-      offset_setter.set(offset);
-      refresh.set(**refresh + 1);
+  let onrefresh = use_callback(refresh.clone(), |_, refresh| {
+    refresh.set(**refresh + 1);
+  });
+
+  use_auto_refresh(auto_refresh.active_interval(), onrefresh);
+
+  let onchange_auto_refresh = use_callback(
+    auto_refresh.setter(),
+    |auto_refresh, auto_refresh_setter| {
+      auto_refresh_setter.set(auto_refresh);
     },
   );
 
-  let onlimit = use_callback(
-    (refresh.clone(), limit.setter()),
-    |limit, (refresh, limit_setter)| {
-      limit_setter.set(limit);
-      refresh.set(**refresh + 1);
-    },
+  let onoffset = use_callback(list_state.clone(), |offset, list_state| {
+    list_state.set(ListState {
+      offset,
+      ..(**list_state).clone()
+    });
+  });
+
+  let onlimit = use_callback(list_state.clone(), |limit, list_state| {
+    list_state.set(ListState {
+      limit,
+      ..(**list_state).clone()
+    });
+  });
+
+  let onquery = use_callback(list_state.clone(), |query, list_state| {
+    list_state.set(ListState {
+      offset: 0,
+      query,
+      ..(**list_state).clone()
+    });
+  });
+
+  // Recounted after creating a negotiation, not on every auto-refresh tick.
+  let total_entries = use_total_entries(
+    (*created, list_state.query.clone()),
+    list_state.query.to_query(0, 0),
+    |client, query| async move { client.contract_negotiations().query(query).await },
   );
 
   let on_create = use_callback(
-    (backdropper.clone(), refresh.clone()),
-    |_, (backdropper, refresh)| {
+    (backdropper.clone(), refresh.clone(), created.clone()),
+    |_, (backdropper, refresh, created)| {
       if let Some(backdropper) = backdropper {
         backdropper.close();
       }
 
       refresh.set(**refresh + 1);
+      created.set(**created + 1);
     },
   );
 
@@ -59,6 +88,9 @@ pub fn ContractNegotiationPage() -> Html {
           <SplitItem fill=true>
             <Title level={Level::H3} size={Size::XXLarge}>{ "List Contract Negotiations" }</Title>
           </SplitItem>
+          <SplitItem>
+            <AutoRefreshToggle value={*auto_refresh} onchange={onchange_auto_refresh} />
+          </SplitItem>
           <SplitItem>
             <Button icon={Icon::Plus} {onclick} variant={ButtonVariant::Primary}>{ "Add" }</Button>
           </SplitItem>
@@ -69,10 +101,13 @@ pub fn ContractNegotiationPage() -> Html {
           <CardBody>
             <Suspense>
               <ContractNegotiationPageInner
-                offset={*offset}
-                limit={*limit}
+                offset={list_state.offset}
+                limit={list_state.limit}
                 {onoffset}
                 {onlimit}
+                list_query={list_state.query.clone()}
+                {onquery}
+                {total_entries}
                 force_refresh={*refresh}
               />
             </Suspense>
@@ -89,6 +124,9 @@ pub struct ContractNegotiationPageInnerProps {
   pub limit: usize,
   pub onoffset: Callback<usize>,
   pub onlimit: Callback<usize>,
+  pub list_query: ListQuery,
+  pub onquery: Callback<ListQuery>,
+  pub total_entries: Option<usize>,
   pub force_refresh: usize,
 }
 
@@ -101,40 +139,54 @@ pub fn ContractNegotiationPageInner(props: &ContractNegotiationPageInnerProps) -
       edc_connector_context,
       props.limit,
       props.offset,
+      props.list_query.clone(),
       props.force_refresh,
     ),
     |parameters| async move {
-      let (edc_connector_context, limit, offset, _) = (*parameters).clone();
-
-      let query = Query::builder()
-        .limit(limit as u32)
-        .offset(offset as u32)
-        .build();
-
-      if let Some(client) = edc_connector_context.get_client() {
-        client
-          .contract_negotiations()
-          .query(query)
-          .await
-          .unwrap_or_default()
-          .into_iter()
-          .map(ContractNegotiationItem::from)
-          .collect()
-      } else {
-        vec![]
-      }
+      let (edc_connector_context, limit, offset, list_query, _) = (*parameters).clone();
+
+      let query = list_query.to_query(offset, limit);
+
+      let Some(client) = edc_connector_context.get_client() else {
+        return Ok(vec![]);
+      };
+
+      client
+        .contract_negotiations()
+        .query(query)
+        .await
+        .map(|entities| {
+          entities
+            .into_iter()
+            .map(ContractNegotiationItem::from)
+            .collect::<Vec<_>>()
+        })
+        .map_err(|error| error_message(&error))
     },
   )?;
 
-  let contract_negotiation_items = (*contract_negotiation_items).clone();
+  let (contract_negotiation_items, error) = match (*contract_negotiation_items).clone() {
+    Ok(contract_negotiation_items) => (contract_negotiation_items, None),
+    Err(error) => (vec![], Some(error)),
+  };
 
   Ok(html!(
-    <ListContractNegotiations
-      contract_negotiation_items={contract_negotiation_items}
-      offset={props.offset}
-      limit={props.limit}
-      onoffset={props.onoffset.clone()}
-      onlimit={props.onlimit.clone()}
-    />
+    <>
+      if let Some(error) = error {
+        <Alert inline=true r#type={AlertType::Danger} title="Unable to load contract negotiations">
+          { html!(error) }
+        </Alert>
+      }
+      <ListContractNegotiations
+        contract_negotiation_items={contract_negotiation_items}
+        offset={props.offset}
+        total_entries={props.total_entries}
+        limit={props.limit}
+        onoffset={props.onoffset.clone()}
+        onlimit={props.onlimit.clone()}
+        list_query={props.list_query.clone()}
+        onquery={props.onquery.clone()}
+      />
+    </>
   ))
 }
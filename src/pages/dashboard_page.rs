@@ -0,0 +1,270 @@
+use crate::contexts::use_edc_connector_context;
+use crate::models::{AgeThreshold, EntityKind};
+use crate::routes::AppRoute;
+use crate::services::{ProcessSummary, StateCount, connector_overview};
+use patternfly_yew::prelude::*;
+use yew::prelude::*;
+use yew::suspense::use_future_with;
+use yew_nested_router::components::Link;
+
+#[component]
+pub fn DashboardPage() -> Html {
+  let refresh = use_state(|| 0usize);
+  let age_threshold = use_state(AgeThreshold::default);
+
+  let onrefresh = use_callback(refresh.clone(), |_, refresh| {
+    refresh.set(**refresh + 1);
+  });
+
+  let onselect_age_threshold = use_callback(
+    age_threshold.setter(),
+    |age_threshold, age_threshold_setter| {
+      age_threshold_setter.set(age_threshold);
+    },
+  );
+
+  html!(
+    <Stack gutter=true>
+      <StackItem>
+        <Split gutter=true>
+          <SplitItem fill=true>
+            <Title level={Level::H3} size={Size::XXLarge}>{ "Dashboard" }</Title>
+          </SplitItem>
+          <SplitItem>
+            <SimpleSelect<AgeThreshold>
+              entries={AgeThreshold::ALL.to_vec()}
+              selected={*age_threshold}
+              onselect={onselect_age_threshold}
+            />
+          </SplitItem>
+          <SplitItem>
+            <Button icon={Icon::SyncAlt} variant={ButtonVariant::Secondary} onclick={onrefresh}>
+              { "Refresh" }
+            </Button>
+          </SplitItem>
+        </Split>
+      </StackItem>
+      <StackItem>
+        <Suspense fallback={html!(<Spinner />)}>
+          <DashboardPageInner force_refresh={*refresh} age_threshold={*age_threshold} />
+        </Suspense>
+      </StackItem>
+    </Stack>
+  )
+}
+
+#[derive(Clone, Debug, PartialEq, Properties)]
+struct DashboardPageInnerProps {
+  force_refresh: usize,
+  age_threshold: AgeThreshold,
+}
+
+#[component]
+fn DashboardPageInner(props: &DashboardPageInnerProps) -> HtmlResult {
+  let edc_connector_context = use_edc_connector_context();
+
+  let connector_overview = use_future_with(
+    (edc_connector_context, props.force_refresh),
+    |parameters| async move {
+      let (edc_connector_context, _) = (*parameters).clone();
+
+      let Some(client) = edc_connector_context.get_client() else {
+        return Err("No connector configured".to_string());
+      };
+
+      connector_overview(&edc_connector_context, &client)
+        .await
+        .map_err(|error| error.to_string())
+    },
+  )?;
+
+  let connector_overview = match (*connector_overview).clone() {
+    Ok(connector_overview) => connector_overview,
+    Err(error) => {
+      return Ok(html!(
+        <Alert inline=true r#type={AlertType::Danger} title="Unable to load the connector overview">
+          { html!(error) }
+        </Alert>
+      ));
+    }
+  };
+
+  let now = chrono::Utc::now().timestamp_millis();
+  let threshold = props.age_threshold.duration().as_millis() as i64;
+  let old = connector_overview
+    .in_progress
+    .iter()
+    .filter(|summary| now - summary.since > threshold)
+    .cloned()
+    .collect::<Vec<_>>();
+
+  Ok(html!(
+    <Stack gutter=true>
+      <StackItem>
+        <Gallery gutter=true>
+          { count_card("Assets", connector_overview.assets, AppRoute::Assets) }
+          { count_card("Policies", connector_overview.policy_definitions, AppRoute::Policies) }
+          { count_card(
+            "Contract Definitions",
+            connector_overview.contract_definitions,
+            AppRoute::ContractDefinitions,
+          ) }
+        </Gallery>
+      </StackItem>
+      <StackItem>
+        <Split gutter=true>
+          <SplitItem fill=true>
+            { state_counts_card(
+              "Contract Negotiations",
+              &connector_overview.contract_negotiation_states,
+              AppRoute::ContractNegotiations,
+            ) }
+          </SplitItem>
+          <SplitItem fill=true>
+            { state_counts_card(
+              "Transfer Processes",
+              &connector_overview.transfer_process_states,
+              AppRoute::TransferProcesses,
+            ) }
+          </SplitItem>
+        </Split>
+      </StackItem>
+      <StackItem>
+        <Card>
+          <CardHeader>
+            <CardTitle>{ "Recent Failures" }</CardTitle>
+          </CardHeader>
+          <CardBody>
+            if connector_overview.recent_failures.is_empty() {
+              { "No terminated negotiations or transfers" }
+            } else {
+              <List>
+                { for connector_overview.recent_failures.iter().map(|failure| html_nested!(
+                  <ListItem>
+                    { process_summary(failure) }
+                    { format!(" — {}", failure_time(failure)) }
+                    if let Some(error_detail) = failure.error_detail.clone() {
+                      <Alert inline=true plain=true r#type={AlertType::Danger} title={error_detail} />
+                    }
+                  </ListItem>
+                )) }
+              </List>
+            }
+          </CardBody>
+        </Card>
+      </StackItem>
+      <StackItem>
+        <Card>
+          <CardHeader>
+            <CardTitle>{ format!("Long Running ({})", old.len()) }</CardTitle>
+          </CardHeader>
+          <CardBody>
+            if old.is_empty() {
+              { "Nothing in a non-terminal state is that old" }
+            } else {
+              <List>
+                { for old.iter().map(|summary| html_nested!(
+                  <ListItem>
+                    { process_summary(summary) }
+                    { format!(" — {}", in_progress_age(summary, now)) }
+                  </ListItem>
+                )) }
+              </List>
+            }
+          </CardBody>
+        </Card>
+      </StackItem>
+    </Stack>
+  ))
+}
+
+fn count_card(title: &str, count: usize, to: AppRoute) -> Html {
+  html!(
+    <Card>
+      <CardHeader>
+        <CardTitle>
+          <Link<AppRoute> {to}>{ title.to_string() }</Link<AppRoute>>
+        </CardTitle>
+      </CardHeader>
+      <CardBody>
+        <Title level={Level::H2} size={Size::XXXXLarge}>{ count }</Title>
+      </CardBody>
+    </Card>
+  )
+}
+
+fn state_counts_card(title: &str, state_counts: &[StateCount], to: AppRoute) -> Html {
+  let total = state_counts
+    .iter()
+    .map(|state_count| state_count.count)
+    .sum::<usize>();
+
+  html!(
+    <Card>
+      <CardHeader>
+        <CardTitle>
+          <Link<AppRoute> {to}>{ format!("{title} ({total})") }</Link<AppRoute>>
+        </CardTitle>
+      </CardHeader>
+      <CardBody>
+        if state_counts.is_empty() {
+          { "None" }
+        } else {
+          <DescriptionList>
+            { for state_counts.iter().map(|state_count| html_nested!(
+              <DescriptionGroup term={state_count.state.clone()}>
+                { state_count.count }
+              </DescriptionGroup>
+            )) }
+          </DescriptionList>
+        }
+      </CardBody>
+    </Card>
+  )
+}
+
+fn process_summary(summary: &ProcessSummary) -> Html {
+  html!(
+    <>
+      { format!("{} ", summary.kind) }
+      <Link<AppRoute> to={summary.kind.route(summary.id.clone())}>{ summary.id.clone() }</Link<AppRoute>>
+    </>
+  )
+}
+
+/// Negotiations are timestamped with their creation, transfers with their last state change.
+fn failure_time(summary: &ProcessSummary) -> String {
+  match summary.kind {
+    EntityKind::ContractNegotiation => format!("created {}", timestamp(summary.since)),
+    _ => format!("terminated {}", timestamp(summary.since)),
+  }
+}
+
+fn in_progress_age(summary: &ProcessSummary, now: i64) -> String {
+  match summary.kind {
+    EntityKind::ContractNegotiation => {
+      format!(
+        "{}, created {} ago",
+        summary.state,
+        age(now - summary.since)
+      )
+    }
+    _ => format!("{} for {}", summary.state, age(now - summary.since)),
+  }
+}
+
+fn timestamp(millis: i64) -> String {
+  chrono::DateTime::from_timestamp_millis(millis)
+    .unwrap_or_default()
+    .to_string()
+}
+
+fn age(millis: i64) -> String {
+  let minutes = millis / 60_000;
+
+  match minutes {
+    minutes if minutes >= 1440 => format!("{}d {}h", minutes / 1440, minutes % 1440 / 60),
+    minutes if minutes >= 60 => format!("{}h {}min", minutes / 60, minutes % 60),
+    minutes => format!("{minutes}min"),
+  }
+}
@@ -0,0 +1,125 @@
+use crate::components::ListDataPlanes;
+use crate::contexts::use_edc_connector_context;
+use crate::models::{DataPlaneInstanceItem, DataPlaneInstanceState};
+use patternfly_yew::prelude::*;
+use std::collections::BTreeSet;
+use yew::prelude::*;
+use yew::suspense::use_future_with;
+
+#[component]
+pub fn DataPlanePage() -> Html {
+  let refresh = use_state(|| 0usize);
+
+  let onrefresh = use_callback(refresh.clone(), |_, refresh| {
+    refresh.set(**refresh + 1);
+  });
+
+  html!(
+    <Stack gutter=true>
+      <StackItem>
+        <Split gutter=true>
+          <SplitItem fill=true>
+            <Title level={Level::H3} size={Size::XXLarge}>{ "Data Planes" }</Title>
+          </SplitItem>
+          <SplitItem>
+            <Button icon={Icon::SyncAlt} variant={ButtonVariant::Secondary} onclick={onrefresh}>
+              { "Refresh" }
+            </Button>
+          </SplitItem>
+        </Split>
+      </StackItem>
+      <StackItem>
+        <Suspense>
+          <DataPlanePageInner force_refresh={*refresh} />
+        </Suspense>
+      </StackItem>
+    </Stack>
+  )
+}
+
+#[derive(Clone, Debug, PartialEq, Properties)]
+struct DataPlanePageInnerProps {
+  force_refresh: usize,
+}
+
+#[component]
+fn DataPlanePageInner(props: &DataPlanePageInnerProps) -> HtmlResult {
+  let edc_connector_context = use_edc_connector_context();
+
+  let data_plane_instance_items = use_future_with(
+    (edc_connector_context, props.force_refresh),
+    |parameters| async move {
+      let (edc_connector_context, _) = (*parameters).clone();
+
+      let Some(client) = edc_connector_context.get_client() else {
+        return Err("No connector configured".to_string());
+      };
+
+      client
+        .data_planes()
+        .list()
+        .await
+        .map(|data_plane_instances| {
+          data_plane_instances
+            .into_iter()
+            .map(DataPlaneInstanceItem::from)
+            .collect::<Vec<_>>()
+        })
+        .map_err(|error| error.to_string())
+    },
+  )?;
+
+  let data_plane_instance_items = match (*data_plane_instance_items).clone() {
+    Ok(data_plane_instance_items) => data_plane_instance_items,
+    Err(error) => {
+      return Ok(html!(
+        <Alert inline=true r#type={AlertType::Danger} title="Unable to load data planes">
+          { html!(error) }
+        </Alert>
+      ));
+    }
+  };
+
+  // Transfers are only dispatched to data planes that passed their last availability check.
+  let available_transfer_types = data_plane_instance_items
+    .iter()
+    .filter(|data_plane_instance_item| {
+      data_plane_instance_item.state == DataPlaneInstanceState::Available
+    })
+    .flat_map(|data_plane_instance_item| data_plane_instance_item.allowed_transfer_types.clone())
+    .collect::<BTreeSet<_>>();
+
+  Ok(html!(
+    <Stack gutter=true>
+      <StackItem>
+        <Card>
+          <CardHeader>
+            <CardTitle>{ "Available Transfer Types" }</CardTitle>
+          </CardHeader>
+          <CardBody>
+            if available_transfer_types.is_empty() {
+              <Alert
+                inline=true
+                r#type={AlertType::Warning}
+                title="No available data plane, transfers will fail until one is registered"
+              />
+            } else {
+              <List>
+                { for available_transfer_types.into_iter().map(|transfer_type| html_nested!(
+                  <ListItem>{ transfer_type }</ListItem>
+                )) }
+              </List>
+            }
+          </CardBody>
+        </Card>
+      </StackItem>
+      <StackItem>
+        <Card>
+          <CardBody>
+            <ListDataPlanes {data_plane_instance_items} />
+          </CardBody>
+        </Card>
+      </StackItem>
+    </Stack>
+  ))
+}
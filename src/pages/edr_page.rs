@@ -0,0 +1,219 @@
+use crate::components::ListEdrs;
+use crate::contexts::{
+  ActivityOperation, record_activity, use_activity_log_context, use_edc_connector_context,
+};
+use crate::hooks::{use_list_state, use_total_entries};
+use crate::models::{EdrEntryItem, EntityKind, ListQuery, ListState};
+use crate::services::{error_message, get_cached_edr, refresh_edr};
+use patternfly_yew::prelude::*;
+use yew::platform::spawn_local;
+use yew::prelude::*;
+use yew::suspense::use_future_with;
+
+#[component]
+pub fn EdrPage() -> Html {
+  let refresh = use_state(|| 0usize);
+  let error = use_state(|| Option::<String>::None);
+  let list_state = use_list_state();
+
+  let onoffset = use_callback(list_state.clone(), |offset, list_state| {
+    list_state.set(ListState {
+      offset,
+      ..(**list_state).clone()
+    });
+  });
+
+  let onlimit = use_callback(list_state.clone(), |limit, list_state| {
+    list_state.set(ListState {
+      limit,
+      ..(**list_state).clone()
+    });
+  });
+
+  let onquery = use_callback(list_state.clone(), |query, list_state| {
+    list_state.set(ListState {
+      offset: 0,
+      query,
+      ..(**list_state).clone()
+    });
+  });
+
+  let total_entries = use_total_entries(
+    (*refresh, list_state.query.clone()),
+    list_state.query.to_query(0, 0),
+    |client, query| async move { client.edrs().query(query).await },
+  );
+
+  let edc_connector_context = use_edc_connector_context();
+  let activity_log_context = use_activity_log_context();
+
+  let onrefresh = use_callback(
+    (
+      refresh.clone(),
+      error.clone(),
+      edc_connector_context.clone(),
+    ),
+    |transfer_process_id: String, (refresh, error, edc_connector_context)| {
+      let refresh = refresh.clone();
+      let error = error.clone();
+      let edc_connector_context = edc_connector_context.clone();
+
+      spawn_local(async move {
+        match refresh_edr(&edc_connector_context, &transfer_process_id).await {
+          Ok(_) => error.set(None),
+          Err(message) => error.set(Some(format!(
+            "Could not refresh the EDR of {transfer_process_id}: {message}"
+          ))),
+        }
+        refresh.set(*refresh + 1);
+      });
+    },
+  );
+
+  let ondelete = use_callback(
+    (refresh.clone(), edc_connector_context, activity_log_context),
+    |transfer_process_id: String, (refresh, edc_connector_context, activity_log_context)| {
+      let refresh = refresh.clone();
+      let edc_connector_context = edc_connector_context.clone();
+      let activity_log_context = activity_log_context.clone();
+
+      spawn_local(async move {
+        if let Some(client) = edc_connector_context.get_client() {
+          let result = client.edrs().delete(&transfer_process_id).await;
+          record_activity(
+            &activity_log_context,
+            ActivityOperation::Delete,
+            EntityKind::EndpointDataReference,
+            Some(transfer_process_id),
+            &result,
+          );
+        }
+        refresh.set(*refresh + 1);
+      });
+    },
+  );
+
+  html!(
+    <Stack gutter=true>
+      <StackItem>
+        <Title level={Level::H3} size={Size::XXLarge}>{ "List EDRs" }</Title>
+      </StackItem>
+      if let Some(error) = (*error).clone() {
+        <StackItem>
+          <Alert inline=true r#type={AlertType::Danger} title="Refresh failed">{ html!(error) }</Alert>
+        </StackItem>
+      }
+      <StackItem>
+        <Card>
+          <CardBody>
+            <Suspense>
+              <EdrPageInner
+                offset={list_state.offset}
+                limit={list_state.limit}
+                {onoffset}
+                {onlimit}
+                list_query={list_state.query.clone()}
+                {onquery}
+                {onrefresh}
+                {ondelete}
+                {total_entries}
+                force_refresh={*refresh}
+              />
+            </Suspense>
+          </CardBody>
+        </Card>
+      </StackItem>
+    </Stack>
+  )
+}
+
+#[derive(Clone, Debug, PartialEq, Properties)]
+pub struct EdrPageInnerProps {
+  pub offset: usize,
+  pub limit: usize,
+  pub onoffset: Callback<usize>,
+  pub onlimit: Callback<usize>,
+  pub list_query: ListQuery,
+  pub onquery: Callback<ListQuery>,
+  pub onrefresh: Callback<String>,
+  pub ondelete: Callback<String>,
+  pub total_entries: Option<usize>,
+  pub force_refresh: usize,
+}
+
+#[component]
+pub fn EdrPageInner(props: &EdrPageInnerProps) -> HtmlResult {
+  let edc_connector_context = use_edc_connector_context();
+
+  let edr_entry_items = use_future_with(
+    (
+      edc_connector_context,
+      props.limit,
+      props.offset,
+      props.list_query.clone(),
+      props.force_refresh,
+    ),
+    |parameters| async move {
+      let (edc_connector_context, limit, offset, list_query, _) = (*parameters).clone();
+
+      let query = list_query.to_query(offset, limit);
+
+      let Some(client) = edc_connector_context.get_client() else {
+        return Ok(vec![]);
+      };
+
+      let mut edr_entry_items = client
+        .edrs()
+        .query(query)
+        .await
+        .map_err(|error| error_message(&error))?
+        .into_iter()
+        .map(EdrEntryItem::from)
+        .collect::<Vec<_>>();
+
+      // The cache entries don't carry the expiry, only the token in the data address does.
+      let endpoint_data_references =
+        futures::future::join_all(edr_entry_items.iter().map(|edr_entry_item| {
+          get_cached_edr(&edc_connector_context, &edr_entry_item.transfer_process_id)
+        }))
+        .await;
+
+      for (edr_entry_item, endpoint_data_reference) in
+        edr_entry_items.iter_mut().zip(endpoint_data_references)
+      {
+        edr_entry_item.expires_at = endpoint_data_reference
+          .ok()
+          .and_then(|endpoint_data_reference| endpoint_data_reference.expires_at);
+      }
+
+      Ok::<_, String>(edr_entry_items)
+    },
+  )?;
+
+  let (edr_entry_items, error) = match (*edr_entry_items).clone() {
+    Ok(edr_entry_items) => (edr_entry_items, None),
+    Err(error) => (vec![], Some(error)),
+  };
+
+  Ok(html!(
+    <>
+      if let Some(error) = error {
+        <Alert inline=true r#type={AlertType::Danger} title="Unable to load EDRs">
+          { html!(error) }
+        </Alert>
+      }
+      <ListEdrs
+        edr_entry_items={edr_entry_items}
+        offset={props.offset}
+        total_entries={props.total_entries}
+        limit={props.limit}
+        onoffset={props.onoffset.clone()}
+        onlimit={props.onlimit.clone()}
+        list_query={props.list_query.clone()}
+        onquery={props.onquery.clone()}
+        onrefresh={props.onrefresh.clone()}
+        ondelete={props.ondelete.clone()}
+      />
+    </>
+  ))
+}
@@ -0,0 +1,72 @@
+use crate::contexts::use_edc_connector_context;
+use crate::models::PolicyDefinitionItem;
+use patternfly_yew::prelude::*;
+use yew::prelude::*;
+use yew::suspense::use_future_with;
+
+#[derive(Clone, Debug, PartialEq, Properties)]
+pub struct PolicyDefinitionDetailsPageProps {
+  pub id: String,
+}
+
+#[component]
+pub fn PolicyDefinitionDetailsPage(props: &PolicyDefinitionDetailsPageProps) -> Html {
+  html!(
+    <Stack gutter=true>
+      <StackItem>
+        <Title level={Level::H3} size={Size::XXLarge}>{ format!("Policy {}", props.id) }</Title>
+      </StackItem>
+      <StackItem>
+        <Card>
+          <CardBody>
+            <Suspense>
+              <PolicyDefinitionDetailsPageInner id={props.id.clone()} />
+            </Suspense>
+          </CardBody>
+        </Card>
+      </StackItem>
+    </Stack>
+  )
+}
+
+#[component]
+fn PolicyDefinitionDetailsPageInner(props: &PolicyDefinitionDetailsPageProps) -> HtmlResult {
+  let edc_connector_context = use_edc_connector_context();
+
+  let policy_definition_item = use_future_with(
+    (edc_connector_context, props.id.clone()),
+    |parameters| async move {
+      let (edc_connector_context, id) = (*parameters).clone();
+
+      match edc_connector_context.get_client() {
+        Some(client) => client
+          .policies()
+          .get(&id)
+          .await
+          .map(PolicyDefinitionItem::from)
+          .map_err(|error| error.to_string()),
+        None => Err("No connector configured".to_string()),
+      }
+    },
+  )?;
+
+  Ok(match (*policy_definition_item).clone() {
+    Ok(policy_definition_item) => html!(
+      <DescriptionList>
+        <DescriptionGroup term="ID">{ policy_definition_item.id }</DescriptionGroup>
+        <DescriptionGroup term="Kind">{ policy_definition_item.kind }</DescriptionGroup>
+        <DescriptionGroup term="Assignee">
+          { policy_definition_item.assignee.unwrap_or_default() }
+        </DescriptionGroup>
+        <DescriptionGroup term="Assigner">
+          { policy_definition_item.assigner.unwrap_or_default() }
+        </DescriptionGroup>
+      </DescriptionList>
+    ),
+    Err(error) => html!(
+      <Alert inline=true r#type={AlertType::Danger} title="Unable to load policy">
+        { html!(error) }
+      </Alert>
+    ),
+  })
+}
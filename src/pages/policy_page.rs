@@ -1,7 +1,10 @@
 use crate::components::{CreatePolicy, ListPolicies};
-use crate::contexts::use_edc_connector_context;
-use crate::models::PolicyDefinitionItem;
-use edc_connector_client::types::query::Query;
+use crate::contexts::{
+  ActivityOperation, record_activity, use_activity_log_context, use_edc_connector_context,
+};
+use crate::hooks::{use_list_state, use_total_entries};
+use crate::models::{EntityKind, ListQuery, ListState, PolicyDefinitionItem};
+use crate::services::error_message;
 use patternfly_yew::prelude::*;
 use yew::platform::spawn_local;
 use yew::prelude::*;
@@ -11,37 +14,61 @@ use yew::suspense::use_future_with;
 pub fn PolicyPage() -> Html {
   let refresh = use_state(|| 0usize);
   let backdropper = use_backdrop();
-  let offset = use_state(|| 0usize);
-  let limit = use_state(|| 10usize);
+  let list_state = use_list_state();
 
-  let onoffset = use_callback(
-    (refresh.clone(), offset.setter()),
-    |offset, (refresh, offset_setter)| {
-      offset_setter.set(offset);
-      refresh.set(**refresh + 1);
-    },
-  );
+  let onoffset = use_callback(list_state.clone(), |offset, list_state| {
+    list_state.set(ListState {
+      offset,
+      ..(**list_state).clone()
+    });
+  });
 
-  let onlimit = use_callback(
-    (refresh.clone(), limit.setter()),
-    |limit, (refresh, limit_setter)| {
-      limit_setter.set(limit);
-      refresh.set(**refresh + 1);
-    },
+  let onlimit = use_callback(list_state.clone(), |limit, list_state| {
+    list_state.set(ListState {
+      limit,
+      ..(**list_state).clone()
+    });
+  });
+
+  let onquery = use_callback(list_state.clone(), |query, list_state| {
+    list_state.set(ListState {
+      offset: 0,
+      query,
+      ..(**list_state).clone()
+    });
+  });
+
+  let total_entries = use_total_entries(
+    (*refresh, list_state.query.clone()),
+    list_state.query.to_query(0, 0),
+    |client, query| async move { client.policies().query(query).await },
   );
 
   let edc_connector_context = use_edc_connector_context();
+  let activity_log_context = use_activity_log_context();
 
   let ondelete = use_callback(
-    (refresh.clone(), edc_connector_context),
-    |policy_id: String, (refresh, edc_connector_context)| {
+    (
+      refresh.clone(),
+      edc_connector_context.clone(),
+      activity_log_context,
+    ),
+    |policy_id: String, (refresh, edc_connector_context, activity_log_context)| {
       let refresh = refresh.clone();
       let edc_connector_context = edc_connector_context.clone();
+      let activity_log_context = activity_log_context.clone();
       let policy_id = policy_id.clone();
 
       spawn_local(async move {
         if let Some(client) = edc_connector_context.get_client() {
-          let _ = client.policies().delete(&policy_id).await;
+          let result = client.policies().delete(&policy_id).await;
+          record_activity(
+            &activity_log_context,
+            ActivityOperation::Delete,
+            EntityKind::PolicyDefinition,
+            Some(policy_id.clone()),
+            &result,
+          );
           log::warn!("Deleted policy {} - {}", policy_id, *refresh + 1);
           refresh.set(*refresh + 1);
         }
@@ -60,6 +87,48 @@ pub fn PolicyPage() -> Html {
     },
   );
 
+  let duplicate_error = use_state(|| Option::<String>::None);
+
+  let onduplicate = use_callback(
+    (
+      backdropper.clone(),
+      edc_connector_context,
+      on_create.clone(),
+      duplicate_error.clone(),
+    ),
+    |policy_id: String, (backdropper, edc_connector_context, on_create, duplicate_error)| {
+      let backdropper = backdropper.clone();
+      let edc_connector_context = edc_connector_context.clone();
+      let on_create = on_create.clone();
+      let duplicate_error = duplicate_error.clone();
+
+      spawn_local(async move {
+        let Some(client) = edc_connector_context.get_client() else {
+          return;
+        };
+
+        let initial = match client.policies().get(&policy_id).await {
+          Ok(policy_definition) => policy_definition.policy().clone(),
+          Err(error) => {
+            duplicate_error.set(Some(error_message(&error)));
+            return;
+          }
+        };
+
+        duplicate_error.set(None);
+        if let Some(backdropper) = backdropper {
+          backdropper.open(html!(
+            <Bullseye>
+              <Modal variant={ModalVariant::Medium} title="Duplicate a Policy">
+                <CreatePolicy {on_create} {initial} />
+              </Modal>
+            </Bullseye>
+          ))
+        }
+      });
+    },
+  );
+
   let onclick = use_callback((backdropper, on_create), |_, (backdropper, on_create)| {
     if let Some(backdropper) = backdropper {
       backdropper.open(html!(
@@ -84,16 +153,27 @@ pub fn PolicyPage() -> Html {
           </SplitItem>
         </Split>
       </StackItem>
+      if let Some(error) = (*duplicate_error).clone() {
+        <StackItem>
+          <Alert inline=true r#type={AlertType::Danger} title="Could not duplicate">
+            { html!(error) }
+          </Alert>
+        </StackItem>
+      }
       <StackItem>
         <Card>
           <CardBody>
             <Suspense>
               <PolicyPageInner
-                offset={*offset}
-                limit={*limit}
+                offset={list_state.offset}
+                limit={list_state.limit}
                 {onoffset}
                 {onlimit}
+                list_query={list_state.query.clone()}
+                {onquery}
                 {ondelete}
+                {onduplicate}
+                {total_entries}
                 force_refresh={*refresh}
               />
             </Suspense>
@@ -110,7 +190,11 @@ pub struct PolicyPageInnerProps {
   pub limit: usize,
   pub onoffset: Callback<usize>,
   pub onlimit: Callback<usize>,
+  pub list_query: ListQuery,
+  pub onquery: Callback<ListQuery>,
   pub ondelete: Callback<String>,
+  pub onduplicate: Callback<String>,
+  pub total_entries: Option<usize>,
   pub force_refresh: usize,
 }
 
@@ -123,41 +207,56 @@ pub fn PolicyPageInner(props: &PolicyPageInnerProps) -> HtmlResult {
       edc_connector_context,
       props.limit,
       props.offset,
+      props.list_query.clone(),
       props.force_refresh,
     ),
     |parameters| async move {
-      let (edc_connector_context, limit, offset, _) = (*parameters).clone();
-
-      let query = Query::builder()
-        .limit(limit as u32)
-        .offset(offset as u32)
-        .build();
-
-      if let Some(client) = edc_connector_context.get_client() {
-        client
-          .policies()
-          .query(query)
-          .await
-          .unwrap_or_default()
-          .into_iter()
-          .map(PolicyDefinitionItem::from)
-          .collect::<Vec<_>>()
-      } else {
-        vec![]
-      }
+      let (edc_connector_context, limit, offset, list_query, _) = (*parameters).clone();
+
+      let query = list_query.to_query(offset, limit);
+
+      let Some(client) = edc_connector_context.get_client() else {
+        return Ok(vec![]);
+      };
+
+      client
+        .policies()
+        .query(query)
+        .await
+        .map(|entities| {
+          entities
+            .into_iter()
+            .map(PolicyDefinitionItem::from)
+            .collect::<Vec<_>>()
+        })
+        .map_err(|error| error_message(&error))
     },
   )?;
 
-  let policy_definition_items = (*policy_definition_items).clone();
+  let (policy_definition_items, error) = match (*policy_definition_items).clone() {
+    Ok(policy_definition_items) => (policy_definition_items, None),
+    Err(error) => (vec![], Some(error)),
+  };
 
   Ok(html!(
-    <ListPolicies
-      policy_definition_items={policy_definition_items}
-      offset={props.offset}
-      limit={props.limit}
-      onoffset={props.onoffset.clone()}
-      onlimit={props.onlimit.clone()}
-      ondelete={props.ondelete.clone()}
-    />
+    <>
+      if let Some(error) = error {
+        <Alert inline=true r#type={AlertType::Danger} title="Unable to load policies">
+          { html!(error) }
+        </Alert>
+      }
+      <ListPolicies
+        policy_definition_items={policy_definition_items}
+        offset={props.offset}
+        total_entries={props.total_entries}
+        limit={props.limit}
+        onoffset={props.onoffset.clone()}
+        onlimit={props.onlimit.clone()}
+        list_query={props.list_query.clone()}
+        onquery={props.onquery.clone()}
+        ondelete={props.ondelete.clone()}
+        onduplicate={props.onduplicate.clone()}
+      />
+    </>
   ))
 }
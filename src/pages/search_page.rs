@@ -0,0 +1,118 @@
+use crate::contexts::use_edc_connector_context;
+use crate::routes::AppRoute;
+use crate::services::{SearchResultGroup, global_search};
+use patternfly_yew::prelude::*;
+use yew::prelude::*;
+use yew::suspense::use_future_with;
+use yew_nested_router::components::Link;
+
+#[derive(Clone, Debug, PartialEq, Properties)]
+pub struct SearchPageProps {
+  pub term: String,
+}
+
+#[component]
+pub fn SearchPage(props: &SearchPageProps) -> Html {
+  html!(
+    <Stack gutter=true>
+      <StackItem>
+        <Title level={Level::H3} size={Size::XXLarge}>
+          { format!("Search results for \"{}\"", props.term) }
+        </Title>
+      </StackItem>
+      <StackItem>
+        <Suspense>
+          <SearchPageInner term={props.term.clone()} />
+        </Suspense>
+      </StackItem>
+    </Stack>
+  )
+}
+
+#[component]
+fn SearchPageInner(props: &SearchPageProps) -> HtmlResult {
+  let edc_connector_context = use_edc_connector_context();
+
+  let search_result_groups = use_future_with(
+    (edc_connector_context, props.term.clone()),
+    |parameters| async move {
+      let (edc_connector_context, term) = (*parameters).clone();
+
+      match edc_connector_context.get_client() {
+        Some(client) => Ok(global_search(&client, term.trim()).await),
+        None => Err("No connector configured".to_string()),
+      }
+    },
+  )?;
+
+  let search_result_groups = match (*search_result_groups).clone() {
+    Ok(search_result_groups) => search_result_groups,
+    Err(error) => {
+      return Ok(html!(
+        <Alert inline=true r#type={AlertType::Danger} title="Search failed">
+          { html!(error) }
+        </Alert>
+      ));
+    }
+  };
+
+  let search_result_groups = search_result_groups
+    .into_iter()
+    .filter(|search_result_group| {
+      !search_result_group.results.is_empty() || search_result_group.error.is_some()
+    })
+    .collect::<Vec<_>>();
+
+  if search_result_groups.is_empty() {
+    return Ok(html!(
+      <Alert inline=true r#type={AlertType::Info} title="Nothing matches this ID or property value" />
+    ));
+  }
+
+  Ok(html!(
+    <Stack gutter=true>
+      { for search_result_groups.into_iter().map(|search_result_group| html_nested!(
+        <StackItem>
+          <SearchResultGroupCard {search_result_group} />
+        </StackItem>
+      )) }
+    </Stack>
+  ))
+}
+
+#[derive(Clone, Debug, PartialEq, Properties)]
+struct SearchResultGroupCardProps {
+  search_result_group: SearchResultGroup,
+}
+
+#[component]
+fn SearchResultGroupCard(props: &SearchResultGroupCardProps) -> Html {
+  let kind = props.search_result_group.kind;
+
+  html!(
+    <Card>
+      <CardHeader>
+        <CardTitle>
+          { format!("{kind} ({})", props.search_result_group.results.len()) }
+        </CardTitle>
+      </CardHeader>
+      <CardBody>
+        if let Some(error) = props.search_result_group.error.clone() {
+          <Alert inline=true r#type={AlertType::Warning} title="Some lookups failed">
+            { html!(error) }
+          </Alert>
+        }
+        <List>
+          { for props.search_result_group.results.iter().map(|search_result| html_nested!(
+            <ListItem>
+              <Link<AppRoute> to={kind.route(search_result.id.clone())}>
+                { search_result.id.clone() }
+              </Link<AppRoute>>
+              { format!(" — matched {}", search_result.matched.join(", ")) }
+            </ListItem>
+          )) }
+        </List>
+      </CardBody>
+    </Card>
+  )
+}
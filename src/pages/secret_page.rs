@@ -0,0 +1,240 @@
+use crate::components::{ListSecrets, SecretForm};
+use crate::contexts::{
+  ActivityOperation, record_activity, use_activity_log_context, use_edc_connector_context,
+};
+use crate::models::{EntityKind, SecretItem};
+use crate::services::{error_message, secret_exists, secret_references};
+use patternfly_yew::prelude::*;
+use yew::platform::spawn_local;
+use yew::prelude::*;
+use yew::suspense::use_future_with;
+
+#[component]
+pub fn SecretPage() -> Html {
+  let refresh = use_state(|| 0usize);
+  let backdropper = use_backdrop();
+  // Keys created or looked up here; the vault itself can't be listed.
+  let known_ids = use_state(Vec::<String>::new);
+  let lookup_id = use_state(String::new);
+
+  let edc_connector_context = use_edc_connector_context();
+  let activity_log_context = use_activity_log_context();
+
+  let on_save = use_callback(
+    (backdropper.clone(), refresh.clone(), known_ids.clone()),
+    |id: String, (backdropper, refresh, known_ids)| {
+      if let Some(backdropper) = backdropper {
+        backdropper.close();
+      }
+
+      if !known_ids.contains(&id) {
+        let mut ids = (**known_ids).clone();
+        ids.push(id);
+        known_ids.set(ids);
+      }
+
+      refresh.set(**refresh + 1);
+    },
+  );
+
+  let onclick = use_callback(
+    (backdropper.clone(), on_save.clone()),
+    |_, (backdropper, on_save)| {
+      if let Some(backdropper) = backdropper {
+        backdropper.open(html!(
+          <Bullseye>
+            <Modal variant={ModalVariant::Small} title="Create a Secret">
+              <SecretForm on_save={on_save.clone()} />
+            </Modal>
+          </Bullseye>
+        ))
+      }
+    },
+  );
+
+  let onedit = use_callback(
+    (backdropper, on_save),
+    |secret_item: SecretItem, (backdropper, on_save)| {
+      if let Some(backdropper) = backdropper {
+        backdropper.open(html!(
+          <Bullseye>
+            <Modal variant={ModalVariant::Small} title="Set Secret Value">
+              <SecretForm
+                secret_id={secret_item.id}
+                stored={secret_item.stored != Some(false)}
+                on_save={on_save.clone()}
+              />
+            </Modal>
+          </Bullseye>
+        ))
+      }
+    },
+  );
+
+  let ondelete = use_callback(
+    (refresh.clone(), edc_connector_context, activity_log_context),
+    |secret_id: String, (refresh, edc_connector_context, activity_log_context)| {
+      let refresh = refresh.clone();
+      let edc_connector_context = edc_connector_context.clone();
+      let activity_log_context = activity_log_context.clone();
+
+      spawn_local(async move {
+        if let Some(client) = edc_connector_context.get_client() {
+          let result = client.secrets().delete(&secret_id).await;
+          record_activity(
+            &activity_log_context,
+            ActivityOperation::Delete,
+            EntityKind::Secret,
+            Some(secret_id),
+            &result,
+          );
+        }
+        refresh.set(*refresh + 1);
+      });
+    },
+  );
+
+  let onchange_lookup_id = use_callback(lookup_id.setter(), |value, lookup_id_setter| {
+    lookup_id_setter.set(value);
+  });
+
+  let onlookup = use_callback(
+    (lookup_id.clone(), known_ids.clone()),
+    |_, (lookup_id, known_ids)| {
+      let id = lookup_id.trim().to_string();
+
+      if !id.is_empty() && !known_ids.contains(&id) {
+        let mut ids = (**known_ids).clone();
+        ids.push(id);
+        known_ids.set(ids);
+      }
+
+      lookup_id.set(String::new());
+    },
+  );
+
+  html!(
+    <Stack gutter=true>
+      <StackItem>
+        <Split gutter=true>
+          <SplitItem fill=true>
+            <Title level={Level::H3} size={Size::XXLarge}>{ "List Secrets" }</Title>
+          </SplitItem>
+          <SplitItem>
+            <Button icon={Icon::Plus} {onclick} variant={ButtonVariant::Primary}>{ "Add" }</Button>
+          </SplitItem>
+        </Split>
+      </StackItem>
+      <StackItem>
+        <Card>
+          <CardBody>
+            <Stack gutter=true>
+              <StackItem>
+                <Alert
+                  inline=true
+                  plain=true
+                  r#type={AlertType::Info}
+                  title="The management API can't list the vault. Shown are the secrets referenced by assets, plus any key created or looked up here."
+                />
+              </StackItem>
+              <StackItem>
+                <Toolbar>
+                  <ToolbarContent>
+                    <ToolbarItem>
+                      <TextInput
+                        placeholder="Secret key"
+                        value={(*lookup_id).clone()}
+                        onchange={onchange_lookup_id}
+                      />
+                    </ToolbarItem>
+                    <ToolbarItem>
+                      <Button variant={ButtonVariant::Secondary} onclick={onlookup}>
+                        { "Look Up" }
+                      </Button>
+                    </ToolbarItem>
+                  </ToolbarContent>
+                </Toolbar>
+              </StackItem>
+              <StackItem>
+                <Suspense>
+                  <SecretPageInner
+                    known_ids={(*known_ids).clone()}
+                    {onedit}
+                    {ondelete}
+                    force_refresh={*refresh}
+                  />
+                </Suspense>
+              </StackItem>
+            </Stack>
+          </CardBody>
+        </Card>
+      </StackItem>
+    </Stack>
+  )
+}
+
+#[derive(Clone, Debug, PartialEq, Properties)]
+pub struct SecretPageInnerProps {
+  pub known_ids: Vec<String>,
+  pub onedit: Callback<SecretItem>,
+  pub ondelete: Callback<String>,
+  pub force_refresh: usize,
+}
+
+#[component]
+pub fn SecretPageInner(props: &SecretPageInnerProps) -> HtmlResult {
+  let edc_connector_context = use_edc_connector_context();
+
+  let secret_items = use_future_with(
+    (
+      edc_connector_context,
+      props.known_ids.clone(),
+      props.force_refresh,
+    ),
+    |parameters| async move {
+      let (edc_connector_context, known_ids, _) = (*parameters).clone();
+
+      let Some(client) = edc_connector_context.get_client() else {
+        return Ok(vec![]);
+      };
+
+      let mut references = secret_references(&client)
+        .await
+        .map_err(|error| error_message(&error))?;
+
+      for id in known_ids {
+        references.entry(id).or_default();
+      }
+
+      let stored =
+        futures::future::join_all(references.keys().map(|id| secret_exists(&client, id))).await;
+
+      Ok::<_, String>(
+        references
+          .into_iter()
+          .zip(stored)
+          .map(|((id, referenced_by), stored)| SecretItem {
+            id,
+            stored: stored.ok(),
+            referenced_by,
+          })
+          .collect::<Vec<_>>(),
+      )
+    },
+  )?;
+
+  Ok(match (*secret_items).clone() {
+    Ok(secret_items) => html!(
+      <ListSecrets
+        {secret_items}
+        onedit={props.onedit.clone()}
+        ondelete={props.ondelete.clone()}
+      />
+    ),
+    Err(error) => html!(
+      <Alert inline=true r#type={AlertType::Danger} title="Could not read the asset references">
+        { html!(error) }
+      </Alert>
+    ),
+  })
+}
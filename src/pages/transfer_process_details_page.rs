@@ -0,0 +1,304 @@
+use crate::components::{
+  AutoRefreshToggle, EdrPanel, TransferProcessAction, TransferProcessActionForm, WatchButton,
+};
+use crate::contexts::{WatchedKind, use_edc_connector_context};
+use crate::hooks::use_auto_refresh;
+use crate::models::{AutoRefresh, TransferProcessDetails};
+use crate::routes::AppRoute;
+use crate::services::get_raw_entity;
+use edc_connector_client::types::transfer_process::{
+  TransferProcessKind as EdcTransferProcessKind, TransferProcessState as EdcTransferProcessState,
+};
+use patternfly_yew::prelude::*;
+use serde_json::Value;
+use yew::prelude::*;
+use yew::suspense::use_future_with;
+use yew_nested_router::components::Link;
+
+#[derive(Clone, Debug, PartialEq, Properties)]
+pub struct TransferProcessDetailsPageProps {
+  pub id: String,
+}
+
+#[component]
+pub fn TransferProcessDetailsPage(props: &TransferProcessDetailsPageProps) -> Html {
+  let backdropper = use_backdrop();
+  let refresh = use_state(|| 0usize);
+  let state = use_state(|| Option::<EdcTransferProcessState>::None);
+  // Only consumers of HTTP pull transfers receive an EDR.
+  let is_consumer_pull = use_state(|| false);
+  let auto_refresh = use_state(AutoRefresh::default);
+
+  let onrefresh = use_callback(refresh.clone(), |_, refresh| {
+    refresh.set(**refresh + 1);
+  });
+
+  use_auto_refresh(auto_refresh.active_interval(), onrefresh);
+
+  let onchange_auto_refresh = use_callback(
+    auto_refresh.setter(),
+    |auto_refresh, auto_refresh_setter| {
+      auto_refresh_setter.set(auto_refresh);
+    },
+  );
+
+  // Taken from the details below rather than fetching the transfer process again.
+  let onload = use_callback(
+    (state.clone(), is_consumer_pull.clone()),
+    |loaded: Option<(EdcTransferProcessState, bool)>, (state, is_consumer_pull)| {
+      let (loaded_state, loaded_is_consumer_pull) = loaded.unzip();
+
+      state.set(loaded_state);
+      is_consumer_pull.set(loaded_is_consumer_pull.unwrap_or_default());
+    },
+  );
+
+  let on_done = use_callback(
+    (backdropper.clone(), refresh.clone()),
+    |_, (backdropper, refresh)| {
+      if let Some(backdropper) = backdropper {
+        backdropper.close();
+      }
+
+      refresh.set(**refresh + 1);
+    },
+  );
+
+  let onaction = use_callback(
+    (backdropper, props.id.clone(), on_done),
+    |action: TransferProcessAction, (backdropper, transfer_process_id, on_done)| {
+      if let Some(backdropper) = backdropper {
+        backdropper.open(html!(
+          <Bullseye>
+            <Modal variant={ModalVariant::Small} title={format!("{action} Transfer Process")}>
+              <TransferProcessActionForm
+                transfer_process_id={transfer_process_id.clone()}
+                {action}
+                on_done={on_done.clone()}
+              />
+            </Modal>
+          </Bullseye>
+        ))
+      }
+    },
+  );
+
+  let (can_suspend, can_resume, can_terminate) = match &*state {
+    Some(state) => (
+      matches!(
+        state,
+        EdcTransferProcessState::Started | EdcTransferProcessState::Resumed
+      ),
+      matches!(state, EdcTransferProcessState::Suspended),
+      !matches!(
+        state,
+        EdcTransferProcessState::Completing
+          | EdcTransferProcessState::Completed
+          | EdcTransferProcessState::Terminating
+          | EdcTransferProcessState::Terminated
+          | EdcTransferProcessState::Deprovisioning
+          | EdcTransferProcessState::DeprovisioningRequested
+          | EdcTransferProcessState::Deprovisioned
+      ),
+    ),
+    None => (false, false, false),
+  };
+
+  html!(
+    <Stack gutter=true>
+      <StackItem>
+        <Split gutter=true>
+          <SplitItem fill=true>
+            <Title level={Level::H3} size={Size::XXLarge}>
+              { format!("Transfer Process {}", props.id) }
+            </Title>
+          </SplitItem>
+          <SplitItem>
+            <AutoRefreshToggle value={*auto_refresh} onchange={onchange_auto_refresh} />
+          </SplitItem>
+          <SplitItem>
+            <WatchButton kind={WatchedKind::TransferProcess} id={props.id.clone()} />
+          </SplitItem>
+          <SplitItem>
+            <Button
+              icon={Icon::Pause}
+              variant={ButtonVariant::Secondary}
+              disabled={!can_suspend}
+              onclick={onaction.reform(|_| TransferProcessAction::Suspend)}
+            >
+              { "Suspend" }
+            </Button>
+          </SplitItem>
+          <SplitItem>
+            <Button
+              icon={Icon::Play}
+              variant={ButtonVariant::Secondary}
+              disabled={!can_resume}
+              onclick={onaction.reform(|_| TransferProcessAction::Resume)}
+            >
+              { "Resume" }
+            </Button>
+          </SplitItem>
+          <SplitItem>
+            <Button
+              icon={Icon::Times}
+              variant={ButtonVariant::Danger}
+              disabled={!can_terminate}
+              onclick={onaction.reform(|_| TransferProcessAction::Terminate)}
+            >
+              { "Terminate" }
+            </Button>
+          </SplitItem>
+        </Split>
+      </StackItem>
+      <StackItem>
+        <Card>
+          <CardBody>
+            <Suspense>
+              <TransferProcessDetailsPageInner
+                id={props.id.clone()}
+                force_refresh={*refresh}
+                {onload}
+              />
+            </Suspense>
+          </CardBody>
+        </Card>
+      </StackItem>
+      if *is_consumer_pull && matches!(*state, Some(EdcTransferProcessState::Started)) {
+        <StackItem>
+          <Card>
+            <CardTitle>{ "Endpoint Data Reference" }</CardTitle>
+            <CardBody>
+              <Suspense>
+                <EdrPanel transfer_process_id={props.id.clone()} force_refresh={*refresh} />
+              </Suspense>
+            </CardBody>
+          </Card>
+        </StackItem>
+      }
+    </Stack>
+  )
+}
+
+#[derive(Clone, Debug, PartialEq, Properties)]
+struct TransferProcessDetailsPageInnerProps {
+  id: String,
+  force_refresh: usize,
+  /// Receives the state and whether this is a consumer pull transfer, `None` if loading failed.
+  onload: Callback<Option<(EdcTransferProcessState, bool)>>,
+}
+
+#[component]
+fn TransferProcessDetailsPageInner(props: &TransferProcessDetailsPageInnerProps) -> HtmlResult {
+  let edc_connector_context = use_edc_connector_context();
+
+  let transfer_process = use_future_with(
+    (edc_connector_context, props.id.clone(), props.force_refresh),
+    |parameters| async move {
+      let (edc_connector_context, id, _) = (*parameters).clone();
+
+      let Some(client) = edc_connector_context.get_client() else {
+        return Err("No connector configured".to_string());
+      };
+
+      let transfer_processes = client.transfer_processes();
+      let (transfer_process, raw) = futures::join!(
+        transfer_processes.get(&id),
+        get_raw_entity(&edc_connector_context, "transferprocesses", &id)
+      );
+
+      let transfer_process = transfer_process.map_err(|error| error.to_string())?;
+      let state = transfer_process.state().clone();
+      let is_consumer_pull = matches!(transfer_process.kind(), EdcTransferProcessKind::Consumer)
+        && transfer_process.transfer_type() == "HttpData-PULL";
+      let local_asset = client
+        .assets()
+        .get(transfer_process.asset_id())
+        .await
+        .is_ok();
+
+      Ok((
+        TransferProcessDetails::new(transfer_process, &raw.unwrap_or(Value::Null)),
+        local_asset,
+        (state, is_consumer_pull),
+      ))
+    },
+  )?;
+
+  let loaded = transfer_process
+    .as_ref()
+    .ok()
+    .map(|(_, _, loaded)| loaded.clone());
+
+  use_effect_with((loaded, props.onload.clone()), |(loaded, onload)| {
+    onload.emit(loaded.clone());
+  });
+
+  Ok(match (*transfer_process).clone() {
+    Ok((details, local_asset, _)) => {
+      let transfer_process_item = details.item;
+      let contract_id = transfer_process_item.contract_id.clone();
+      let asset_id = transfer_process_item.asset_id.clone();
+
+      let asset = if local_asset {
+        html!(<Link<AppRoute> to={AppRoute::Asset { id: asset_id.clone() }}>{ asset_id }</Link<AppRoute>>)
+      } else {
+        html!(asset_id)
+      };
+
+      html!(
+        <DescriptionList>
+          <DescriptionGroup term="ID">{ transfer_process_item.id }</DescriptionGroup>
+          <DescriptionGroup term="State">{ transfer_process_item.state }</DescriptionGroup>
+          if let Some(error_detail) = details.error_detail {
+            <DescriptionGroup term="Error Detail">
+              <Alert inline=true plain=true r#type={AlertType::Danger} title={error_detail} />
+            </DescriptionGroup>
+          }
+          <DescriptionGroup term="State Timestamp">{ details.state_timestamp }</DescriptionGroup>
+          <DescriptionGroup term="Kind">{ transfer_process_item.kind }</DescriptionGroup>
+          <DescriptionGroup term="Transfer Type">
+            { transfer_process_item.transfer_type }
+          </DescriptionGroup>
+          <DescriptionGroup term="Correlation ID">
+            { details.correlation_id.unwrap_or_default() }
+          </DescriptionGroup>
+          <DescriptionGroup term="Asset ID">{ asset }</DescriptionGroup>
+          <DescriptionGroup term="Contract Agreement">
+            <Link<AppRoute> to={AppRoute::ContractAgreement { id: contract_id.clone() }}>
+              { contract_id }
+            </Link<AppRoute>>
+          </DescriptionGroup>
+          <DescriptionGroup term="Data Destination">
+            { properties_list(&details.data_destination) }
+          </DescriptionGroup>
+          <DescriptionGroup term="Private Properties">
+            { properties_list(&details.private_properties) }
+          </DescriptionGroup>
+          <DescriptionGroup term="Callback Addresses">
+            <List>
+              { for details.callback_addresses.iter().map(|callback_address| html_nested!(
+                <ListItem>{ callback_address.to_string() }</ListItem>
+              )) }
+            </List>
+          </DescriptionGroup>
+        </DescriptionList>
+      )
+    }
+    Err(error) => html!(
+      <Alert inline=true r#type={AlertType::Danger} title="Unable to load transfer process">
+        { html!(error) }
+      </Alert>
+    ),
+  })
+}
+
+fn properties_list(properties: &[(String, String)]) -> Html {
+  html!(
+    <List>
+      { for properties.iter().map(|(name, value)| html_nested!(
+        <ListItem>{ format!("{name}: {value}") }</ListItem>
+      )) }
+    </List>
+  )
+}
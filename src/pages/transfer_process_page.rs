@@ -1,7 +1,8 @@
-use crate::components::ListTransferProcesses;
+use crate::components::{AutoRefreshToggle, ListTransferProcesses};
 use crate::contexts::use_edc_connector_context;
-use crate::models::TransferProcessItem;
-use edc_connector_client::types::query::Query;
+use crate::hooks::{use_auto_refresh, use_list_state, use_total_entries};
+use crate::models::{AutoRefresh, ListQuery, ListState, TransferProcessItem};
+use crate::services::error_message;
 use patternfly_yew::prelude::*;
 use yew::prelude::*;
 use yew::suspense::use_future_with;
@@ -9,39 +10,74 @@ use yew::suspense::use_future_with;
 #[component]
 pub fn TransferProcessPage() -> Html {
   let refresh = use_state(|| 0usize);
-  let offset = use_state(|| 0usize);
-  let limit = use_state(|| 10usize);
-
-  let onoffset = use_callback(
-    (refresh.clone(), offset.setter()),
-    |offset, (refresh, offset_setter)| {
-      offset_setter.set(offset);
-      refresh.set(**refresh + 1);
+  let list_state = use_list_state();
+  let auto_refresh = use_state(AutoRefresh::default);
+
+  let onrefresh = use_callback(refresh.clone(), |_, refresh| {
+    refresh.set(**refresh + 1);
+  });
+
+  use_auto_refresh(auto_refresh.active_interval(), onrefresh);
+
+  let onchange_auto_refresh = use_callback(
+    auto_refresh.setter(),
+    |auto_refresh, auto_refresh_setter| {
+      auto_refresh_setter.set(auto_refresh);
     },
   );
 
-  let onlimit = use_callback(
-    (refresh.clone(), limit.setter()),
-    |limit, (refresh, limit_setter)| {
-      limit_setter.set(limit);
-      refresh.set(**refresh + 1);
-    },
+  let onoffset = use_callback(list_state.clone(), |offset, list_state| {
+    list_state.set(ListState {
+      offset,
+      ..(**list_state).clone()
+    });
+  });
+
+  let onlimit = use_callback(list_state.clone(), |limit, list_state| {
+    list_state.set(ListState {
+      limit,
+      ..(**list_state).clone()
+    });
+  });
+
+  let onquery = use_callback(list_state.clone(), |query, list_state| {
+    list_state.set(ListState {
+      offset: 0,
+      query,
+      ..(**list_state).clone()
+    });
+  });
+
+  let total_entries = use_total_entries(
+    list_state.query.clone(),
+    list_state.query.to_query(0, 0),
+    |client, query| async move { client.transfer_processes().query(query).await },
   );
 
   html!(
     <Stack gutter=true>
       <StackItem>
-        <Title level={Level::H3} size={Size::XXLarge}>{ "List Transfer Processes" }</Title>
+        <Split gutter=true>
+          <SplitItem fill=true>
+            <Title level={Level::H3} size={Size::XXLarge}>{ "List Transfer Processes" }</Title>
+          </SplitItem>
+          <SplitItem>
+            <AutoRefreshToggle value={*auto_refresh} onchange={onchange_auto_refresh} />
+          </SplitItem>
+        </Split>
       </StackItem>
       <StackItem>
         <Card>
           <CardBody>
             <Suspense>
               <TransferProcessPageInner
-                offset={*offset}
-                limit={*limit}
+                offset={list_state.offset}
+                limit={list_state.limit}
                 {onoffset}
                 {onlimit}
+                list_query={list_state.query.clone()}
+                {onquery}
+                {total_entries}
                 force_refresh={*refresh}
               />
             </Suspense>
@@ -58,6 +94,9 @@ pub struct TransferProcessPageInnerProps {
   pub limit: usize,
   pub onoffset: Callback<usize>,
   pub onlimit: Callback<usize>,
+  pub list_query: ListQuery,
+  pub onquery: Callback<ListQuery>,
+  pub total_entries: Option<usize>,
   pub force_refresh: usize,
 }
 
@@ -70,40 +109,54 @@ pub fn TransferProcessPageInner(props: &TransferProcessPageInnerProps) -> HtmlRe
       edc_connector_context,
       props.limit,
       props.offset,
+      props.list_query.clone(),
       props.force_refresh,
     ),
     |parameters| async move {
-      let (edc_connector_context, limit, offset, _) = (*parameters).clone();
-
-      let query = Query::builder()
-        .limit(limit as u32)
-        .offset(offset as u32)
-        .build();
-
-      if let Some(client) = edc_connector_context.get_client() {
-        client
-          .transfer_processes()
-          .query(query)
-          .await
-          .unwrap_or_default()
-          .into_iter()
-          .map(TransferProcessItem::from)
-          .collect::<Vec<_>>()
-      } else {
-        vec![]
-      }
+      let (edc_connector_context, limit, offset, list_query, _) = (*parameters).clone();
+
+      let query = list_query.to_query(offset, limit);
+
+      let Some(client) = edc_connector_context.get_client() else {
+        return Ok(vec![]);
+      };
+
+      client
+        .transfer_processes()
+        .query(query)
+        .await
+        .map(|entities| {
+          entities
+            .into_iter()
+            .map(TransferProcessItem::from)
+            .collect::<Vec<_>>()
+        })
+        .map_err(|error| error_message(&error))
     },
   )?;
 
-  let transfer_processe_items = (*transfer_processe_items).clone();
+  let (transfer_processe_items, error) = match (*transfer_processe_items).clone() {
+    Ok(transfer_processe_items) => (transfer_processe_items, None),
+    Err(error) => (vec![], Some(error)),
+  };
 
   Ok(html!(
-    <ListTransferProcesses
-      transfer_processe_items={transfer_processe_items}
-      offset={props.offset}
-      limit={props.limit}
-      onoffset={props.onoffset.clone()}
-      onlimit={props.onlimit.clone()}
-    />
+    <>
+      if let Some(error) = error {
+        <Alert inline=true r#type={AlertType::Danger} title="Unable to load transfer processes">
+          { html!(error) }
+        </Alert>
+      }
+      <ListTransferProcesses
+        transfer_processe_items={transfer_processe_items}
+        offset={props.offset}
+        total_entries={props.total_entries}
+        limit={props.limit}
+        onoffset={props.onoffset.clone()}
+        onlimit={props.onlimit.clone()}
+        list_query={props.list_query.clone()}
+        onquery={props.onquery.clone()}
+      />
+    </>
   ))
 }
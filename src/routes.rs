@@ -0,0 +1,43 @@
+use yew_nested_router::Target;
+
+#[derive(Debug, Clone, PartialEq, Eq, Target)]
+pub enum AppRoute {
+  #[target(rename = "dashboard")]
+  Dashboard,
+  #[target(rename = "assets")]
+  Assets,
+  #[target(rename = "assets")]
+  Asset { id: String },
+  #[target(rename = "policies")]
+  Policies,
+  #[target(rename = "policies")]
+  PolicyDefinition { id: String },
+  #[target(rename = "contract-definitions")]
+  ContractDefinitions,
+  #[target(rename = "contract-definitions")]
+  ContractDefinition { id: String },
+  #[target(rename = "contract-negotiations")]
+  ContractNegotiations,
+  #[target(rename = "contract-negotiations")]
+  ContractNegotiation { id: String },
+  #[target(rename = "contract-agreements")]
+  ContractAgreements,
+  #[target(rename = "contract-agreements")]
+  ContractAgreement { id: String },
+  #[target(rename = "transfer-processes")]
+  TransferProcesses,
+  #[target(rename = "transfer-processes")]
+  TransferProcess { id: String },
+  #[target(rename = "secrets")]
+  Secrets,
+  #[target(rename = "edrs")]
+  Edrs,
+  #[target(rename = "data-planes")]
+  DataPlanes,
+  #[target(rename = "backup")]
+  Backup,
+  #[target(rename = "compare")]
+  Compare,
+  #[target(rename = "search")]
+  Search { term: String },
+}
@@ -0,0 +1,29 @@
+mod browser_notification;
+mod browser_response;
+mod connector_backup;
+mod connector_diff;
+mod connector_overview;
+mod edr_cache;
+mod edr_request;
+mod error_message;
+mod global_search;
+mod query_all;
+mod raw_entity;
+mod secrets;
+mod source_test;
+mod transfer_types;
+
+pub use browser_notification::*;
+pub use browser_response::*;
+pub use connector_backup::*;
+pub use connector_diff::*;
+pub use connector_overview::*;
+pub use edr_cache::*;
+pub use edr_request::*;
+pub use error_message::*;
+pub use global_search::*;
+pub use query_all::*;
+pub use raw_entity::*;
+pub use secrets::*;
+pub use source_test::*;
+pub use transfer_types::*;
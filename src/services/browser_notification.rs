@@ -0,0 +1,27 @@
+use web_sys::js_sys::Reflect;
+use web_sys::wasm_bindgen::JsValue;
+use web_sys::{Notification, NotificationOptions, NotificationPermission};
+
+fn browser_notifications_supported() -> bool {
+  Reflect::has(&gloo_utils::window(), &JsValue::from_str("Notification")).unwrap_or_default()
+}
+
+/// Asks for permission the first time; the browser remembers the answer afterwards.
+pub fn request_browser_notification_permission() {
+  if browser_notifications_supported()
+    && Notification::permission() == NotificationPermission::Default
+  {
+    let _ = Notification::request_permission();
+  }
+}
+
+pub fn show_browser_notification(title: &str, body: &str) {
+  if browser_notifications_supported()
+    && Notification::permission() == NotificationPermission::Granted
+  {
+    let options = NotificationOptions::new();
+    options.set_body(body);
+
+    let _ = Notification::new_with_options(title, &options);
+  }
+}
@@ -0,0 +1,53 @@
+use futures::StreamExt;
+
+/// Responses larger than this are cut off before being shown.
+const MAX_BODY_LENGTH: usize = 64 * 1024;
+
+/// A response to a request made from the browser, e.g. against an EDR or an asset's source.
+#[derive(Clone, Debug, PartialEq)]
+pub struct BrowserResponse {
+  pub status: String,
+  pub content_type: Option<String>,
+  pub body: String,
+  pub truncated: bool,
+}
+
+/// Reads a response for display, cutting off bodies after 64 KiB.
+pub async fn read_response(response: reqwest::Response) -> Result<BrowserResponse, String> {
+  let status = response.status().to_string();
+  let content_type = response
+    .headers()
+    .get("content-type")
+    .and_then(|content_type| content_type.to_str().ok())
+    .map(|content_type| content_type.to_string());
+
+  // Stop reading at the limit instead of downloading the whole payload into the browser.
+  let mut stream = response.bytes_stream();
+  let mut bytes = Vec::new();
+  let mut truncated = false;
+  while let Some(chunk) = stream.next().await {
+    bytes.extend_from_slice(&chunk.map_err(|error| error.to_string())?);
+
+    if bytes.len() > MAX_BODY_LENGTH {
+      bytes.truncate(MAX_BODY_LENGTH);
+      truncated = true;
+      break;
+    }
+  }
+
+  // Drop a character the cut went through rather than showing it as garbage.
+  if truncated
+    && let Err(error) = std::str::from_utf8(&bytes)
+    && error.error_len().is_none()
+  {
+    bytes.truncate(error.valid_up_to());
+  }
+  let body = String::from_utf8_lossy(&bytes).into_owned();
+
+  Ok(BrowserResponse {
+    status,
+    content_type,
+    body,
+    truncated,
+  })
+}
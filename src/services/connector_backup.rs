@@ -0,0 +1,373 @@
+use super::{compact_property_name, query_all};
+use crate::models::EntityKind;
+use edc_connector_client::types::asset::{Asset, NewAsset};
+use edc_connector_client::types::contract_definition::{ContractDefinition, NewContractDefinition};
+use edc_connector_client::types::policy::{NewPolicyDefinition, PolicyDefinition};
+use edc_connector_client::types::query::Query;
+use edc_connector_client::{EdcConnectorClient, EdcResult, Error};
+use reqwest::StatusCode;
+use serde::de::DeserializeOwned;
+use serde::{Deserialize, Serialize};
+use serde_json::Value;
+use std::collections::HashMap;
+use std::future::Future;
+
+pub const BACKUP_VERSION: u32 = 1;
+
+#[derive(Clone, Debug, Deserialize, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct ConnectorBackup {
+  pub version: u32,
+  pub exported_at: String,
+  pub policy_definitions: Vec<PolicyDefinition>,
+  pub assets: Vec<Asset>,
+  pub contract_definitions: Vec<ContractDefinition>,
+}
+
+impl ConnectorBackup {
+  pub async fn export(client: &EdcConnectorClient) -> EdcResult<ConnectorBackup> {
+    let policy_definitions = query_all(Query::default(), |query| {
+      let client = client.clone();
+      async move { client.policies().query(query).await }
+    })
+    .await?;
+
+    let assets = query_all(Query::default(), |query| {
+      let client = client.clone();
+      async move { client.assets().query(query).await }
+    })
+    .await?;
+
+    let contract_definitions = query_all(Query::default(), |query| {
+      let client = client.clone();
+      async move { client.contract_definitions().query(query).await }
+    })
+    .await?;
+
+    Ok(ConnectorBackup {
+      version: BACKUP_VERSION,
+      exported_at: chrono::Utc::now().to_rfc3339(),
+      policy_definitions,
+      assets,
+      contract_definitions,
+    })
+  }
+
+  pub fn parse(input: &str) -> Result<ConnectorBackup, String> {
+    let backup =
+      serde_json::from_str::<ConnectorBackup>(input).map_err(|error| error.to_string())?;
+
+    if backup.version > BACKUP_VERSION {
+      return Err(format!(
+        "unsupported backup version {} (expected at most {BACKUP_VERSION})",
+        backup.version
+      ));
+    }
+
+    Ok(backup)
+  }
+}
+
+#[derive(Copy, Clone, Debug, PartialEq, Eq, strum::Display)]
+pub enum ConflictStrategy {
+  Skip,
+  Overwrite,
+  Rename,
+}
+
+#[derive(Clone, Debug, PartialEq)]
+pub enum RestoreOutcome {
+  Created,
+  Overwritten,
+  Renamed(String),
+  Skipped,
+  Failed(String),
+  /// The entry existed and could not be overwritten.
+  OverwriteFailed(String),
+}
+
+#[derive(Clone, Debug, PartialEq)]
+pub struct RestoreEntry {
+  pub kind: EntityKind,
+  pub id: String,
+  pub outcome: RestoreOutcome,
+}
+
+/// Replays a backup into a connector in dependency order: policy definitions, then assets, then
+/// contract definitions, whose policy and asset references follow any renames made on the way.
+pub async fn restore(
+  client: &EdcConnectorClient,
+  backup: &ConnectorBackup,
+  strategy: ConflictStrategy,
+  on_entry: impl Fn(RestoreEntry),
+) -> Vec<RestoreEntry> {
+  let mut entries = vec![];
+  let mut renamed_policies = HashMap::new();
+  let mut renamed_assets = HashMap::new();
+
+  let mut record = |entry: RestoreEntry| {
+    on_entry(entry.clone());
+    entries.push(entry);
+  };
+
+  for policy_definition in &backup.policy_definitions {
+    let id = policy_definition.id();
+    let outcome = match (exists(client.policies().get(id)).await, strategy) {
+      (Err(error), _) => RestoreOutcome::Failed(error),
+      (Ok(false), _) => create_policy_definition(client, policy_definition, id).await,
+      (Ok(true), ConflictStrategy::Skip) => RestoreOutcome::Skipped,
+      (Ok(true), ConflictStrategy::Overwrite) => {
+        overwritten(client.policies().update(policy_definition).await)
+      }
+      (Ok(true), ConflictStrategy::Rename) => {
+        let new_id = free_id(id, |candidate| async move {
+          exists(client.policies().get(&candidate)).await
+        })
+        .await;
+
+        match new_id {
+          Ok(new_id) => match create_policy_definition(client, policy_definition, &new_id).await {
+            RestoreOutcome::Created => {
+              renamed_policies.insert(id.to_string(), new_id.clone());
+              RestoreOutcome::Renamed(new_id)
+            }
+            outcome => outcome,
+          },
+          Err(error) => RestoreOutcome::Failed(error),
+        }
+      }
+    };
+
+    record(RestoreEntry {
+      kind: EntityKind::PolicyDefinition,
+      id: id.to_string(),
+      outcome,
+    });
+  }
+
+  for asset in &backup.assets {
+    let id = asset.id();
+    let outcome = match (exists(client.assets().get(id)).await, strategy) {
+      (Err(error), _) => RestoreOutcome::Failed(error),
+      (Ok(false), _) => create_asset(client, asset, id).await,
+      (Ok(true), ConflictStrategy::Skip) => RestoreOutcome::Skipped,
+      (Ok(true), ConflictStrategy::Overwrite) => overwritten(client.assets().update(asset).await),
+      (Ok(true), ConflictStrategy::Rename) => {
+        let new_id = free_id(id, |candidate| async move {
+          exists(client.assets().get(&candidate)).await
+        })
+        .await;
+
+        match new_id {
+          Ok(new_id) => match create_asset(client, asset, &new_id).await {
+            RestoreOutcome::Created => {
+              renamed_assets.insert(id.to_string(), new_id.clone());
+              RestoreOutcome::Renamed(new_id)
+            }
+            outcome => outcome,
+          },
+          Err(error) => RestoreOutcome::Failed(error),
+        }
+      }
+    };
+
+    record(RestoreEntry {
+      kind: EntityKind::Asset,
+      id: id.to_string(),
+      outcome,
+    });
+  }
+
+  for contract_definition in &backup.contract_definitions {
+    let id = contract_definition.id();
+
+    let outcome =
+      match remap_contract_definition(contract_definition, &renamed_policies, &renamed_assets) {
+        Ok(contract_definition) => {
+          match (
+            exists(client.contract_definitions().get(id)).await,
+            strategy,
+          ) {
+            (Err(error), _) => RestoreOutcome::Failed(error),
+            (Ok(false), _) => create_contract_definition(client, &contract_definition, id).await,
+            (Ok(true), ConflictStrategy::Skip) => RestoreOutcome::Skipped,
+            (Ok(true), ConflictStrategy::Overwrite) => overwritten(
+              client
+                .contract_definitions()
+                .update(&contract_definition)
+                .await,
+            ),
+            (Ok(true), ConflictStrategy::Rename) => {
+              let new_id = free_id(id, |candidate| async move {
+                exists(client.contract_definitions().get(&candidate)).await
+              })
+              .await;
+
+              match new_id {
+                Ok(new_id) => {
+                  match create_contract_definition(client, &contract_definition, &new_id).await {
+                    RestoreOutcome::Created => RestoreOutcome::Renamed(new_id),
+                    outcome => outcome,
+                  }
+                }
+                Err(error) => RestoreOutcome::Failed(error),
+              }
+            }
+          }
+        }
+        Err(error) => RestoreOutcome::Failed(error),
+      };
+
+    record(RestoreEntry {
+      kind: EntityKind::ContractDefinition,
+      id: id.to_string(),
+      outcome,
+    });
+  }
+
+  entries
+}
+
+async fn create_policy_definition(
+  client: &EdcConnectorClient,
+  policy_definition: &PolicyDefinition,
+  id: &str,
+) -> RestoreOutcome {
+  match with_id::<_, NewPolicyDefinition>(policy_definition, id) {
+    Ok(new_policy_definition) => created(client.policies().create(&new_policy_definition).await),
+    Err(error) => RestoreOutcome::Failed(error),
+  }
+}
+
+async fn create_asset(client: &EdcConnectorClient, asset: &Asset, id: &str) -> RestoreOutcome {
+  match with_id::<_, NewAsset>(asset, id) {
+    Ok(new_asset) => created(client.assets().create(&new_asset).await),
+    Err(error) => RestoreOutcome::Failed(error),
+  }
+}
+
+async fn create_contract_definition(
+  client: &EdcConnectorClient,
+  contract_definition: &ContractDefinition,
+  id: &str,
+) -> RestoreOutcome {
+  match with_id::<_, NewContractDefinition>(contract_definition, id) {
+    Ok(new_contract_definition) => created(
+      client
+        .contract_definitions()
+        .create(&new_contract_definition)
+        .await,
+    ),
+    Err(error) => RestoreOutcome::Failed(error),
+  }
+}
+
+fn created<T>(result: EdcResult<T>) -> RestoreOutcome {
+  match result {
+    Ok(_) => RestoreOutcome::Created,
+    Err(error) => RestoreOutcome::Failed(error.to_string()),
+  }
+}
+
+fn overwritten(result: EdcResult<()>) -> RestoreOutcome {
+  match result {
+    Ok(_) => RestoreOutcome::Overwritten,
+    Err(error) => RestoreOutcome::OverwriteFailed(error.to_string()),
+  }
+}
+
+/// Tells a missing entity apart from a failed lookup, which must not be taken for one.
+async fn exists<T>(lookup: impl Future<Output = EdcResult<T>>) -> Result<bool, String> {
+  match lookup.await {
+    Ok(_) => Ok(true),
+    Err(Error::ManagementApi(error)) if error.status_code == StatusCode::NOT_FOUND => Ok(false),
+    Err(error) => Err(error.to_string()),
+  }
+}
+
+async fn free_id<F, Fut>(id: &str, exists: F) -> Result<String, String>
+where
+  F: Fn(String) -> Fut,
+  Fut: Future<Output = Result<bool, String>>,
+{
+  let mut suffix = 1;
+
+  loop {
+    let candidate = if suffix == 1 {
+      format!("{id}-restored")
+    } else {
+      format!("{id}-restored-{suffix}")
+    };
+
+    if !exists(candidate.clone()).await? {
+      return Ok(candidate);
+    }
+
+    suffix += 1;
+  }
+}
+
+pub(crate) fn with_id<T: Serialize, U: DeserializeOwned>(
+  entity: &T,
+  id: &str,
+) -> Result<U, String> {
+  let mut value = serde_json::to_value(entity).map_err(|error| error.to_string())?;
+
+  if let Value::Object(object) = &mut value {
+    object.insert("@id".to_string(), Value::String(id.to_string()));
+  }
+
+  serde_json::from_value(value).map_err(|error| error.to_string())
+}
+
+fn remap_contract_definition(
+  contract_definition: &ContractDefinition,
+  renamed_policies: &HashMap<String, String>,
+  renamed_assets: &HashMap<String, String>,
+) -> Result<ContractDefinition, String> {
+  let mut value = serde_json::to_value(contract_definition).map_err(|error| error.to_string())?;
+
+  for key in ["accessPolicyId", "contractPolicyId"] {
+    if let Some(Value::String(policy_id)) = value.get_mut(key)
+      && let Some(new_policy_id) = renamed_policies.get(policy_id.as_str())
+    {
+      *policy_id = new_policy_id.clone();
+    }
+  }
+
+  if let Some(Value::Array(criteria)) = value.get_mut("assetsSelector") {
+    // Only criteria on the asset ID refer to assets, other properties may hold the same text.
+    let selects_asset_id = |criterion: &Value| {
+      criterion
+        .get("operandLeft")
+        .and_then(Value::as_str)
+        .is_some_and(|operand_left| compact_property_name(operand_left) == "id")
+    };
+
+    for criterion in criteria
+      .iter_mut()
+      .filter(|criterion| selects_asset_id(criterion))
+    {
+      match criterion.get_mut("operandRight") {
+        Some(Value::String(asset_id)) => remap_asset_id(asset_id, renamed_assets),
+        // Lists of IDs, as used with the `in` operator.
+        Some(Value::Array(asset_ids)) => {
+          for asset_id in asset_ids {
+            if let Value::String(asset_id) = asset_id {
+              remap_asset_id(asset_id, renamed_assets);
+            }
+          }
+        }
+        _ => {}
+      }
+    }
+  }
+
+  serde_json::from_value(value).map_err(|error| error.to_string())
+}
+
+fn remap_asset_id(asset_id: &mut String, renamed_assets: &HashMap<String, String>) {
+  if let Some(new_asset_id) = renamed_assets.get(asset_id.as_str()) {
+    *asset_id = new_asset_id.clone();
+  }
+}
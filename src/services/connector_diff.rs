@@ -0,0 +1,187 @@
+use super::ConnectorBackup;
+use crate::models::EntityKind;
+use serde::Serialize;
+use serde_json::Value;
+use std::collections::{BTreeMap, BTreeSet};
+
+#[derive(Copy, Clone, Debug, PartialEq, Eq, strum::Display)]
+pub enum DiffStatus {
+  Added,
+  Removed,
+  Changed,
+}
+
+#[derive(Clone, Debug, PartialEq)]
+pub struct FieldChange {
+  pub path: String,
+  pub left: Option<String>,
+  pub right: Option<String>,
+}
+
+#[derive(Clone, Debug, PartialEq)]
+pub struct DiffEntry {
+  pub kind: EntityKind,
+  pub id: String,
+  pub status: DiffStatus,
+  pub changes: Vec<FieldChange>,
+}
+
+/// Compares two snapshots, `Added` and `Removed` being relative to the left one.
+pub fn diff_connectors(left: &ConnectorBackup, right: &ConnectorBackup) -> Vec<DiffEntry> {
+  let mut entries = diff_entities(
+    EntityKind::PolicyDefinition,
+    &left.policy_definitions,
+    &right.policy_definitions,
+    |policy_definition| policy_definition.id(),
+  );
+
+  entries.extend(diff_entities(
+    EntityKind::Asset,
+    &left.assets,
+    &right.assets,
+    |asset| asset.id(),
+  ));
+
+  entries.extend(diff_entities(
+    EntityKind::ContractDefinition,
+    &left.contract_definitions,
+    &right.contract_definitions,
+    |contract_definition| contract_definition.id(),
+  ));
+
+  entries
+}
+
+/// Builds a backup holding only the entries of `source` matching the given kinds and ids.
+pub fn select_entries(
+  source: &ConnectorBackup,
+  selection: &BTreeSet<(EntityKind, String)>,
+) -> ConnectorBackup {
+  let selected = |kind: EntityKind, id: &str| selection.contains(&(kind, id.to_string()));
+
+  ConnectorBackup {
+    version: source.version,
+    exported_at: source.exported_at.clone(),
+    policy_definitions: source
+      .policy_definitions
+      .iter()
+      .filter(|policy_definition| selected(EntityKind::PolicyDefinition, policy_definition.id()))
+      .cloned()
+      .collect(),
+    assets: source
+      .assets
+      .iter()
+      .filter(|asset| selected(EntityKind::Asset, asset.id()))
+      .cloned()
+      .collect(),
+    contract_definitions: source
+      .contract_definitions
+      .iter()
+      .filter(|contract_definition| {
+        selected(EntityKind::ContractDefinition, contract_definition.id())
+      })
+      .cloned()
+      .collect(),
+  }
+}
+
+fn diff_entities<T: Serialize>(
+  kind: EntityKind,
+  left: &[T],
+  right: &[T],
+  id: impl Fn(&T) -> &str,
+) -> Vec<DiffEntry> {
+  let left = left
+    .iter()
+    .map(|entity| (id(entity).to_string(), flatten(entity)))
+    .collect::<BTreeMap<_, _>>();
+  let right = right
+    .iter()
+    .map(|entity| (id(entity).to_string(), flatten(entity)))
+    .collect::<BTreeMap<_, _>>();
+
+  left
+    .keys()
+    .chain(right.keys())
+    .collect::<BTreeSet<_>>()
+    .into_iter()
+    .filter_map(|entity_id| {
+      let (status, changes) = match (left.get(entity_id), right.get(entity_id)) {
+        (Some(_), None) => (DiffStatus::Removed, vec![]),
+        (None, Some(_)) => (DiffStatus::Added, vec![]),
+        (Some(left_fields), Some(right_fields)) => {
+          let changes = diff_fields(left_fields, right_fields);
+
+          if changes.is_empty() {
+            return None;
+          }
+
+          (DiffStatus::Changed, changes)
+        }
+        (None, None) => return None,
+      };
+
+      Some(DiffEntry {
+        kind,
+        id: entity_id.clone(),
+        status,
+        changes,
+      })
+    })
+    .collect()
+}
+
+fn diff_fields(
+  left: &BTreeMap<String, String>,
+  right: &BTreeMap<String, String>,
+) -> Vec<FieldChange> {
+  left
+    .keys()
+    .chain(right.keys())
+    .collect::<BTreeSet<_>>()
+    .into_iter()
+    .filter(|path| left.get(*path) != right.get(*path))
+    .map(|path| FieldChange {
+      path: path.clone(),
+      left: left.get(path).cloned(),
+      right: right.get(path).cloned(),
+    })
+    .collect()
+}
+
+fn flatten<T: Serialize>(entity: &T) -> BTreeMap<String, String> {
+  let mut fields = BTreeMap::new();
+
+  if let Ok(value) = serde_json::to_value(entity) {
+    flatten_value("", &value, &mut fields);
+  }
+
+  fields
+}
+
+fn flatten_value(path: &str, value: &Value, fields: &mut BTreeMap<String, String>) {
+  match value {
+    Value::Object(object) => {
+      for (key, value) in object {
+        let path = if path.is_empty() {
+          key.clone()
+        } else {
+          format!("{path}.{key}")
+        };
+
+        flatten_value(&path, value, fields);
+      }
+    }
+    Value::Array(array) => {
+      for (index, value) in array.iter().enumerate() {
+        flatten_value(&format!("{path}[{index}]"), value, fields);
+      }
+    }
+    Value::String(string) => {
+      fields.insert(path.to_string(), string.clone());
+    }
+    value => {
+      fields.insert(path.to_string(), value.to_string());
+    }
+  }
+}
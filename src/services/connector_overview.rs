@@ -0,0 +1,174 @@
+use super::{count_all, get_raw_entity, query_all, raw_string_property};
+use crate::contexts::EdcConnectorState;
+use crate::models::{ContractNegotiationState, EntityKind, TransferProcessState};
+use edc_connector_client::types::contract_negotiation::ContractNegotiationState as EdcContractNegotiationState;
+use edc_connector_client::types::query::Query;
+use edc_connector_client::types::transfer_process::TransferProcessState as EdcTransferProcessState;
+use edc_connector_client::{EdcConnectorClient, EdcResult};
+
+const RECENT_FAILURES_LIMIT: usize = 10;
+
+#[derive(Clone, Debug, PartialEq)]
+pub struct StateCount {
+  pub state: String,
+  pub count: usize,
+}
+
+/// A negotiation or transfer, timestamped with when it entered its current state where the
+/// connector reports that, and with its creation otherwise.
+#[derive(Clone, Debug, PartialEq)]
+pub struct ProcessSummary {
+  pub kind: EntityKind,
+  pub id: String,
+  pub state: String,
+  pub since: i64,
+  pub error_detail: Option<String>,
+}
+
+#[derive(Clone, Debug, PartialEq)]
+pub struct ConnectorOverview {
+  pub assets: usize,
+  pub policy_definitions: usize,
+  pub contract_definitions: usize,
+  pub contract_negotiation_states: Vec<StateCount>,
+  pub transfer_process_states: Vec<StateCount>,
+  pub recent_failures: Vec<ProcessSummary>,
+  /// Negotiations and transfers still on their way to a terminal state, oldest first.
+  pub in_progress: Vec<ProcessSummary>,
+}
+
+pub async fn connector_overview(
+  edc_connector_state: &EdcConnectorState,
+  client: &EdcConnectorClient,
+) -> EdcResult<ConnectorOverview> {
+  let assets = count_all(Query::default(), |query| {
+    let client = client.clone();
+    async move { client.assets().query(query).await }
+  });
+  let policy_definitions = count_all(Query::default(), |query| {
+    let client = client.clone();
+    async move { client.policies().query(query).await }
+  });
+  let contract_definitions = count_all(Query::default(), |query| {
+    let client = client.clone();
+    async move { client.contract_definitions().query(query).await }
+  });
+  let contract_negotiations = query_all(Query::default(), |query| {
+    let client = client.clone();
+    async move { client.contract_negotiations().query(query).await }
+  });
+  let transfer_processes = query_all(Query::default(), |query| {
+    let client = client.clone();
+    async move { client.transfer_processes().query(query).await }
+  });
+
+  let (counts, processes) = futures::join!(
+    futures::future::try_join3(assets, policy_definitions, contract_definitions),
+    futures::future::try_join(contract_negotiations, transfer_processes),
+  );
+  let (assets, policy_definitions, contract_definitions) = counts?;
+  let (contract_negotiations, transfer_processes) = processes?;
+
+  let mut failures = vec![];
+  let mut in_progress = vec![];
+
+  for contract_negotiation in &contract_negotiations {
+    let summary = ProcessSummary {
+      kind: EntityKind::ContractNegotiation,
+      id: contract_negotiation.id().to_string(),
+      state: ContractNegotiationState::from(contract_negotiation.state()).to_string(),
+      since: contract_negotiation.created_at(),
+      error_detail: None,
+    };
+
+    match contract_negotiation.state() {
+      EdcContractNegotiationState::Terminated => failures.push(summary),
+      EdcContractNegotiationState::Finalized => {}
+      _ => in_progress.push(summary),
+    }
+  }
+
+  for transfer_process in &transfer_processes {
+    let summary = ProcessSummary {
+      kind: EntityKind::TransferProcess,
+      id: transfer_process.id().to_string(),
+      state: TransferProcessState::from(transfer_process.state()).to_string(),
+      since: transfer_process.state_timestamp(),
+      error_detail: None,
+    };
+
+    // Started and Suspended transfers are where they are meant to be until someone acts on them.
+    match transfer_process.state() {
+      EdcTransferProcessState::Terminated => failures.push(summary),
+      EdcTransferProcessState::Started
+      | EdcTransferProcessState::Suspended
+      | EdcTransferProcessState::Completed
+      | EdcTransferProcessState::Deprovisioned => {}
+      _ => in_progress.push(summary),
+    }
+  }
+
+  // Negotiations only carry their creation time, so they are ordered by that.
+  failures.sort_by_key(|failure| std::cmp::Reverse(failure.since));
+  failures.truncate(RECENT_FAILURES_LIMIT);
+  in_progress.sort_by_key(|summary| summary.since);
+
+  // Only the raw management API response carries the error detail.
+  let error_details = futures::future::join_all(failures.iter().map(|failure| {
+    let collection = match failure.kind {
+      EntityKind::ContractNegotiation => "contractnegotiations",
+      _ => "transferprocesses",
+    };
+
+    get_raw_entity(edc_connector_state, collection, &failure.id)
+  }))
+  .await;
+
+  for (failure, raw) in failures.iter_mut().zip(error_details) {
+    failure.error_detail = raw
+      .ok()
+      .and_then(|raw| raw_string_property(&raw, "errorDetail"));
+  }
+
+  Ok(ConnectorOverview {
+    assets,
+    policy_definitions,
+    contract_definitions,
+    contract_negotiation_states: state_counts(contract_negotiations.iter().map(
+      |contract_negotiation| {
+        ContractNegotiationState::from(contract_negotiation.state()).to_string()
+      },
+    )),
+    transfer_process_states: state_counts(
+      transfer_processes
+        .iter()
+        .map(|transfer_process| TransferProcessState::from(transfer_process.state()).to_string()),
+    ),
+    recent_failures: failures,
+    in_progress,
+  })
+}
+
+/// Counts per state, most frequent first.
+fn state_counts(states: impl Iterator<Item = String>) -> Vec<StateCount> {
+  let mut state_counts: Vec<StateCount> = vec![];
+
+  for state in states {
+    match state_counts
+      .iter_mut()
+      .find(|state_count| state_count.state == state)
+    {
+      Some(state_count) => state_count.count += 1,
+      None => state_counts.push(StateCount { state, count: 1 }),
+    }
+  }
+
+  state_counts.sort_by(|left, right| {
+    right
+      .count
+      .cmp(&left.count)
+      .then_with(|| left.state.cmp(&right.state))
+  });
+
+  state_counts
+}
@@ -0,0 +1,37 @@
+use crate::contexts::EdcConnectorState;
+use crate::models::EndpointDataReference;
+use crate::services::raw_request;
+use reqwest::Method;
+
+/// Reads a cached EDR without letting connectors that support it refresh the token on the way,
+/// so an expired EDR shows up as such.
+pub async fn get_cached_edr(
+  edc_connector_state: &EdcConnectorState,
+  transfer_process_id: &str,
+) -> Result<EndpointDataReference, String> {
+  raw_request(
+    edc_connector_state,
+    Method::GET,
+    &format!(
+      "edrs/{}/dataaddress?auto_refresh=false",
+      urlencoding::encode(transfer_process_id)
+    ),
+  )
+  .await
+  .map(|data_address| EndpointDataReference::from(&data_address))
+}
+
+/// Asks the connector for a fresh token. Only connectors with EDR refresh support, such as
+/// Tractus-X EDC, expose this endpoint.
+pub async fn refresh_edr(
+  edc_connector_state: &EdcConnectorState,
+  transfer_process_id: &str,
+) -> Result<EndpointDataReference, String> {
+  raw_request(
+    edc_connector_state,
+    Method::POST,
+    &format!("edrs/{}/refresh", urlencoding::encode(transfer_process_id)),
+  )
+  .await
+  .map(|data_address| EndpointDataReference::from(&data_address))
+}
@@ -0,0 +1,28 @@
+use super::{BrowserResponse, read_response};
+
+/// Performs a GET against an EDR endpoint from the browser, sending the EDR token as the
+/// `Authorization` header the way the provider's data plane expects it.
+pub async fn try_edr_request(
+  endpoint: &str,
+  authorization: Option<&str>,
+  path: &str,
+) -> Result<BrowserResponse, String> {
+  let url = match path.trim() {
+    "" => endpoint.to_string(),
+    path => format!(
+      "{}/{}",
+      endpoint.trim_end_matches('/'),
+      path.trim_start_matches('/')
+    ),
+  };
+
+  let request = reqwest::Client::new().get(url);
+  let request = match authorization {
+    Some(authorization) => request.header("Authorization", authorization),
+    None => request,
+  };
+
+  let response = request.send().await.map_err(|error| error.to_string())?;
+
+  read_response(response).await
+}
@@ -0,0 +1,22 @@
+use edc_connector_client::{Error, ManagementApiErrorDetailKind};
+
+/// Describes an error including the response body the management API sent along with it.
+pub fn error_message(error: &Error) -> String {
+  match error {
+    Error::ManagementApi(management_api_error) => match &management_api_error.error_detail {
+      ManagementApiErrorDetailKind::Raw(body) if !body.is_empty() => {
+        format!("{error}: {body}")
+      }
+      ManagementApiErrorDetailKind::Raw(_) => error.to_string(),
+      ManagementApiErrorDetailKind::Parsed(details) => format!(
+        "{error}: {}",
+        details
+          .iter()
+          .map(|detail| format!("{} ({})", detail.message, detail.kind))
+          .collect::<Vec<_>>()
+          .join("; ")
+      ),
+    },
+    _ => error.to_string(),
+  }
+}
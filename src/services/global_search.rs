@@ -0,0 +1,185 @@
+use crate::models::{EntityKind, QueryProperty};
+use edc_connector_client::types::query::Query;
+use edc_connector_client::{EdcConnectorClient, EdcResult};
+use std::future::Future;
+
+const SEARCH_LIMIT: u32 = 25;
+
+#[derive(Clone, Debug, PartialEq)]
+pub struct SearchResult {
+  pub id: String,
+  pub matched: Vec<&'static str>,
+}
+
+#[derive(Clone, Debug, PartialEq)]
+pub struct SearchResultGroup {
+  pub kind: EntityKind,
+  pub results: Vec<SearchResult>,
+  pub error: Option<String>,
+}
+
+const ASSET_PROPERTIES: [QueryProperty; 2] = [
+  QueryProperty::new("ID", "id"),
+  QueryProperty::new("Name", "https://w3id.org/edc/v0.0.1/ns/name"),
+];
+
+const POLICY_DEFINITION_PROPERTIES: [QueryProperty; 1] = [QueryProperty::new("ID", "id")];
+
+const CONTRACT_DEFINITION_PROPERTIES: [QueryProperty; 3] = [
+  QueryProperty::new("ID", "id"),
+  QueryProperty::new("Access Policy ID", "accessPolicyId"),
+  QueryProperty::new("Contract Policy ID", "contractPolicyId"),
+];
+
+const CONTRACT_NEGOTIATION_PROPERTIES: [QueryProperty; 3] = [
+  QueryProperty::new("ID", "id"),
+  QueryProperty::new("Contract Agreement ID", "contractAgreement.id"),
+  QueryProperty::new("Counter Party ID", "counterPartyId"),
+];
+
+const CONTRACT_AGREEMENT_PROPERTIES: [QueryProperty; 4] = [
+  QueryProperty::new("ID", "id"),
+  QueryProperty::new("Asset ID", "assetId"),
+  QueryProperty::new("Consumer ID", "consumerId"),
+  QueryProperty::new("Provider ID", "providerId"),
+];
+
+const TRANSFER_PROCESS_PROPERTIES: [QueryProperty; 3] = [
+  QueryProperty::new("ID", "id"),
+  QueryProperty::new("Contract ID", "contractId"),
+  QueryProperty::new("Asset ID", "assetId"),
+];
+
+/// Looks the term up by ID and key properties across every entity type, one query per property.
+pub async fn global_search(client: &EdcConnectorClient, term: &str) -> Vec<SearchResultGroup> {
+  let (
+    assets,
+    policy_definitions,
+    contract_definitions,
+    contract_negotiations,
+    contract_agreements,
+    transfer_processes,
+  ) = futures::join!(
+    search_kind(
+      EntityKind::Asset,
+      &ASSET_PROPERTIES,
+      term,
+      |query| {
+        let client = client.clone();
+        async move { client.assets().query(query).await }
+      },
+      |asset| asset.id().to_string(),
+    ),
+    search_kind(
+      EntityKind::PolicyDefinition,
+      &POLICY_DEFINITION_PROPERTIES,
+      term,
+      |query| {
+        let client = client.clone();
+        async move { client.policies().query(query).await }
+      },
+      |policy_definition| policy_definition.id().to_string(),
+    ),
+    search_kind(
+      EntityKind::ContractDefinition,
+      &CONTRACT_DEFINITION_PROPERTIES,
+      term,
+      |query| {
+        let client = client.clone();
+        async move { client.contract_definitions().query(query).await }
+      },
+      |contract_definition| contract_definition.id().to_string(),
+    ),
+    search_kind(
+      EntityKind::ContractNegotiation,
+      &CONTRACT_NEGOTIATION_PROPERTIES,
+      term,
+      |query| {
+        let client = client.clone();
+        async move { client.contract_negotiations().query(query).await }
+      },
+      |contract_negotiation| contract_negotiation.id().to_string(),
+    ),
+    search_kind(
+      EntityKind::ContractAgreement,
+      &CONTRACT_AGREEMENT_PROPERTIES,
+      term,
+      |query| {
+        let client = client.clone();
+        async move { client.contract_agreements().query(query).await }
+      },
+      |contract_agreement| contract_agreement.id().to_string(),
+    ),
+    search_kind(
+      EntityKind::TransferProcess,
+      &TRANSFER_PROCESS_PROPERTIES,
+      term,
+      |query| {
+        let client = client.clone();
+        async move { client.transfer_processes().query(query).await }
+      },
+      |transfer_process| transfer_process.id().to_string(),
+    ),
+  );
+
+  vec![
+    assets,
+    policy_definitions,
+    contract_definitions,
+    contract_negotiations,
+    contract_agreements,
+    transfer_processes,
+  ]
+}
+
+async fn search_kind<T, F, Fut>(
+  kind: EntityKind,
+  properties: &[QueryProperty],
+  term: &str,
+  fetch: F,
+  id: fn(&T) -> String,
+) -> SearchResultGroup
+where
+  F: Fn(Query) -> Fut,
+  Fut: Future<Output = EdcResult<Vec<T>>>,
+{
+  let pages = futures::future::join_all(properties.iter().map(|property| {
+    fetch(
+      Query::builder()
+        .filter(property.path, "=", term.to_string())
+        .limit(SEARCH_LIMIT)
+        .build(),
+    )
+  }))
+  .await;
+
+  let mut results: Vec<SearchResult> = vec![];
+  let mut error = None;
+
+  for (property, page) in properties.iter().zip(pages) {
+    match page {
+      Ok(page) => {
+        for entity in page {
+          let entity_id = id(&entity);
+
+          match results.iter_mut().find(|result| result.id == entity_id) {
+            Some(result) => result.matched.push(property.label),
+            None => results.push(SearchResult {
+              id: entity_id,
+              matched: vec![property.label],
+            }),
+          }
+        }
+      }
+      Err(search_error) => {
+        error.get_or_insert_with(|| format!("{}: {search_error}", property.label));
+      }
+    }
+  }
+
+  SearchResultGroup {
+    kind,
+    results,
+    error,
+  }
+}
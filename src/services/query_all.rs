@@ -0,0 +1,68 @@
+use edc_connector_client::EdcResult;
+use edc_connector_client::types::query::Query;
+use std::future::Future;
+
+const PAGE_SIZE: u32 = 100;
+
+pub async fn query_all<T, F, Fut>(query: Query, fetch: F) -> EdcResult<Vec<T>>
+where
+  F: Fn(Query) -> Fut,
+  Fut: Future<Output = EdcResult<Vec<T>>>,
+{
+  let mut items = vec![];
+  let mut offset = 0;
+
+  loop {
+    let page = fetch(query.to_builder().offset(offset).limit(PAGE_SIZE).build()).await?;
+    let page_len = page.len() as u32;
+
+    items.extend(page);
+
+    if page_len < PAGE_SIZE {
+      return Ok(items);
+    }
+
+    offset += PAGE_SIZE;
+  }
+}
+
+pub async fn count_all<T, F, Fut>(query: Query, fetch: F) -> EdcResult<usize>
+where
+  F: Fn(Query) -> Fut,
+  Fut: Future<Output = EdcResult<Vec<T>>>,
+{
+  let mut count = 0;
+  let mut offset = 0;
+
+  loop {
+    let page_len = fetch(query.to_builder().offset(offset).limit(PAGE_SIZE).build())
+      .await?
+      .len() as u32;
+
+    count += page_len as usize;
+
+    if page_len < PAGE_SIZE {
+      return Ok(count);
+    }
+
+    offset += PAGE_SIZE;
+  }
+}
+
+/// A short page pins the total exactly; otherwise the counted total is used, which may lag behind.
+/// An empty page past the first only tells that the offset is beyond the end, e.g. once entries
+/// were deleted, so the counted total is used then too.
+pub fn page_total_entries(
+  offset: usize,
+  limit: usize,
+  page_len: usize,
+  counted: Option<usize>,
+) -> Option<usize> {
+  if page_len == 0 && offset > 0 {
+    counted
+  } else if page_len < limit {
+    Some(offset + page_len)
+  } else {
+    counted.map(|counted| counted.max(offset + page_len))
+  }
+}
@@ -0,0 +1,85 @@
+use crate::contexts::EdcConnectorState;
+use reqwest::Method;
+use serde_json::Value;
+
+const EDC_NAMESPACE: &str = "https://w3id.org/edc/v0.0.1/ns/";
+
+/// Fetches an entity as plain JSON from the management API, for fields the typed client drops.
+pub async fn get_raw_entity(
+  edc_connector_state: &EdcConnectorState,
+  collection: &str,
+  id: &str,
+) -> Result<Value, String> {
+  raw_request(
+    edc_connector_state,
+    Method::GET,
+    &format!("{collection}/{}", urlencoding::encode(id)),
+  )
+  .await
+}
+
+/// Sends a body-less request to a management API path below the API version, e.g.
+/// `edrs/{id}/refresh`, for endpoints the typed client doesn't cover.
+pub async fn raw_request(
+  edc_connector_state: &EdcConnectorState,
+  method: Method,
+  path: &str,
+) -> Result<Value, String> {
+  let client = edc_connector_state
+    .get_client()
+    .ok_or_else(|| "No connector configured".to_string())?;
+
+  let url = format!(
+    "{}/{}/{path}",
+    edc_connector_state.management_url().trim_end_matches('/'),
+    client.api_version().as_str(),
+  );
+
+  let request = reqwest::Client::new().request(method, url);
+  let request = match edc_connector_state.api_key() {
+    Some(api_key) => request.header("X-Api-Key", api_key),
+    None => request,
+  };
+
+  request
+    .send()
+    .await
+    .and_then(|response| response.error_for_status())
+    .map_err(|error| error.to_string())?
+    .json::<Value>()
+    .await
+    .map_err(|error| error.to_string())
+}
+
+/// Reads a property whether the response is compacted against the EDC vocabulary or not.
+pub fn raw_property<'a>(value: &'a Value, name: &str) -> Option<&'a Value> {
+  value
+    .get(name)
+    .or_else(|| value.get(format!("edc:{name}")))
+    .or_else(|| value.get(format!("{EDC_NAMESPACE}{name}")))
+}
+
+pub fn raw_string_property(value: &Value, name: &str) -> Option<String> {
+  raw_property(value, name).map(plain_value)
+}
+
+/// Renders a JSON-LD value as text, unwrapping `@value` and `@id` objects.
+pub fn plain_value(value: &Value) -> String {
+  match value {
+    Value::String(string) => string.clone(),
+    Value::Object(object) => object
+      .get("@value")
+      .or_else(|| object.get("@id"))
+      .map(plain_value)
+      .unwrap_or_else(|| value.to_string()),
+    other => other.to_string(),
+  }
+}
+
+/// Drops the EDC namespace from a property name, leaving other IRIs untouched.
+pub fn compact_property_name(name: &str) -> &str {
+  name
+    .strip_prefix(EDC_NAMESPACE)
+    .or_else(|| name.strip_prefix("edc:"))
+    .unwrap_or(name)
+}
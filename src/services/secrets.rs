@@ -0,0 +1,55 @@
+use crate::services::{compact_property_name, plain_value, query_all};
+use edc_connector_client::types::query::Query;
+use edc_connector_client::{EdcConnectorClient, EdcResult, Error};
+use reqwest::StatusCode;
+use std::collections::BTreeMap;
+
+/// Data address properties that name a vault secret instead of holding a value.
+pub const SECRET_REFERENCE_PROPERTIES: [&str; 4] = [
+  "secretName",
+  "keyName",
+  "oauth2:clientSecretKey",
+  "oauth2:privateKeyName",
+];
+
+/// Maps every secret referenced by an asset's data address to the referencing asset IDs.
+///
+/// The management API can't list the vault, so references are the only way to discover keys.
+pub async fn secret_references(
+  client: &EdcConnectorClient,
+) -> EdcResult<BTreeMap<String, Vec<String>>> {
+  let assets = query_all(Query::default(), |query| async move {
+    client.assets().query(query).await
+  })
+  .await?;
+
+  let mut references = BTreeMap::<String, Vec<String>>::new();
+
+  for asset in assets {
+    let data_address = serde_json::to_value(asset.data_address()).unwrap_or_default();
+
+    for (name, value) in data_address.as_object().into_iter().flatten() {
+      if !SECRET_REFERENCE_PROPERTIES.contains(&compact_property_name(name)) {
+        continue;
+      }
+
+      let secret_name = plain_value(value);
+      if !secret_name.is_empty() {
+        references
+          .entry(secret_name)
+          .or_default()
+          .push(asset.id().to_string());
+      }
+    }
+  }
+
+  Ok(references)
+}
+
+pub async fn secret_exists(client: &EdcConnectorClient, id: &str) -> EdcResult<bool> {
+  match client.secrets().get(id).await {
+    Ok(_) => Ok(true),
+    Err(Error::ManagementApi(error)) if error.status_code == StatusCode::NOT_FOUND => Ok(false),
+    Err(error) => Err(error),
+  }
+}
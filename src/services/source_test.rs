@@ -0,0 +1,108 @@
+use super::{BrowserResponse, compact_property_name, plain_value, read_response};
+use edc_connector_client::types::data_address::DataAddress;
+use reqwest::Method;
+
+/// The request an `HttpData` address describes, as far as a browser can reproduce it.
+#[derive(Clone, Debug, Default, PartialEq)]
+pub struct SourceTestRequest {
+  pub method: String,
+  pub base_url: String,
+  pub path: String,
+  pub query_params: String,
+  pub headers: Vec<(String, String)>,
+  /// How the data plane authenticates where the browser can't do the same, e.g. with a vault
+  /// secret.
+  pub unsent_authentication: Option<String>,
+}
+
+impl SourceTestRequest {
+  pub fn url(&self) -> String {
+    let mut url = self.base_url.trim_end_matches('/').to_string();
+
+    if !self.path.trim().is_empty() {
+      url = format!("{url}/{}", self.path.trim().trim_start_matches('/'));
+    }
+
+    if !self.query_params.trim().is_empty() {
+      url = format!("{url}?{}", self.query_params.trim().trim_start_matches('?'));
+    }
+
+    url
+  }
+}
+
+impl From<&DataAddress> for SourceTestRequest {
+  fn from(data_address: &DataAddress) -> Self {
+    let data_address = serde_json::to_value(data_address).unwrap_or_default();
+
+    let mut request = SourceTestRequest {
+      method: "GET".to_string(),
+      ..SourceTestRequest::default()
+    };
+    let mut auth_key = None;
+    let mut auth_code = None;
+
+    for (name, value) in data_address.as_object().into_iter().flatten() {
+      let value = plain_value(value);
+
+      match compact_property_name(name) {
+        "baseUrl" => request.base_url = value,
+        "path" => request.path = value,
+        "queryParams" => request.query_params = value,
+        "method" if !value.trim().is_empty() => request.method = value.trim().to_uppercase(),
+        "authKey" => auth_key = Some(value),
+        "authCode" => auth_code = Some(value),
+        "secretName" => request.unsent_authentication = Some(format!("the vault secret '{value}'")),
+        "oauth2:tokenUrl" => {
+          request.unsent_authentication = Some(format!("an OAuth2 token from '{value}'"))
+        }
+        name => {
+          if let Some(header) = name.strip_prefix("header:") {
+            request.headers.push((header.to_string(), value));
+          }
+        }
+      }
+    }
+
+    if let (Some(auth_key), Some(auth_code)) = (auth_key, auth_code) {
+      request.headers.push((auth_key, auth_code));
+    }
+    request.headers.sort();
+
+    request
+  }
+}
+
+#[derive(Clone, Debug, PartialEq)]
+pub struct SourceTestResult {
+  pub url: String,
+  pub response: BrowserResponse,
+  /// Time until the response headers arrived.
+  pub latency_ms: i64,
+}
+
+/// Sends the request an asset's data address describes from the browser, so it can be checked
+/// without involving the connector.
+pub async fn test_source(request: &SourceTestRequest) -> Result<SourceTestResult, String> {
+  let url = request.url();
+  let method = Method::from_bytes(request.method.as_bytes())
+    .map_err(|_| format!("invalid method '{}'", request.method))?;
+
+  let http_request = request.headers.iter().fold(
+    reqwest::Client::new().request(method, &url),
+    |http_request, (name, value)| http_request.header(name, value),
+  );
+
+  let started_at = chrono::Utc::now();
+  let response = http_request
+    .send()
+    .await
+    .map_err(|error| error.to_string())?;
+  let latency_ms = (chrono::Utc::now() - started_at).num_milliseconds();
+
+  Ok(SourceTestResult {
+    url,
+    response: read_response(response).await?,
+    latency_ms,
+  })
+}
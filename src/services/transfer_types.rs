@@ -0,0 +1,117 @@
+use super::{plain_value, raw_property};
+use edc_connector_client::EdcConnectorClient;
+use edc_connector_client::types::ExtraTokenFields;
+use edc_connector_client::types::Protocol;
+use edc_connector_client::types::catalog::DatasetRequest;
+use edc_connector_client::types::dataplane::DataPlaneInstanceState;
+use edc_connector_client::types::query::Query;
+use serde::Deserialize;
+use serde_json::Value;
+use std::collections::BTreeSet;
+
+#[derive(Clone, Copy, Debug, PartialEq, Eq, strum::Display)]
+pub enum TransferTypeSource {
+  #[strum(to_string = "the catalog of the agreed asset")]
+  Catalog,
+  #[strum(to_string = "the available data planes")]
+  DataPlanes,
+}
+
+#[derive(Clone, Debug, PartialEq)]
+pub struct SupportedTransferTypes {
+  pub source: TransferTypeSource,
+  pub transfer_types: Vec<String>,
+}
+
+#[derive(Debug, Deserialize)]
+struct DatasetDistributions {
+  #[serde(default, alias = "dcat:distribution")]
+  distribution: Value,
+}
+
+impl ExtraTokenFields for DatasetDistributions {}
+
+/// Reads the transfer types the provider offers for the agreed asset from its catalog, falling
+/// back to what the local data planes support when the catalog can't be reached.
+pub async fn supported_transfer_types(
+  client: &EdcConnectorClient,
+  contract_id: &str,
+) -> SupportedTransferTypes {
+  if let Some(transfer_types) = catalog_transfer_types(client, contract_id).await
+    && !transfer_types.is_empty()
+  {
+    return SupportedTransferTypes {
+      source: TransferTypeSource::Catalog,
+      transfer_types,
+    };
+  }
+
+  let transfer_types = client
+    .data_planes()
+    .list()
+    .await
+    .unwrap_or_default()
+    .iter()
+    .filter(|data_plane_instance| *data_plane_instance.state() == DataPlaneInstanceState::Available)
+    .flat_map(|data_plane_instance| data_plane_instance.allowed_transfer_types().clone())
+    .collect::<BTreeSet<_>>()
+    .into_iter()
+    .collect();
+
+  SupportedTransferTypes {
+    source: TransferTypeSource::DataPlanes,
+    transfer_types,
+  }
+}
+
+async fn catalog_transfer_types(
+  client: &EdcConnectorClient,
+  contract_id: &str,
+) -> Option<Vec<String>> {
+  let contract_agreement = client.contract_agreements().get(contract_id).await.ok()?;
+
+  // The negotiation that produced the agreement knows where, and over which protocol, the
+  // provider's catalog lives.
+  let contract_negotiation = client
+    .contract_negotiations()
+    .query(
+      Query::builder()
+        .filter("contractAgreement.id", "=", contract_id.to_string())
+        .build(),
+    )
+    .await
+    .ok()?
+    .into_iter()
+    .next()?;
+
+  let dataset_request = DatasetRequest::builder()
+    .id(contract_agreement.asset_id())
+    .counter_party_address(contract_negotiation.counter_party_address())
+    .protocol(Protocol::new(contract_negotiation.protocol()))
+    .counter_party_id(contract_agreement.provider_id().to_string())
+    .build();
+
+  let dataset = client
+    .catalogue()
+    .dataset::<DatasetDistributions>(&dataset_request)
+    .await
+    .ok()?;
+
+  let distributions = match &dataset.extra.distribution {
+    Value::Array(distributions) => distributions.iter().collect::<Vec<_>>(),
+    Value::Null => vec![],
+    distribution => vec![distribution],
+  };
+
+  Some(
+    distributions
+      .into_iter()
+      .filter_map(|distribution| {
+        raw_property(distribution, "format").or_else(|| distribution.get("dct:format"))
+      })
+      .map(plain_value)
+      .collect::<BTreeSet<_>>()
+      .into_iter()
+      .collect(),
+  )
+}